@@ -1167,11 +1167,12 @@ async fn run_schema_migrate(
 fn create_sql_for_table(table: &SchemaTable, db_kind: DbKind) -> String {
     let mut cols = Vec::new();
     for col in &table.columns {
+        let name = quote_identifier(&col.name, db_kind);
         if col.primary_key {
-            cols.push(format!("{} {}", col.name, auto_increment_pk(db_kind)));
+            cols.push(format!("{} {}", name, auto_increment_pk(db_kind)));
             continue;
         }
-        let mut def = format!("{} {}", col.name, col.sql_type);
+        let mut def = format!("{} {}", name, col.sql_type);
         if !col.nullable {
             def.push_str(" NOT NULL");
         }
@@ -1179,11 +1180,19 @@ fn create_sql_for_table(table: &SchemaTable, db_kind: DbKind) -> String {
     }
     format!(
         "CREATE TABLE IF NOT EXISTS {} ({})",
-        table.name,
+        quote_identifier(&table.name, db_kind),
         cols.join(", ")
     )
 }
 
+/// Quotes a table or column name the way the ORM's generated SQL does.
+fn quote_identifier(name: &str, db_kind: DbKind) -> String {
+    match db_kind {
+        DbKind::Sqlite | DbKind::Mysql => format!("`{}`", name.replace('`', "``")),
+        DbKind::Postgres => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
 fn auto_increment_pk(db_kind: DbKind) -> &'static str {
     match db_kind {
         DbKind::Sqlite => "INTEGER PRIMARY KEY",
@@ -1223,6 +1232,8 @@ fn write_schema_migration_file(
 }
 
 #[cfg(test)]
+// CWD_LOCK is held across awaits on purpose: it serializes tests that change the process cwd.
+#[allow(clippy::await_holding_lock)]
 mod tests {
     use std::{
         env, fs,
@@ -1269,6 +1280,71 @@ struct User {
         fs::write(src_dir.join("main.rs"), content.trim()).unwrap();
     }
 
+    #[test]
+    fn scan_models_schema_follows_naming_overrides() {
+        let root = make_temp_dir();
+        let src_dir = root.join("src");
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"app\"\n\n[package.metadata.premix]\nnaming = \"plural\"\n",
+        )
+        .unwrap();
+        let content = r#"
+#[derive(Model)]
+struct UserCategory {
+    id: i32,
+}
+
+#[derive(Model)]
+#[premix(table = "folks")]
+struct Person {
+    id: i32,
+    #[premix(column = "fullName", index)]
+    full_name: String,
+}
+"#;
+        fs::write(src_dir.join("main.rs"), content.trim()).unwrap();
+
+        let mut tables = scan_models_schema(&src_dir, DbKind::Sqlite).unwrap();
+        tables.sort_by(|a, b| a.name.cmp(&b.name));
+        let names: Vec<_> = tables.iter().map(|table| table.name.as_str()).collect();
+        assert_eq!(names, vec!["folks", "user_categories"]);
+        assert_eq!(tables[0].columns[1].name, "fullName");
+        assert_eq!(tables[0].indexes[0].columns, vec!["fullName"]);
+    }
+
+    #[test]
+    fn create_sql_for_table_quotes_names() {
+        let table = SchemaTable {
+            name: "folks".to_string(),
+            columns: vec![
+                schema::SchemaColumn {
+                    name: "id".to_string(),
+                    sql_type: "INTEGER".to_string(),
+                    nullable: false,
+                    primary_key: true,
+                },
+                schema::SchemaColumn {
+                    name: "fullName".to_string(),
+                    sql_type: "TEXT".to_string(),
+                    nullable: false,
+                    primary_key: false,
+                },
+            ],
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+            fulltext: Vec::new(),
+            primary_key: Vec::new(),
+            create_sql: None,
+        };
+        assert_eq!(
+            create_sql_for_table(&table, DbKind::Postgres),
+            r#"CREATE TABLE IF NOT EXISTS "folks" ("id" SERIAL PRIMARY KEY, "fullName" TEXT NOT NULL)"#
+        );
+        assert!(create_sql_for_table(&table, DbKind::Mysql).contains("`fullName` TEXT NOT NULL"));
+    }
+
     #[test]
     fn load_migrations_empty_dir() {
         let dir = make_temp_dir();
@@ -1,6 +1,7 @@
 use std::error::Error;
 use std::path::Path;
 
+use premix_core::NamingStrategy;
 use premix_core::schema::{SchemaColumn, SchemaForeignKey, SchemaIndex, SchemaTable};
 use syn::punctuated::Punctuated;
 use syn::{Attribute, Field, Fields, Item, Token};
@@ -32,6 +33,12 @@ pub fn scan_models_schema(
     if !src_dir.exists() {
         return Ok(Vec::new());
     }
+    // Tables follow the crate's `[package.metadata.premix] naming`, like the derive.
+    let manifest = src_dir
+        .parent()
+        .and_then(|root| std::fs::read_to_string(root.join("Cargo.toml")).ok())
+        .unwrap_or_default();
+    let naming = NamingStrategy::from_manifest(&manifest)?;
 
     let mut tables = Vec::new();
     for entry in WalkDir::new(src_dir)
@@ -47,7 +54,7 @@ pub fn scan_models_schema(
         }
         let source = std::fs::read_to_string(entry.path())?;
         let parsed = syn::parse_file(&source)?;
-        collect_tables_from_items(&parsed.items, db_kind, &mut tables)?;
+        collect_tables_from_items(&parsed.items, db_kind, naming, &mut tables)?;
     }
 
     Ok(tables)
@@ -76,6 +83,7 @@ fn should_skip_path(path: &Path, src_dir: &Path) -> bool {
 fn collect_tables_from_items(
     items: &[Item],
     db_kind: DbKind,
+    naming: NamingStrategy,
     tables: &mut Vec<SchemaTable>,
 ) -> Result<(), syn::Error> {
     for item in items {
@@ -84,12 +92,12 @@ fn collect_tables_from_items(
                 if !has_derive_model(&item_struct.attrs) {
                     continue;
                 }
-                let table = build_schema_table(item_struct, db_kind)?;
+                let table = build_schema_table(item_struct, db_kind, naming)?;
                 tables.push(table);
             }
             Item::Mod(item_mod) => {
                 if let Some((_, items)) = &item_mod.content {
-                    collect_tables_from_items(items, db_kind, tables)?;
+                    collect_tables_from_items(items, db_kind, naming, tables)?;
                 }
             }
             _ => {}
@@ -117,9 +125,15 @@ fn has_derive_model(attrs: &[Attribute]) -> bool {
     })
 }
 
-fn build_schema_table(item: &syn::ItemStruct, db_kind: DbKind) -> Result<SchemaTable, syn::Error> {
-    let struct_name = item.ident.to_string();
-    let table_name = format!("{}s", struct_name.to_lowercase());
+fn build_schema_table(
+    item: &syn::ItemStruct,
+    db_kind: DbKind,
+    naming: NamingStrategy,
+) -> Result<SchemaTable, syn::Error> {
+    let table_name = match table_override(&item.attrs)? {
+        Some(table) => table,
+        None => naming.table_name(&item.ident.to_string()),
+    };
 
     let fields = match &item.fields {
         Fields::Named(named) => &named.named,
@@ -131,7 +145,9 @@ fn build_schema_table(item: &syn::ItemStruct, db_kind: DbKind) -> Result<SchemaT
 
     let (index_specs, foreign_key_specs) = collect_schema_specs(fields, &table_name)?;
 
+    let has_marked_key = fields.iter().any(|f| has_premix_flag(f, "primary_key"));
     let mut columns = Vec::new();
+    let mut fulltext = Vec::new();
     for field in fields {
         if is_ignored(field) {
             continue;
@@ -139,11 +155,19 @@ fn build_schema_table(item: &syn::ItemStruct, db_kind: DbKind) -> Result<SchemaT
         let ident = field
             .ident
             .as_ref()
-            .ok_or_else(|| syn::Error::new_spanned(field, "Field must have an ident"))?;
-        let name = ident.to_string();
-        let primary_key = name == "id";
+            .ok_or_else(|| syn::Error::new_spanned(field, "Field must have an ident"))?
+            .to_string();
+        let name = field_column_name(field)?;
+        let primary_key = if has_marked_key {
+            has_premix_flag(field, "primary_key")
+        } else {
+            ident == "id"
+        };
         let nullable = !primary_key && is_option_type(&field.ty);
-        let sql_type = sql_type_for_field(&name, &field.ty, db_kind).to_string();
+        let sql_type = sql_type_for_field(&ident, &field.ty, db_kind).to_string();
+        if has_premix_flag(field, "fulltext") {
+            fulltext.push(name.clone());
+        }
         columns.push(SchemaColumn {
             name,
             sql_type,
@@ -175,22 +199,85 @@ fn build_schema_table(item: &syn::ItemStruct, db_kind: DbKind) -> Result<SchemaT
         columns,
         indexes,
         foreign_keys,
+        fulltext,
+        primary_key: Vec::new(),
         create_sql: None,
     })
 }
 
+/// `#[premix(table = "...")]` on the struct, if present.
+fn table_override(attrs: &[Attribute]) -> Result<Option<String>, syn::Error> {
+    let mut table = None;
+    for attr in attrs {
+        if !attr.path().is_ident("premix") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                table = Some(lit.value());
+                Ok(())
+            } else {
+                skip_premix_meta(&meta)
+            }
+        })?;
+    }
+    Ok(table)
+}
+
+/// The SQL column for a field: `#[premix(column = "...")]` when given, otherwise
+/// the field name.
+fn field_column_name(field: &Field) -> Result<String, syn::Error> {
+    let mut column = field
+        .ident
+        .as_ref()
+        .ok_or_else(|| syn::Error::new_spanned(field, "Field must have an ident"))?
+        .to_string();
+    for attr in &field.attrs {
+        if !attr.path().is_ident("premix") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("column") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                column = lit.value();
+                Ok(())
+            } else {
+                skip_premix_meta(&meta)
+            }
+        })?;
+    }
+    Ok(column)
+}
+
+/// Consumes a `#[premix(...)]` entry handled elsewhere (`flag`, `key = value`,
+/// or `name(...)`).
+fn skip_premix_meta(meta: &syn::meta::ParseNestedMeta) -> Result<(), syn::Error> {
+    if meta.input.peek(syn::token::Paren) {
+        meta.input.step(|cursor| {
+            cursor
+                .token_tree()
+                .ok_or_else(|| cursor.error("expected arguments"))
+        })?;
+    } else if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    }
+    Ok(())
+}
+
 fn has_premix_flag(field: &Field, flag: &str) -> bool {
+    let mut found = false;
     for attr in &field.attrs {
         if attr.path().is_ident("premix") {
-            let args = attr.parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated);
-            if let Ok(args) = args {
-                if args.iter().any(|ident| ident == flag) {
-                    return true;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(flag) {
+                    found = true;
                 }
-            }
+                skip_premix_meta(&meta)
+            });
         }
     }
-    false
+    found
 }
 
 fn is_ignored(field: &Field) -> bool {
@@ -220,11 +307,7 @@ fn collect_schema_specs(
         if is_ignored(field) {
             continue;
         }
-        let field_name = field
-            .ident
-            .as_ref()
-            .ok_or_else(|| syn::Error::new_spanned(field, "Field must have an ident"))?
-            .to_string();
+        let field_name = field_column_name(field)?;
 
         for attr in &field.attrs {
             if !attr.path().is_ident("premix") {
@@ -278,6 +361,8 @@ fn collect_schema_specs(
                         ref_table,
                         ref_column,
                     });
+                } else {
+                    skip_premix_meta(&meta)?;
                 }
                 Ok(())
             })?;
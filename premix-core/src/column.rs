@@ -0,0 +1,196 @@
+use crate::query::{BindValue, ColumnRef, FilterExpr, FilterOp, escape_like};
+use smallvec::smallvec;
+use std::marker::PhantomData;
+
+/// A typed handle to a model column, generated by `#[derive(Model)]` as an
+/// associated constant (`User::AGE`, `User::NAME`, ...).
+///
+/// `T` is the field's Rust type (`Option<T>` fields use `T`), so comparing a
+/// column with a value of another type does not compile. Each method returns a
+/// [`Condition`] for [`QueryBuilder::filter_by`](crate::QueryBuilder::filter_by)
+/// or [`FilterGroup::filter_by`](crate::FilterGroup::filter_by).
+///
+/// ```ignore
+/// User::find_in_pool(&pool)
+///     .filter_by(User::AGE.gt(18))
+///     .filter_by(User::NAME.like("a%"))
+///     .all()
+///     .await?;
+/// ```
+///
+/// A value of the wrong type is rejected at compile time:
+///
+/// ```compile_fail
+/// const AGE: premix_core::Column<i32> = premix_core::Column::new("age");
+/// let _ = AGE.eq("oops");
+/// ```
+pub struct Column<T> {
+    name: &'static str,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Column<T> {
+    /// Creates a handle for the column `name`.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _type: PhantomData,
+        }
+    }
+
+    /// The SQL column name.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// `column IS NULL`.
+    pub fn is_null(self) -> Condition {
+        Condition(FilterExpr::NullCheck {
+            column: self.into(),
+            is_null: true,
+        })
+    }
+
+    /// `column IS NOT NULL`.
+    pub fn is_not_null(self) -> Condition {
+        Condition(FilterExpr::NullCheck {
+            column: self.into(),
+            is_null: false,
+        })
+    }
+
+    fn compare(self, op: FilterOp, value: BindValue) -> Condition {
+        Condition(FilterExpr::Compare {
+            column: self.into(),
+            op,
+            values: smallvec![value],
+        })
+    }
+}
+
+impl<T: Into<BindValue>> Column<T> {
+    /// `column = value`.
+    pub fn eq(self, value: impl Into<T>) -> Condition {
+        self.compare(FilterOp::Eq, value.into().into())
+    }
+
+    /// `column != value`.
+    pub fn ne(self, value: impl Into<T>) -> Condition {
+        self.compare(FilterOp::Ne, value.into().into())
+    }
+
+    /// `column < value`.
+    pub fn lt(self, value: impl Into<T>) -> Condition {
+        self.compare(FilterOp::Lt, value.into().into())
+    }
+
+    /// `column <= value`.
+    pub fn lte(self, value: impl Into<T>) -> Condition {
+        self.compare(FilterOp::Lte, value.into().into())
+    }
+
+    /// `column > value`.
+    pub fn gt(self, value: impl Into<T>) -> Condition {
+        self.compare(FilterOp::Gt, value.into().into())
+    }
+
+    /// `column >= value`.
+    pub fn gte(self, value: impl Into<T>) -> Condition {
+        self.compare(FilterOp::Gte, value.into().into())
+    }
+
+    /// `column BETWEEN low AND high`.
+    pub fn between(self, low: impl Into<T>, high: impl Into<T>) -> Condition {
+        Condition(FilterExpr::Compare {
+            column: self.into(),
+            op: FilterOp::Between,
+            values: smallvec![low.into().into(), high.into().into()],
+        })
+    }
+
+    /// `column IN (values...)`; an empty list matches no rows.
+    pub fn in_list<I, V>(self, values: I) -> Condition
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<T>,
+    {
+        Condition(FilterExpr::Compare {
+            column: self.into(),
+            op: FilterOp::In,
+            values: values.into_iter().map(|v| v.into().into()).collect(),
+        })
+    }
+
+    /// `column NOT IN (values...)`; an empty list matches every row.
+    pub fn not_in<I, V>(self, values: I) -> Condition
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<T>,
+    {
+        Condition(FilterExpr::Compare {
+            column: self.into(),
+            op: FilterOp::NotIn,
+            values: values.into_iter().map(|v| v.into().into()).collect(),
+        })
+    }
+}
+
+impl Column<String> {
+    /// `column LIKE pattern`; `%` and `_` in `pattern` are wildcards.
+    pub fn like(self, pattern: impl Into<String>) -> Condition {
+        self.compare(FilterOp::Like, pattern.into().into())
+    }
+
+    /// Case-insensitive [`like`](Self::like).
+    pub fn ilike(self, pattern: impl Into<String>) -> Condition {
+        self.compare(FilterOp::ILike, pattern.into().into())
+    }
+
+    /// Matches values containing `value`, with wildcards escaped.
+    pub fn contains(self, value: impl AsRef<str>) -> Condition {
+        let pattern = format!("%{}%", escape_like(value.as_ref()));
+        self.compare(FilterOp::LikeEscaped, pattern.into())
+    }
+
+    /// Case-insensitive [`contains`](Self::contains).
+    pub fn icontains(self, value: impl AsRef<str>) -> Condition {
+        let pattern = format!("%{}%", escape_like(value.as_ref()));
+        self.compare(FilterOp::ILikeEscaped, pattern.into())
+    }
+
+    /// Matches values starting with `value`, with wildcards escaped.
+    pub fn starts_with(self, value: impl AsRef<str>) -> Condition {
+        let pattern = format!("{}%", escape_like(value.as_ref()));
+        self.compare(FilterOp::LikeEscaped, pattern.into())
+    }
+
+    /// Matches values ending with `value`, with wildcards escaped.
+    pub fn ends_with(self, value: impl AsRef<str>) -> Condition {
+        let pattern = format!("%{}", escape_like(value.as_ref()));
+        self.compare(FilterOp::LikeEscaped, pattern.into())
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Column<T> {}
+
+impl<T> std::fmt::Debug for Column<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Column").field(&self.name).finish()
+    }
+}
+
+impl<T> From<Column<T>> for ColumnRef {
+    fn from(column: Column<T>) -> Self {
+        ColumnRef::Static(column.name)
+    }
+}
+
+/// A bound filter built from a typed [`Column`], e.g. `User::AGE.gt(18)`.
+#[derive(Debug, Clone)]
+pub struct Condition(pub(crate) FilterExpr);
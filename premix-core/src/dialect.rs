@@ -1,3 +1,4 @@
+use crate::query::{Aggregate, LockMode, LockWait};
 use sqlx::Database;
 
 // Chapter 18: Multi-Database Support
@@ -14,6 +15,10 @@ where
     fn placeholder(n: usize) -> String;
     /// Returns the SQL fragment for an auto-incrementing Primary Key.
     fn auto_increment_pk() -> &'static str;
+    /// Returns the SQL fragment for an auto-incrementing 64-bit Primary Key.
+    fn auto_increment_bigint_pk() -> &'static str {
+        "BIGINT AUTO_INCREMENT PRIMARY KEY"
+    }
     /// Returns the number of rows affected by a query result.
     fn rows_affected(res: &Self::QueryResult) -> u64;
     /// Returns the ID of the last inserted row.
@@ -23,6 +28,24 @@ where
         false
     }
 
+    /// Returns true if `UPDATE`/`DELETE ... RETURNING *` can be used for
+    /// [`update_returning`](crate::QueryBuilder::update_returning) and
+    /// [`delete_returning`](crate::QueryBuilder::delete_returning).
+    fn supports_update_returning() -> bool {
+        Self::supports_returning()
+    }
+
+    /// Returns true if the database supports `NULLS FIRST` / `NULLS LAST` in `ORDER BY`.
+    fn supports_nulls_order() -> bool {
+        true
+    }
+
+    /// Returns true if keyset pagination should use a row-value comparison
+    /// (`(a, b) > (?, ?)`) instead of the expanded `a > ? OR (a = ? AND b > ?)` form.
+    fn supports_row_value_comparison() -> bool {
+        true
+    }
+
     /// Returns the SQL function code for getting the current timestamp.
     fn current_timestamp_fn() -> &'static str {
         "CURRENT_TIMESTAMP"
@@ -52,12 +75,160 @@ where
         "BLOB"
     }
 
+    /// Renders an aggregate call over an already-quoted argument (or `*`).
+    ///
+    /// `AVG` is cast to [`float_type`](Self::float_type) so it decodes as `f64`
+    /// everywhere instead of `NUMERIC`/`DECIMAL`.
+    fn render_aggregate(aggregate: Aggregate, argument: &str) -> String {
+        match aggregate {
+            Aggregate::Avg => format!("CAST(AVG({}) AS {})", argument, Self::float_type()),
+            other => format!("{}({})", other.function_name(), argument),
+        }
+    }
+
+    /// Renders a case-insensitive `LIKE` of an already-quoted column against a placeholder.
+    fn render_ilike(column: &str, placeholder: &str) -> String {
+        format!("LOWER({}) LIKE LOWER({})", column, placeholder)
+    }
+
+    /// Converts a `$.a.b[0]` JSON path into the form bound for
+    /// [`render_json_path_eq`](Self::render_json_path_eq).
+    fn json_path_arg(path: &str) -> String {
+        path.to_string()
+    }
+
+    /// Renders `<value at path> = value` for a JSON column. `path` and `value` are placeholders.
+    ///
+    /// Postgres uses `#>>` rather than `->>`: `->>` takes a single key, so a nested
+    /// path would need one placeholder per segment, while `#>>` takes the whole
+    /// path as one bound `text[]`.
+    fn render_json_path_eq(column: &str, path: &str, value: &str) -> String {
+        format!("json_extract({}, {}) = {}", column, path, value)
+    }
+
+    /// Renders a JSON containment check of `column` against a bound JSON document.
+    ///
+    /// The default follows `@>` one level deep with `json_each`: every member of an
+    /// object and every element of an array must appear in `column`, and a scalar
+    /// must be an element of (or equal to) `column`. Nested values compare exactly.
+    fn render_json_contains(column: &str, value: &str) -> String {
+        format!(
+            "EXISTS (SELECT 1 FROM (SELECT json({value}) AS want) AS arg WHERE \
+             CASE json_type(arg.want) \
+             WHEN 'object' THEN json_type({column}) = 'object' AND NOT EXISTS \
+             (SELECT 1 FROM json_each(arg.want) AS w WHERE NOT EXISTS (SELECT 1 FROM \
+             json_each({column}) AS h WHERE h.key = w.key AND h.value = w.value)) \
+             WHEN 'array' THEN json_type({column}) = 'array' AND NOT EXISTS \
+             (SELECT 1 FROM json_each(arg.want) AS w WHERE NOT EXISTS (SELECT 1 FROM \
+             json_each({column}) AS h WHERE h.value = w.value)) \
+             ELSE json_type({column}) <> 'object' AND EXISTS (SELECT 1 FROM \
+             json_each({column}) AS h WHERE h.value = json_extract(arg.want, '$')) END)"
+        )
+    }
+
+    /// Converts a plain-text search into the bound argument for
+    /// [`render_fulltext_match`](Self::render_fulltext_match).
+    ///
+    /// The default builds an FTS5 query: every word is quoted so user input cannot
+    /// inject FTS operators, and the search is limited to `columns`.
+    fn fulltext_query_arg(columns: &[&str], query: &str) -> String {
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect();
+        format!("{{{}}} : ({})", columns.join(" "), terms.join(" "))
+    }
+
+    /// Whether full-text matches are tied back to rows by an integer rowid, so
+    /// only models with a single integer key can use them.
+    fn fulltext_needs_rowid_key() -> bool {
+        true
+    }
+
+    /// Renders a full-text match of already-quoted `columns` on `table`, whose
+    /// key column is `primary_key`.
+    ///
+    /// The default matches against the model's FTS5 table (see
+    /// [`schema::fulltext_name`](crate::schema::fulltext_name)) by rowid.
+    fn render_fulltext_match(
+        table: &str,
+        primary_key: &str,
+        _columns: &[String],
+        query: &str,
+    ) -> String {
+        let fts = crate::schema::fulltext_name(table);
+        format!(
+            "{}.{} IN (SELECT rowid FROM {} WHERE {} MATCH {})",
+            Self::quote_identifier(table),
+            Self::quote_identifier(primary_key),
+            fts,
+            fts,
+            query
+        )
+    }
+
+    /// Renders an `ORDER BY` term (with direction) that sorts full-text matches best first.
+    fn render_fulltext_rank(
+        table: &str,
+        primary_key: &str,
+        _columns: &[String],
+        query: &str,
+    ) -> String {
+        let fts = crate::schema::fulltext_name(table);
+        // bm25() is lower for better matches.
+        format!(
+            "(SELECT bm25({}) FROM {} WHERE {} MATCH {} AND {}.rowid = {}.{}) ASC",
+            fts,
+            fts,
+            fts,
+            query,
+            fts,
+            Self::quote_identifier(table),
+            Self::quote_identifier(primary_key)
+        )
+    }
+
+    /// Renders the row-locking clause appended to a `SELECT`; empty when the
+    /// database has no row locks.
+    fn render_row_lock(mode: LockMode, wait: LockWait) -> String {
+        let mode = match mode {
+            LockMode::Update => "FOR UPDATE",
+            LockMode::Share => "FOR SHARE",
+        };
+        match wait {
+            LockWait::Wait => mode.to_string(),
+            LockWait::SkipLocked => format!("{} SKIP LOCKED", mode),
+            LockWait::NoWait => format!("{} NOWAIT", mode),
+        }
+    }
+
+    /// Returns the prefix that turns a `SELECT` into a query-plan request, or
+    /// `None` when `analyze` (run the query and report actual rows) is unsupported.
+    fn explain_prefix(analyze: bool) -> Option<&'static str> {
+        Some(if analyze {
+            "EXPLAIN ANALYZE "
+        } else {
+            "EXPLAIN FORMAT=JSON "
+        })
+    }
+
     /// Quotes an identifier (table/column name) to prevent SQL injection.
     fn quote_identifier(ident: &str) -> String {
         format!("`{}`", ident.replace('`', "``"))
     }
 }
 
+/// The `tsvector` expression shared by Postgres full-text filters and the GIN index
+/// created for `#[premix(fulltext)]`; they must match for the index to be used.
+#[cfg(feature = "postgres")]
+pub(crate) fn postgres_tsvector<S: AsRef<str>>(columns: &[S]) -> String {
+    let parts: Vec<String> = columns
+        .iter()
+        .map(|col| format!("COALESCE({}, '')", col.as_ref()))
+        .collect();
+    format!("to_tsvector('simple', {})", parts.join(" || ' ' || "))
+}
+
 #[cfg(feature = "sqlite")]
 impl SqlDialect for sqlx::Sqlite {
     fn placeholder(_n: usize) -> String {
@@ -66,6 +237,9 @@ impl SqlDialect for sqlx::Sqlite {
     fn auto_increment_pk() -> &'static str {
         "INTEGER PRIMARY KEY"
     }
+    fn auto_increment_bigint_pk() -> &'static str {
+        "INTEGER PRIMARY KEY"
+    }
     fn bigint_type() -> &'static str {
         "INTEGER"
     }
@@ -81,6 +255,17 @@ impl SqlDialect for sqlx::Sqlite {
     fn supports_returning() -> bool {
         false
     }
+    fn supports_update_returning() -> bool {
+        // RETURNING landed in SQLite 3.35; the sqlx-bundled library is newer.
+        true
+    }
+    fn explain_prefix(analyze: bool) -> Option<&'static str> {
+        (!analyze).then_some("EXPLAIN QUERY PLAN ")
+    }
+    fn render_row_lock(_mode: LockMode, _wait: LockWait) -> String {
+        // SQLite locks the whole database per write transaction; there are no row locks.
+        String::new()
+    }
 }
 
 #[cfg(feature = "postgres")]
@@ -91,6 +276,9 @@ impl SqlDialect for sqlx::Postgres {
     fn auto_increment_pk() -> &'static str {
         "SERIAL PRIMARY KEY"
     }
+    fn auto_increment_bigint_pk() -> &'static str {
+        "BIGSERIAL PRIMARY KEY"
+    }
     fn int_type() -> &'static str {
         "INTEGER"
     }
@@ -121,6 +309,65 @@ impl SqlDialect for sqlx::Postgres {
     fn quote_identifier(ident: &str) -> String {
         format!("\"{}\"", ident.replace('"', "\"\""))
     }
+    fn explain_prefix(analyze: bool) -> Option<&'static str> {
+        Some(if analyze {
+            "EXPLAIN (ANALYZE, FORMAT JSON) "
+        } else {
+            "EXPLAIN (FORMAT JSON) "
+        })
+    }
+    fn render_ilike(column: &str, placeholder: &str) -> String {
+        format!("{} ILIKE {}", column, placeholder)
+    }
+    fn json_path_arg(path: &str) -> String {
+        // `$.settings.tags[0]` -> `{"settings","tags","0"}` for `#>>`.
+        let keys: Vec<String> = path
+            .trim_start_matches('$')
+            .split(['.', '[', ']'])
+            .filter(|key| !key.is_empty())
+            .map(|key| format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\"")))
+            .collect();
+        format!("{{{}}}", keys.join(","))
+    }
+    fn render_json_path_eq(column: &str, path: &str, value: &str) -> String {
+        format!(
+            "{} #>> CAST({} AS TEXT[]) = CAST({} AS TEXT)",
+            column, path, value
+        )
+    }
+    fn render_json_contains(column: &str, value: &str) -> String {
+        format!("{} @> {}", column, value)
+    }
+    fn fulltext_query_arg(_columns: &[&str], query: &str) -> String {
+        query.to_string()
+    }
+    fn fulltext_needs_rowid_key() -> bool {
+        false
+    }
+    fn render_fulltext_match(
+        _table: &str,
+        _primary_key: &str,
+        columns: &[String],
+        query: &str,
+    ) -> String {
+        format!(
+            "{} @@ plainto_tsquery('simple', {})",
+            postgres_tsvector(columns),
+            query
+        )
+    }
+    fn render_fulltext_rank(
+        _table: &str,
+        _primary_key: &str,
+        columns: &[String],
+        query: &str,
+    ) -> String {
+        format!(
+            "ts_rank({}, plainto_tsquery('simple', {})) DESC",
+            postgres_tsvector(columns),
+            query
+        )
+    }
 }
 
 #[cfg(feature = "mysql")]
@@ -146,4 +393,56 @@ impl SqlDialect for sqlx::MySql {
     fn supports_returning() -> bool {
         false
     }
+    fn supports_nulls_order() -> bool {
+        false
+    }
+    fn supports_row_value_comparison() -> bool {
+        // MySQL accepts row constructors but does not use them for index range scans.
+        false
+    }
+    fn render_aggregate(aggregate: Aggregate, argument: &str) -> String {
+        match aggregate {
+            // MySQL only accepts DOUBLE (not REAL) as a CAST target.
+            Aggregate::Avg => format!("CAST(AVG({}) AS DOUBLE)", argument),
+            other => format!("{}({})", other.function_name(), argument),
+        }
+    }
+    fn render_json_path_eq(column: &str, path: &str, value: &str) -> String {
+        format!(
+            "JSON_UNQUOTE(JSON_EXTRACT({}, {})) = {}",
+            column, path, value
+        )
+    }
+    fn render_json_contains(column: &str, value: &str) -> String {
+        format!("JSON_CONTAINS({}, {})", column, value)
+    }
+    fn fulltext_query_arg(_columns: &[&str], query: &str) -> String {
+        query.to_string()
+    }
+    fn fulltext_needs_rowid_key() -> bool {
+        false
+    }
+    fn render_fulltext_match(
+        _table: &str,
+        _primary_key: &str,
+        columns: &[String],
+        query: &str,
+    ) -> String {
+        format!(
+            "MATCH ({}) AGAINST ({} IN NATURAL LANGUAGE MODE)",
+            columns.join(", "),
+            query
+        )
+    }
+    fn render_fulltext_rank(
+        table: &str,
+        primary_key: &str,
+        columns: &[String],
+        query: &str,
+    ) -> String {
+        format!(
+            "{} DESC",
+            Self::render_fulltext_match(table, primary_key, columns, query)
+        )
+    }
 }
@@ -9,6 +9,15 @@ pub enum PremixError {
     VersionConflict,
     /// Validation failed.
     Validation(Vec<ValidationError>),
+    /// A query named a column the model does not have.
+    UnknownColumn {
+        /// Table the query targets.
+        table: String,
+        /// The unknown column name.
+        column: String,
+        /// Closest known column, when one looks like a typo of `column`.
+        suggestion: Option<String>,
+    },
     /// Generic message error.
     Message(String),
 }
@@ -19,6 +28,17 @@ impl std::fmt::Display for PremixError {
             Self::Sqlx(err) => write!(f, "sqlx error: {}", err),
             Self::VersionConflict => write!(f, "version conflict"),
             Self::Validation(errors) => write!(f, "validation failed ({} errors)", errors.len()),
+            Self::UnknownColumn {
+                table,
+                column,
+                suggestion,
+            } => {
+                write!(f, "unknown column '{}' on table {}", column, table)?;
+                match suggestion {
+                    Some(suggestion) => write!(f, "; did you mean '{}'?", suggestion),
+                    None => Ok(()),
+                }
+            }
             Self::Message(message) => write!(f, "{}", message),
         }
     }
@@ -38,11 +58,45 @@ impl From<Vec<ValidationError>> for PremixError {
     }
 }
 
+/// The query builder's unknown-column failure, carried inside
+/// `sqlx::Error::Configuration` until [`map_sqlx_error`] unwraps it.
+#[derive(Debug)]
+pub(crate) struct UnknownColumn {
+    pub(crate) table: String,
+    pub(crate) column: String,
+    pub(crate) suggestion: Option<String>,
+}
+
+impl std::fmt::Display for UnknownColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown column '{}' on table {}",
+            self.column, self.table
+        )?;
+        match &self.suggestion {
+            Some(suggestion) => write!(f, "; did you mean '{}'?", suggestion),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for UnknownColumn {}
+
 /// Result alias for Premix operations.
 pub type PremixResult<T> = Result<T, PremixError>;
 
 /// Convert sqlx errors to actionable Premix errors when possible.
 pub fn map_sqlx_error(err: sqlx::Error) -> PremixError {
+    if let sqlx::Error::Configuration(source) = &err
+        && let Some(unknown) = source.downcast_ref::<UnknownColumn>()
+    {
+        return PremixError::UnknownColumn {
+            table: unknown.table.clone(),
+            column: unknown.column.clone(),
+            suggestion: unknown.suggestion.clone(),
+        };
+    }
     if let sqlx::Error::Protocol(message) = &err {
         let message = message.to_ascii_lowercase();
         if message.contains("premix save failed: version conflict") {
@@ -0,0 +1,389 @@
+use serde_json::Value;
+use sqlx::{Column, Database, Row};
+
+/// A query plan captured by [`QueryBuilder::explain`](crate::QueryBuilder::explain)
+/// or [`QueryBuilder::explain_analyze`](crate::QueryBuilder::explain_analyze).
+///
+/// The database output is normalized into a flat list of [`PlanNode`]s (parents
+/// before children) so tests can assert on index usage without parsing
+/// dialect-specific text:
+///
+/// ```ignore
+/// let plan = User::find_in_pool(&pool).filter_eq("email", email).explain().await?;
+/// assert!(!plan.has_full_scan(), "{}", plan.raw);
+/// assert!(plan.uses_index("idx_users_email"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueryPlan {
+    /// The explained `SELECT`, without the `EXPLAIN` prefix.
+    pub sql: String,
+    /// Plan steps in output order; `depth` gives the nesting.
+    pub nodes: Vec<PlanNode>,
+    /// The unparsed plan text (JSON on Postgres/MySQL, one line per step on SQLite).
+    pub raw: String,
+}
+
+/// One step of a [`QueryPlan`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlanNode {
+    /// Human-readable description, e.g. `SCAN users` or `Index Scan on users using users_pkey`.
+    pub detail: String,
+    /// Table read by this step, if it reads one.
+    pub table: Option<String>,
+    /// Index used by this step, if any.
+    pub index: Option<String>,
+    /// True when the step reads every row of `table` without an index.
+    pub full_scan: bool,
+    /// Nesting level; 0 for top-level steps.
+    pub depth: usize,
+    /// Rows the planner expects this step to produce, when reported.
+    pub estimated_rows: Option<f64>,
+    /// Rows actually produced, reported by `explain_analyze`.
+    pub actual_rows: Option<f64>,
+}
+
+impl QueryPlan {
+    /// Returns true if any step scans a whole table.
+    pub fn has_full_scan(&self) -> bool {
+        self.nodes.iter().any(|node| node.full_scan)
+    }
+
+    /// Steps that scan a whole table.
+    pub fn full_scans(&self) -> impl Iterator<Item = &PlanNode> {
+        self.nodes.iter().filter(|node| node.full_scan)
+    }
+
+    /// Returns true if any step uses the index `name`.
+    pub fn uses_index(&self, name: &str) -> bool {
+        self.nodes
+            .iter()
+            .any(|node| node.index.as_deref() == Some(name))
+    }
+}
+
+/// Builds a [`QueryPlan`] from the rows returned by the dialect's `EXPLAIN`.
+///
+/// SQLite's `EXPLAIN QUERY PLAN` returns one `(id, parent, notused, detail)` row
+/// per step. The other databases return a single text or JSON column: a JSON
+/// document for `FORMAT JSON`, or MySQL's indented tree for `EXPLAIN ANALYZE`.
+pub(crate) fn read_plan<DB>(sql: String, rows: &[DB::Row]) -> Result<QueryPlan, sqlx::Error>
+where
+    DB: Database,
+    for<'c> &'c str: sqlx::ColumnIndex<DB::Row>,
+    i64: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    String: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    sqlx::types::Json<Value>: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+{
+    if rows
+        .first()
+        .is_some_and(|row| row.try_get::<String, _>("detail").is_ok())
+    {
+        let steps = rows
+            .iter()
+            .map(|row| {
+                Ok((
+                    row.try_get::<i64, _>("id")?,
+                    row.try_get::<i64, _>("parent")?,
+                    row.try_get::<String, _>("detail")?,
+                ))
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()?;
+        let raw = steps
+            .iter()
+            .map(|(_, _, detail)| detail.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Ok(QueryPlan {
+            sql,
+            nodes: parse_sqlite_plan(&steps),
+            raw,
+        });
+    }
+
+    let mut raw = String::new();
+    for row in rows {
+        let Some(column) = row.columns().first() else {
+            continue;
+        };
+        let text = match row.try_get::<String, _>(column.name()) {
+            Ok(text) => text,
+            Err(_) => row
+                .try_get::<sqlx::types::Json<Value>, _>(column.name())?
+                .0
+                .to_string(),
+        };
+        if !raw.is_empty() {
+            raw.push('\n');
+        }
+        raw.push_str(&text);
+    }
+    let nodes = match serde_json::from_str::<Value>(&raw) {
+        Ok(json) => parse_json_plan(&json),
+        Err(_) => parse_tree_plan(&raw),
+    };
+    Ok(QueryPlan { sql, nodes, raw })
+}
+
+/// Parses SQLite `EXPLAIN QUERY PLAN` steps, e.g. `SCAN users` or
+/// `SEARCH users USING INDEX idx_users_name (name=?)`.
+fn parse_sqlite_plan(steps: &[(i64, i64, String)]) -> Vec<PlanNode> {
+    let mut depths: Vec<(i64, usize)> = Vec::with_capacity(steps.len());
+    steps
+        .iter()
+        .map(|(id, parent, detail)| {
+            let depth = depths
+                .iter()
+                .find(|(step, _)| step == parent)
+                .map_or(0, |(_, depth)| depth + 1);
+            depths.push((*id, depth));
+
+            let (scan, rest) = match detail.split_once(' ') {
+                Some((verb @ ("SCAN" | "SEARCH"), rest)) => (verb == "SCAN", rest),
+                _ => {
+                    return PlanNode {
+                        detail: detail.clone(),
+                        depth,
+                        ..PlanNode::default()
+                    };
+                }
+            };
+            // Older SQLite versions print `SCAN TABLE users`.
+            let rest = rest.strip_prefix("TABLE ").unwrap_or(rest);
+            let table = rest
+                .split_whitespace()
+                .next()
+                .filter(|name| !name.starts_with('(') && *name != "CONSTANT");
+            let index = if rest.contains(" USING INTEGER PRIMARY KEY") {
+                Some("INTEGER PRIMARY KEY".to_string())
+            } else {
+                rest.split_once(" INDEX ")
+                    .and_then(|(_, after)| after.split_whitespace().next().map(str::to_string))
+            };
+            PlanNode {
+                detail: detail.clone(),
+                table: table.map(str::to_string),
+                full_scan: scan && table.is_some() && index.is_none(),
+                index,
+                depth,
+                ..PlanNode::default()
+            }
+        })
+        .collect()
+}
+
+/// Parses `EXPLAIN (FORMAT JSON)` from Postgres or `EXPLAIN FORMAT=JSON` from MySQL.
+fn parse_json_plan(json: &Value) -> Vec<PlanNode> {
+    let mut nodes = Vec::new();
+    match json {
+        Value::Array(plans) => {
+            for plan in plans {
+                if let Some(root) = plan.get("Plan") {
+                    walk_postgres(root, 0, &mut nodes);
+                }
+            }
+        }
+        Value::Object(_) => walk_mysql(json, 0, &mut nodes),
+        _ => {}
+    }
+    nodes
+}
+
+fn walk_postgres(plan: &Value, depth: usize, nodes: &mut Vec<PlanNode>) {
+    let text = |key: &str| plan.get(key).and_then(Value::as_str).map(str::to_string);
+    let node_type = text("Node Type").unwrap_or_default();
+    let table = text("Relation Name");
+    let index = text("Index Name");
+
+    let mut detail = node_type.clone();
+    if let Some(table) = &table {
+        detail.push_str(" on ");
+        detail.push_str(table);
+    }
+    if let Some(index) = &index {
+        detail.push_str(" using ");
+        detail.push_str(index);
+    }
+    nodes.push(PlanNode {
+        detail,
+        full_scan: node_type == "Seq Scan",
+        table,
+        index,
+        depth,
+        estimated_rows: plan.get("Plan Rows").and_then(Value::as_f64),
+        actual_rows: plan.get("Actual Rows").and_then(Value::as_f64),
+    });
+    for child in plan
+        .get("Plans")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        walk_postgres(child, depth + 1, nodes);
+    }
+}
+
+/// MySQL nests `table` objects under `query_block`, `nested_loop`,
+/// `ordering_operation`, subqueries, and so on; every `table` is one step.
+fn walk_mysql(json: &Value, depth: usize, nodes: &mut Vec<PlanNode>) {
+    match json {
+        Value::Object(map) => {
+            for (key, value) in map {
+                if key == "table" && value.get("table_name").is_some() {
+                    let text = |key: &str| value.get(key).and_then(Value::as_str);
+                    let table = text("table_name").map(str::to_string);
+                    let access = text("access_type").unwrap_or_default();
+                    let index = text("key").map(str::to_string);
+                    let mut detail = format!("{} on {}", access, table.as_deref().unwrap_or(""));
+                    if let Some(index) = &index {
+                        detail.push_str(" using ");
+                        detail.push_str(index);
+                    }
+                    nodes.push(PlanNode {
+                        detail,
+                        full_scan: access == "ALL",
+                        table,
+                        index,
+                        depth,
+                        estimated_rows: value.get("rows_examined_per_scan").and_then(Value::as_f64),
+                        actual_rows: None,
+                    });
+                    walk_mysql(value, depth + 1, nodes);
+                } else {
+                    walk_mysql(value, depth, nodes);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                walk_mysql(item, depth, nodes);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Parses MySQL's `EXPLAIN ANALYZE` tree, e.g.
+/// `-> Index lookup on users using idx_name (name='a')  (cost=0.35 rows=1) (actual ... rows=1 loops=1)`.
+fn parse_tree_plan(text: &str) -> Vec<PlanNode> {
+    text.lines()
+        .filter_map(|line| {
+            let trimmed = line.trim_start();
+            let step = trimmed.strip_prefix("-> ")?;
+            let depth = (line.len() - trimmed.len()) / 4;
+            let detail = step.split("  (").next().unwrap_or(step).to_string();
+
+            let (table, index) = match detail.split_once(" on ") {
+                Some((_, target)) => {
+                    let mut words = target.split_whitespace();
+                    let table = words.next().map(str::to_string);
+                    let index = match (words.next(), words.next()) {
+                        (Some("using"), Some(index)) => Some(index.to_string()),
+                        _ => None,
+                    };
+                    (table, index)
+                }
+                None => (None, None),
+            };
+            let rows_after = |marker: &str| {
+                step.split_once(marker).and_then(|(_, rest)| {
+                    rest.split([' ', ')'])
+                        .find_map(|part| part.strip_prefix("rows="))
+                        .and_then(|rows| rows.parse().ok())
+                })
+            };
+            Some(PlanNode {
+                full_scan: detail.starts_with("Table scan on "),
+                estimated_rows: rows_after("(cost="),
+                actual_rows: rows_after("(actual "),
+                detail,
+                table,
+                index,
+                depth,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqlite_plan_flags_scans_without_index() {
+        let nodes = parse_sqlite_plan(&[
+            (2, 0, "SCAN posts".to_string()),
+            (
+                5,
+                0,
+                "SEARCH users USING INDEX idx_users_name (name=?)".to_string(),
+            ),
+            (
+                9,
+                5,
+                "SEARCH users USING INTEGER PRIMARY KEY (rowid=?)".to_string(),
+            ),
+            (12, 0, "USE TEMP B-TREE FOR ORDER BY".to_string()),
+        ]);
+        assert!(nodes[0].full_scan);
+        assert_eq!(nodes[0].table.as_deref(), Some("posts"));
+        assert!(!nodes[1].full_scan);
+        assert_eq!(nodes[1].index.as_deref(), Some("idx_users_name"));
+        assert_eq!(nodes[2].depth, 1);
+        assert_eq!(nodes[2].index.as_deref(), Some("INTEGER PRIMARY KEY"));
+        assert_eq!(nodes[3].table, None);
+        assert!(!nodes[3].full_scan);
+    }
+
+    #[test]
+    fn postgres_json_plan_walks_children() {
+        let json = serde_json::json!([{
+            "Plan": {
+                "Node Type": "Nested Loop",
+                "Plan Rows": 5,
+                "Plans": [
+                    {"Node Type": "Seq Scan", "Relation Name": "posts", "Plan Rows": 5, "Actual Rows": 4},
+                    {"Node Type": "Index Scan", "Relation Name": "users", "Index Name": "users_pkey"}
+                ]
+            }
+        }]);
+        let nodes = parse_json_plan(&json);
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[1].detail, "Seq Scan on posts");
+        assert!(nodes[1].full_scan);
+        assert_eq!(nodes[1].actual_rows, Some(4.0));
+        assert_eq!(nodes[2].depth, 1);
+        assert_eq!(nodes[2].index.as_deref(), Some("users_pkey"));
+        assert!(!nodes[2].full_scan);
+    }
+
+    #[test]
+    fn mysql_json_plan_reads_access_type() {
+        let json = serde_json::json!({
+            "query_block": {
+                "select_id": 1,
+                "nested_loop": [
+                    {"table": {"table_name": "posts", "access_type": "ALL", "rows_examined_per_scan": 10}},
+                    {"table": {"table_name": "users", "access_type": "eq_ref", "key": "PRIMARY"}}
+                ]
+            }
+        });
+        let nodes = parse_json_plan(&json);
+        assert_eq!(nodes.len(), 2);
+        assert!(nodes[0].full_scan);
+        assert_eq!(nodes[0].estimated_rows, Some(10.0));
+        assert_eq!(nodes[1].index.as_deref(), Some("PRIMARY"));
+    }
+
+    #[test]
+    fn mysql_tree_plan_reads_analyze_output() {
+        let text = "-> Nested loop inner join  (cost=4.5 rows=10) (actual time=0.1..0.2 rows=3 loops=1)\n    -> Table scan on posts  (cost=1.25 rows=10) (actual time=0.05..0.07 rows=10 loops=1)\n    -> Single-row index lookup on users using PRIMARY (id=posts.user_id)  (cost=0.25 rows=1) (actual time=0.01..0.01 rows=1 loops=10)";
+        let nodes = parse_tree_plan(text);
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[0].actual_rows, Some(3.0));
+        assert!(nodes[1].full_scan);
+        assert_eq!(nodes[1].depth, 1);
+        assert_eq!(nodes[1].estimated_rows, Some(10.0));
+        assert_eq!(nodes[2].table.as_deref(), Some("users"));
+        assert_eq!(nodes[2].index.as_deref(), Some("PRIMARY"));
+    }
+}
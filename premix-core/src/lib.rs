@@ -13,8 +13,10 @@
 //! - **Model**: The core trait that all database entities must implement.
 //! - **Executor**: Abstracts over connection pools and transactions.
 //! - **QueryBuilder**: A type-safe SQL query generator with support for:
-//!   - Filtering (eq, ne, gt, lt, in)
-//!   - Pagination (limit, offset)
+//!   - Filtering (eq, ne, gt, lt, in, OR/NOT groups)
+//!   - Ordering (order_by, NULLS FIRST/LAST)
+//!   - Pagination (limit, offset, keyset cursors)
+//!   - Aggregates (GROUP BY, HAVING, COUNT/SUM/AVG/MIN/MAX)
 //!   - Relations (eager loading)
 //!   - Soft Deletes
 //!
@@ -27,8 +29,14 @@ pub use tracing;
 pub use uuid;
 
 // New Modules
+/// Typed column handles for compile-time checked filters.
+pub mod column;
+pub use column::{Column, Condition};
 /// SQL dialect abstractions for multi-database support.
 pub mod dialect;
+/// Query plan capture (`EXPLAIN`) and analysis.
+pub mod explain;
+pub use explain::{PlanNode, QueryPlan};
 /// Database executor abstraction for connection pools and transactions.
 pub mod executor;
 /// Database migration engine.
@@ -43,11 +51,18 @@ pub mod metrics;
 /// Core traits and types for database models.
 pub mod model;
 pub use model::{
-    FastRow, Model, ModelHooks, ModelValidation, Relation, UpdateResult, ValidationError,
+    FastRow, Model, ModelHooks, ModelValidation, PrimaryKey, Relation, RelationJoin, UpdateResult,
+    ValidationError,
 };
+/// Table naming strategies shared by `#[derive(Model)]` and the CLI.
+pub mod naming;
+pub use naming::NamingStrategy;
+/// Cursor and page-based pagination types.
+pub mod pagination;
+pub use pagination::{CursorPage, Page};
 /// Type-safe SQL query builder.
 pub mod query;
-pub use query::QueryBuilder;
+pub use query::{Aggregate, BindValue, FilterGroup, LockMode, LockWait, QueryBuilder, UpdateSet};
 /// Database schema introspection and diffing utilities.
 pub mod schema;
 pub use schema::ModelSchema;
@@ -81,7 +96,7 @@ impl Premix {
         for<'c> &'c sqlx::Pool<DB>: sqlx::Executor<'c, Database = DB>,
     {
         let schema = T::schema();
-        let sql = schema.to_create_sql();
+        let sql = schema.to_create_sql_for::<DB>();
         use sqlx::Executor;
         pool.execute(sql.as_str()).await?;
         Ok(())
@@ -96,23 +111,116 @@ pub fn build_placeholders<DB: crate::dialect::SqlDialect>(start: usize, count: u
         .join(", ")
 }
 
+/// Helper to build a comma-separated list of quoted column names.
+pub fn build_column_list<DB: crate::dialect::SqlDialect>(columns: &[&str]) -> String {
+    columns
+        .iter()
+        .map(|column| DB::quote_identifier(column))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Helper to build a `col = placeholder AND ...` condition over quoted primary key
+/// columns, numbering placeholders from `start`.
+pub fn build_key_condition<DB: crate::dialect::SqlDialect>(
+    columns: &[&str],
+    start: usize,
+) -> String {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            format!(
+                "{} = {}",
+                DB::quote_identifier(column),
+                DB::placeholder(start + i)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
 pub use dialect::SqlDialect;
 pub use executor::{Executor, IntoExecutor};
 
+/// Support code for macro-generated items; not a public API.
+#[doc(hidden)]
+pub mod __private {
+    /// Total byte length of `parts`.
+    pub const fn concat_len(parts: &[&str]) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            len += parts[i].len();
+            i += 1;
+        }
+        len
+    }
+
+    /// Joins `parts` into an array of exactly `N` bytes (`N` = [`concat_len`]).
+    pub const fn concat_bytes<const N: usize>(parts: &[&str]) -> [u8; N] {
+        let mut out = [0u8; N];
+        let mut pos = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            let bytes = parts[i].as_bytes();
+            let mut j = 0;
+            while j < bytes.len() {
+                out[pos] = bytes[j];
+                pos += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+        assert!(pos == N, "concat_bytes length mismatch");
+        out
+    }
+
+    /// Whether `sql` has a `?` or `$n` placeholder outside quoted strings and
+    /// identifiers.
+    pub fn has_sql_placeholder(sql: &str) -> bool {
+        let bytes = sql.as_bytes();
+        let mut quote = None;
+        for (pos, &byte) in bytes.iter().enumerate() {
+            match (quote, byte) {
+                (None, b'\'' | b'"' | b'`') => quote = Some(byte),
+                (Some(open), _) if open == byte => quote = None,
+                (None, b'?') => return true,
+                (None, b'$') if bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) => {
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// `str::from_utf8` for bytes joined from `&str` parts.
+    pub const fn str_from_utf8(bytes: &[u8]) -> &str {
+        match std::str::from_utf8(bytes) {
+            Ok(value) => value,
+            Err(_) => panic!("joined SQL is not UTF-8"),
+        }
+    }
+}
+
 // Prelude
 /// The Premix prelude, re-exporting commonly used traits and types.
 pub mod prelude {
     pub use crate::Premix;
     pub use crate::build_placeholders;
+    pub use crate::column::{Column, Condition};
     pub use crate::dialect::SqlDialect;
     pub use crate::error::{PremixError, PremixResult, map_sqlx_error};
     pub use crate::executor::{Executor, IntoExecutor};
+    pub use crate::explain::{PlanNode, QueryPlan};
     pub use crate::migrator::{Migration, Migrator};
     pub use crate::model::{
-        FastRow, Model, ModelHooks, ModelResultExt, ModelValidation, Relation, UpdateResult,
-        ValidationError,
+        FastRow, Model, ModelHooks, ModelResultExt, ModelValidation, PrimaryKey, Relation,
+        UpdateResult, ValidationError,
     };
-    pub use crate::query::QueryBuilder;
+    pub use crate::pagination::{CursorPage, Page};
+    pub use crate::query::{Aggregate, BindValue, FilterGroup, QueryBuilder, UpdateSet};
     pub use crate::schema::ModelSchema;
     pub use crate::schema_models;
     pub use crate::sql_cache::{cached_placeholders, cached_placeholders_from};
@@ -2,7 +2,8 @@ use crate::dialect::SqlDialect;
 use crate::error::{PremixError, PremixResult};
 use crate::executor::Executor;
 use crate::executor::IntoExecutor;
-use crate::query::QueryBuilder;
+use crate::query::{BindValue, QueryBuilder};
+use crate::schema::ModelSchema;
 use serde_json::Value;
 use sqlx::{Database, FromRow};
 use std::future::Future;
@@ -64,22 +65,81 @@ where
     }
 }
 
-/// Typed relation handle for eager loading.
+/// Typed relation handle for eager loading and joins.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Relation {
     name: &'static str,
+    join: Option<RelationJoin>,
+}
+
+/// How a relation's table is joined: `table.column = owner_table.local_column`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelationJoin {
+    /// The related table.
+    pub table: &'static str,
+    /// The join column on the related table.
+    pub column: &'static str,
+    /// The join column on the owning model's table.
+    pub local_column: &'static str,
+    /// The related table's columns; empty skips checking `(table, column)` filters.
+    pub columns: &'static [&'static str],
+    /// Whether the related model is soft-deleted, so the join skips deleted rows.
+    pub soft_delete: bool,
 }
 
 impl Relation {
     /// Creates a new relation handle from a static name.
     pub const fn new(name: &'static str) -> Self {
-        Self { name }
+        Self { name, join: None }
+    }
+
+    /// Creates a relation handle that can also be used with `QueryBuilder::join`.
+    pub const fn with_join(
+        name: &'static str,
+        table: &'static str,
+        column: &'static str,
+        local_column: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            join: Some(RelationJoin {
+                table,
+                column,
+                local_column,
+                columns: &[],
+                soft_delete: false,
+            }),
+        }
+    }
+
+    /// Like [`with_join`](Self::with_join), taking the table, columns, and
+    /// soft-delete flag from the related model `M`.
+    pub const fn with_model_join<M: ModelSchema>(
+        name: &'static str,
+        column: &'static str,
+        local_column: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            join: Some(RelationJoin {
+                table: M::TABLE_NAME,
+                column,
+                local_column,
+                columns: M::COLUMNS,
+                soft_delete: M::SOFT_DELETE,
+            }),
+        }
     }
 
     /// Returns the relation name.
     pub const fn name(&self) -> &'static str {
         self.name
     }
+
+    /// Returns the join metadata, if the relation was declared with it.
+    pub const fn join_info(&self) -> Option<RelationJoin> {
+        self.join
+    }
 }
 
 impl From<Relation> for String {
@@ -112,6 +172,104 @@ pub trait ModelValidation {
     }
 }
 
+/// A type usable as a model's primary key: `i32`, `i64`, `String` or `Uuid`, or a
+/// tuple of those for a composite key.
+pub trait PrimaryKey: Clone + Eq + Ord + std::hash::Hash + Send + Sync + Unpin + 'static {
+    /// Whether the key is still unset (`0`, empty, or nil). `save()` inserts such
+    /// rows without the key so the database can generate it.
+    fn is_unset(&self) -> bool;
+
+    /// Converts an auto-increment id reported by the database, or `None` when this
+    /// key type is not generated that way.
+    fn from_generated_id(id: i64) -> Option<Self>;
+
+    /// The key's values, one per key column.
+    fn into_bind_values(self) -> Vec<BindValue>;
+}
+
+impl PrimaryKey for i32 {
+    fn is_unset(&self) -> bool {
+        *self == 0
+    }
+
+    fn from_generated_id(id: i64) -> Option<Self> {
+        i32::try_from(id).ok()
+    }
+
+    fn into_bind_values(self) -> Vec<BindValue> {
+        vec![self.into()]
+    }
+}
+
+impl PrimaryKey for i64 {
+    fn is_unset(&self) -> bool {
+        *self == 0
+    }
+
+    fn from_generated_id(id: i64) -> Option<Self> {
+        Some(id)
+    }
+
+    fn into_bind_values(self) -> Vec<BindValue> {
+        vec![self.into()]
+    }
+}
+
+impl PrimaryKey for String {
+    fn is_unset(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn from_generated_id(_id: i64) -> Option<Self> {
+        None
+    }
+
+    fn into_bind_values(self) -> Vec<BindValue> {
+        vec![self.into()]
+    }
+}
+
+impl PrimaryKey for uuid::Uuid {
+    fn is_unset(&self) -> bool {
+        self.is_nil()
+    }
+
+    fn from_generated_id(_id: i64) -> Option<Self> {
+        None
+    }
+
+    fn into_bind_values(self) -> Vec<BindValue> {
+        vec![self.into()]
+    }
+}
+
+// Composite keys are never generated by the database, so they always count as set.
+macro_rules! impl_composite_primary_key {
+    ($($name:ident),+) => {
+        impl<$($name: PrimaryKey),+> PrimaryKey for ($($name,)+) {
+            fn is_unset(&self) -> bool {
+                false
+            }
+
+            fn from_generated_id(_id: i64) -> Option<Self> {
+                None
+            }
+
+            #[allow(non_snake_case)]
+            fn into_bind_values(self) -> Vec<BindValue> {
+                let ($($name,)+) = self;
+                let mut values = Vec::new();
+                $(values.extend($name.into_bind_values());)+
+                values
+            }
+        }
+    };
+}
+
+impl_composite_primary_key!(A, B);
+impl_composite_primary_key!(A, B, C);
+impl_composite_primary_key!(A, B, C, D);
+
 /// The core trait for database models.
 ///
 /// This trait provides the foundation for all database interactions for a specific entity.
@@ -121,12 +279,32 @@ where
     DB: SqlDialect,
     for<'r> Self: FromRow<'r, DB::Row>,
 {
+    /// The primary key type: the `id` field's type, the `#[premix(primary_key)]` field's,
+    /// or a tuple of them when several fields make up the key.
+    type Id: PrimaryKey;
+
     /// Returns the name of the database table associated with this model.
     fn table_name() -> &'static str;
+    /// Returns the primary key column (the first one for a composite key).
+    fn primary_key() -> &'static str {
+        "id"
+    }
+    /// Returns every primary key column, in key order.
+    fn primary_key_columns() -> &'static [&'static str] {
+        &["id"]
+    }
+    /// Returns this instance's primary key.
+    fn primary_key_value(&self) -> Self::Id;
     /// Returns the SQL string required to create the table for this model.
     fn create_table_sql() -> String;
     /// Returns a list of column names for this model.
     fn list_columns() -> Vec<String>;
+    /// Returns the column names as a static list, used to check query columns.
+    ///
+    /// The derive fills this in; an empty list skips the checks.
+    fn column_names() -> &'static [&'static str] {
+        &[]
+    }
 
     /// Saves the current instance to the database.
     fn save<'a, E>(
@@ -226,6 +404,13 @@ where
         &[]
     }
 
+    /// Returns the managed `updated_at` column and the current time to store in it.
+    ///
+    /// Bulk updates assign this unless [`QueryBuilder::without_timestamps`] is used.
+    fn touch_updated_at() -> Option<(&'static str, BindValue)> {
+        None
+    }
+
     /// Returns the relation names available for eager loading.
     fn relation_names() -> &'static [&'static str] {
         &[]
@@ -239,7 +424,7 @@ where
     /// Finds a record by its Primary Key.
     fn find_by_id<'a, E>(
         executor: E,
-        id: i32,
+        id: Self::Id,
     ) -> impl Future<Output = Result<Option<Self>, sqlx::Error>> + Send
     where
         E: IntoExecutor<'a, DB = DB>;
@@ -313,10 +498,21 @@ where
         async move { Self::find(executor).all().await }
     }
 
+    /// Finds a record by its primary key, a tuple for composite keys (alias for [`find_by_id`]).
+    fn find_by_key<'a, E>(
+        executor: E,
+        key: Self::Id,
+    ) -> impl Future<Output = Result<Option<Self>, sqlx::Error>> + Send
+    where
+        E: IntoExecutor<'a, DB = DB>,
+    {
+        Self::find_by_id(executor, key)
+    }
+
     /// Finds a record by its primary key (alias for [`find_by_id`]).
     fn find_one<'a, E>(
         executor: E,
-        id: i32,
+        id: Self::Id,
     ) -> impl Future<Output = Result<Option<Self>, sqlx::Error>> + Send
     where
         E: IntoExecutor<'a, DB = DB>,
@@ -341,7 +537,7 @@ where
     /// Applies a JSON patch update by primary key.
     fn update_by_id<'a, E>(
         executor: E,
-        id: i32,
+        id: Self::Id,
         json_patch: Value,
     ) -> impl Future<Output = Result<u64, sqlx::Error>> + Send
     where
@@ -361,11 +557,41 @@ where
         chrono::NaiveDateTime: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
         chrono::NaiveDate: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
         sqlx::types::Json<serde_json::Value>: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    {
+        async move { Self::find(executor).filter_key(id).update(json_patch).await }
+    }
+
+    /// Loads a record by primary key and locks it with `SELECT ... FOR UPDATE`.
+    ///
+    /// Takes the transaction itself because the lock is held until it commits or
+    /// rolls back; on an autocommit connection it would be released immediately.
+    /// On SQLite this is a plain read.
+    fn lock_by_id<'a, 't>(
+        tx: &'a mut sqlx::Transaction<'t, DB>,
+        id: Self::Id,
+    ) -> impl Future<Output = Result<Option<Self>, sqlx::Error>> + Send + 'a
+    where
+        for<'q> <DB as Database>::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+        for<'c> &'c mut <DB as Database>::Connection: sqlx::Executor<'c, Database = DB>,
+        for<'c> &'c str: sqlx::ColumnIndex<DB::Row>,
+        DB::Connection: Send,
+        Self: Send,
+        String: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        i64: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        f64: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        bool: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        Option<String>: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        uuid::Uuid: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        chrono::DateTime<chrono::Utc>: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        chrono::NaiveDateTime: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        chrono::NaiveDate: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+        sqlx::types::Json<serde_json::Value>: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
     {
         async move {
-            Self::find(executor)
-                .filter_eq("id", id)
-                .update(json_patch)
+            Self::find_in_tx(&mut **tx)
+                .filter_key(id)
+                .for_update()
+                .first()
                 .await
         }
     }
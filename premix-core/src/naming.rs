@@ -0,0 +1,253 @@
+/// How `#[derive(Model)]` derives a table name from a struct name.
+///
+/// The strategy is set once per crate in its `Cargo.toml` and applies to every
+/// model without an explicit `#[premix(table = "...")]`:
+///
+/// ```toml
+/// [package.metadata.premix]
+/// naming = "plural"   # UserProfile -> user_profiles, Category -> categories
+/// ```
+///
+/// The `premix` CLI reads the same setting when it scans models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NamingStrategy {
+    /// The lowercased struct name plus `s` (`UserProfile` -> `userprofiles`).
+    /// Used when no strategy is configured, so existing schemas keep their names.
+    #[default]
+    Legacy,
+    /// snake_case with English pluralization (`Category` -> `categories`).
+    Plural,
+    /// snake_case, singular (`UserProfile` -> `user_profile`).
+    Singular,
+}
+
+impl NamingStrategy {
+    /// Parses a `naming` value: `"legacy"`, `"plural"`, or `"singular"`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "legacy" => Some(Self::Legacy),
+            "plural" => Some(Self::Plural),
+            "singular" => Some(Self::Singular),
+            _ => None,
+        }
+    }
+
+    /// Reads `naming` from the `[package.metadata.premix]` table of a `Cargo.toml`.
+    ///
+    /// A missing entry means [`Legacy`](Self::Legacy); an unknown value is an error.
+    pub fn from_manifest(manifest: &str) -> Result<Self, String> {
+        let mut in_section = false;
+        for line in manifest.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.starts_with('[') {
+                in_section = line == "[package.metadata.premix]";
+                continue;
+            }
+            if !in_section {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=')
+                && key.trim() == "naming"
+            {
+                let value = value.trim().trim_matches('"');
+                return Self::parse(value).ok_or_else(|| {
+                    format!(
+                        "unknown premix naming strategy '{}' (expected \"legacy\", \"plural\" or \"singular\")",
+                        value
+                    )
+                });
+            }
+        }
+        Ok(Self::Legacy)
+    }
+
+    /// The table name for a model struct.
+    pub fn table_name(self, struct_name: &str) -> String {
+        match self {
+            Self::Legacy => format!("{}s", struct_name.to_lowercase()),
+            Self::Plural => pluralize(&to_snake_case(struct_name)),
+            Self::Singular => to_snake_case(struct_name),
+        }
+    }
+
+    /// The foreign-key column pointing at a model, e.g. `user_id` for `User`.
+    pub fn foreign_key(self, struct_name: &str) -> String {
+        match self {
+            Self::Legacy => format!("{}_id", struct_name.to_lowercase()),
+            Self::Plural | Self::Singular => format!("{}_id", to_snake_case(struct_name)),
+        }
+    }
+}
+
+/// Converts a CamelCase name to snake_case (`HTTPRequest` -> `http_request`).
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                Some(prev) if prev.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+                _ => false,
+            };
+            if boundary && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Pluralizes the last word of a snake_case name (`user_category` -> `user_categories`).
+pub fn pluralize(name: &str) -> String {
+    let (stem, word) = match name.rfind('_') {
+        Some(pos) => name.split_at(pos + 1),
+        None => ("", name),
+    };
+    format!("{}{}", stem, pluralize_word(word))
+}
+
+fn pluralize_word(word: &str) -> String {
+    const UNCOUNTABLE: &[&str] = &[
+        "data",
+        "equipment",
+        "feedback",
+        "fish",
+        "information",
+        "metadata",
+        "news",
+        "series",
+        "sheep",
+        "species",
+    ];
+    const IRREGULAR: &[(&str, &str)] = &[
+        ("child", "children"),
+        ("foot", "feet"),
+        ("goose", "geese"),
+        ("man", "men"),
+        ("mouse", "mice"),
+        ("ox", "oxen"),
+        ("person", "people"),
+        ("tooth", "teeth"),
+        ("woman", "women"),
+    ];
+    const O_ES: &[&str] = &["echo", "hero", "potato", "tomato", "veto"];
+    // Only these take -ves; `golf`, `roof`, `safe` and `cafe` just add -s.
+    const F_VES: &[&str] = &[
+        "calf", "elf", "half", "knife", "leaf", "life", "loaf", "self", "shelf", "thief", "wife",
+        "wolf",
+    ];
+
+    if word.is_empty() || UNCOUNTABLE.contains(&word) {
+        return word.to_string();
+    }
+    if let Some((_, plural)) = IRREGULAR.iter().find(|(singular, _)| *singular == word) {
+        return plural.to_string();
+    }
+    let is_vowel = |c: char| "aeiou".contains(c);
+    let before_last = word.chars().rev().nth(1);
+    if let Some(stem) = word.strip_suffix('y')
+        && before_last.is_some_and(|c| !is_vowel(c))
+    {
+        return format!("{}ies", stem);
+    }
+    if word.ends_with("is") && word.len() > 3 {
+        // analysis -> analyses
+        return format!("{}es", &word[..word.len() - 2]);
+    }
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+        || O_ES.contains(&word)
+    {
+        return format!("{}es", word);
+    }
+    if F_VES.contains(&word) {
+        let stem = word.strip_suffix("fe").or_else(|| word.strip_suffix('f'));
+        return format!("{}ves", stem.unwrap_or(word));
+    }
+    format!("{}s", word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snake_case_splits_words_and_acronyms() {
+        assert_eq!(to_snake_case("User"), "user");
+        assert_eq!(to_snake_case("UserProfile"), "user_profile");
+        assert_eq!(to_snake_case("HTTPRequest"), "http_request");
+        assert_eq!(to_snake_case("OrderV2Item"), "order_v2_item");
+    }
+
+    #[test]
+    fn pluralize_handles_common_english_rules() {
+        for (singular, plural) in [
+            ("user", "users"),
+            ("category", "categories"),
+            ("key", "keys"),
+            ("status", "statuses"),
+            ("box", "boxes"),
+            ("match", "matches"),
+            ("analysis", "analyses"),
+            ("person", "people"),
+            ("knife", "knives"),
+            ("shelf", "shelves"),
+            ("leaf", "leaves"),
+            ("golf", "golfs"),
+            ("roof", "roofs"),
+            ("safe", "safes"),
+            ("cafe", "cafes"),
+            ("hero", "heroes"),
+            ("photo", "photos"),
+            ("news", "news"),
+            ("user_category", "user_categories"),
+        ] {
+            assert_eq!(pluralize(singular), plural, "{singular}");
+        }
+    }
+
+    #[test]
+    fn strategies_name_tables_and_foreign_keys() {
+        assert_eq!(NamingStrategy::Legacy.table_name("Category"), "categorys");
+        assert_eq!(NamingStrategy::Plural.table_name("Category"), "categories");
+        assert_eq!(
+            NamingStrategy::Plural.table_name("UserProfile"),
+            "user_profiles"
+        );
+        assert_eq!(
+            NamingStrategy::Singular.table_name("UserProfile"),
+            "user_profile"
+        );
+        assert_eq!(
+            NamingStrategy::Legacy.foreign_key("UserProfile"),
+            "userprofile_id"
+        );
+        assert_eq!(
+            NamingStrategy::Plural.foreign_key("UserProfile"),
+            "user_profile_id"
+        );
+    }
+
+    #[test]
+    fn strategy_is_read_from_package_metadata() {
+        let manifest = "[package]\nname = \"app\"\n\n[package.metadata.premix]\nnaming = \"plural\" # snake_case\n";
+        assert_eq!(
+            NamingStrategy::from_manifest(manifest),
+            Ok(NamingStrategy::Plural)
+        );
+        assert_eq!(
+            NamingStrategy::from_manifest("[dependencies]\nnaming = \"plural\"\n"),
+            Ok(NamingStrategy::Legacy)
+        );
+        assert!(
+            NamingStrategy::from_manifest("[package.metadata.premix]\nnaming = \"camel\"").is_err()
+        );
+    }
+}
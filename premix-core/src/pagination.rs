@@ -0,0 +1,191 @@
+use crate::query::BindValue;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use smallvec::SmallVec;
+use sqlx::{Database, Row, ValueRef};
+
+/// A page of results from keyset (cursor) pagination.
+///
+/// Cursors are opaque, URL-safe tokens. Pass `next_cursor` to
+/// [`QueryBuilder::after`](crate::QueryBuilder::after) for the following page and
+/// `prev_cursor` to [`QueryBuilder::before`](crate::QueryBuilder::before) for the
+/// preceding one. A `None` cursor means there is nothing further in that direction.
+#[derive(Debug, Clone)]
+pub struct CursorPage<T> {
+    /// The rows of this page, in `paginate_by` order.
+    pub items: Vec<T>,
+    /// Cursor pointing after the last item, if more rows follow.
+    pub next_cursor: Option<String>,
+    /// Cursor pointing before the first item, if rows precede this page.
+    pub prev_cursor: Option<String>,
+}
+
+/// A page of results from offset pagination, with the total row count.
+///
+/// Returned by [`QueryBuilder::paginate`](crate::QueryBuilder::paginate).
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// The rows of this page.
+    pub items: Vec<T>,
+    /// Number of rows matching the filters across all pages.
+    pub total: u64,
+    /// The 1-based page number.
+    pub page: u32,
+    /// The page size used for the query.
+    pub per_page: u32,
+    /// Number of pages needed for `total` rows (0 when nothing matches).
+    pub total_pages: u64,
+}
+
+impl<T> Page<T> {
+    /// Returns true if a page follows this one.
+    pub fn has_next(&self) -> bool {
+        u64::from(self.page) < self.total_pages
+    }
+
+    /// Returns true if a page precedes this one.
+    pub fn has_prev(&self) -> bool {
+        self.page > 1
+    }
+}
+
+pub(crate) fn encode_cursor(values: &[BindValue]) -> String {
+    let encoded: Vec<serde_json::Value> = values
+        .iter()
+        .map(|value| {
+            let (tag, json) = match value {
+                BindValue::String(v) => ("s", serde_json::Value::from(v.as_str())),
+                BindValue::I64(v) => ("i", serde_json::Value::from(*v)),
+                BindValue::F64(v) => ("f", serde_json::Value::from(*v)),
+                BindValue::Bool(v) => ("b", serde_json::Value::from(*v)),
+                BindValue::Uuid(v) => ("u", serde_json::Value::from(v.to_string())),
+                BindValue::DateTime(v) => ("t", serde_json::Value::from(v.to_rfc3339())),
+                BindValue::NaiveDateTime(v) => ("n", serde_json::Value::from(v.to_string())),
+                BindValue::NaiveDate(v) => ("d", serde_json::Value::from(v.to_string())),
+                BindValue::Json(v) => ("j", v.clone()),
+                BindValue::Null => ("z", serde_json::Value::Null),
+            };
+            serde_json::json!([tag, json])
+        })
+        .collect();
+    URL_SAFE_NO_PAD.encode(serde_json::Value::Array(encoded).to_string())
+}
+
+pub(crate) fn decode_cursor(token: &str) -> Result<SmallVec<[BindValue; 2]>, sqlx::Error> {
+    let invalid = || sqlx::Error::Protocol(format!("Invalid pagination cursor: {}", token));
+    let bytes = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+    let parsed: Vec<(String, serde_json::Value)> =
+        serde_json::from_slice(&bytes).map_err(|_| invalid())?;
+
+    parsed
+        .into_iter()
+        .map(|(tag, json)| {
+            let text = || json.as_str().map(str::to_string).ok_or_else(invalid);
+            Ok(match tag.as_str() {
+                "s" => BindValue::String(text()?),
+                "i" => BindValue::I64(json.as_i64().ok_or_else(invalid)?),
+                "f" => BindValue::F64(json.as_f64().ok_or_else(invalid)?),
+                "b" => BindValue::Bool(json.as_bool().ok_or_else(invalid)?),
+                "u" => BindValue::Uuid(text()?.parse().map_err(|_| invalid())?),
+                "t" => BindValue::DateTime(
+                    chrono::DateTime::parse_from_rfc3339(&text()?)
+                        .map_err(|_| invalid())?
+                        .with_timezone(&chrono::Utc),
+                ),
+                "n" => BindValue::NaiveDateTime(text()?.parse().map_err(|_| invalid())?),
+                "d" => BindValue::NaiveDate(text()?.parse().map_err(|_| invalid())?),
+                "j" => BindValue::Json(json),
+                "z" => BindValue::Null,
+                _ => return Err(invalid()),
+            })
+        })
+        .collect()
+}
+
+/// Reads the cursor columns of `row` back into bind values.
+///
+/// The column type is not known statically, so each supported Rust type is tried
+/// in turn; `try_get` rejects incompatible SQL types without decoding.
+pub(crate) fn read_cursor_values<DB>(
+    row: &DB::Row,
+    columns: &[&str],
+) -> Result<SmallVec<[BindValue; 2]>, sqlx::Error>
+where
+    DB: Database,
+    for<'c> &'c str: sqlx::ColumnIndex<DB::Row>,
+    i64: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    i32: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    f64: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    String: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    bool: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    uuid::Uuid: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    chrono::DateTime<chrono::Utc>: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    chrono::NaiveDateTime: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    chrono::NaiveDate: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+{
+    let mut values = SmallVec::with_capacity(columns.len());
+    for &column in columns {
+        if row.try_get_raw(column)?.is_null() {
+            return Err(sqlx::Error::Protocol(format!(
+                "Cursor column '{}' is NULL; paginate_by columns must be NOT NULL",
+                column
+            )));
+        }
+        let value = if let Ok(v) = row.try_get::<i64, _>(column) {
+            BindValue::I64(v)
+        } else if let Ok(v) = row.try_get::<i32, _>(column) {
+            BindValue::I64(v.into())
+        } else if let Ok(v) = row.try_get::<f64, _>(column) {
+            BindValue::F64(v)
+        } else if let Ok(v) = row.try_get::<String, _>(column) {
+            BindValue::String(v)
+        } else if let Ok(v) = row.try_get::<bool, _>(column) {
+            BindValue::Bool(v)
+        } else if let Ok(v) = row.try_get::<uuid::Uuid, _>(column) {
+            BindValue::Uuid(v)
+        } else if let Ok(v) = row.try_get::<chrono::DateTime<chrono::Utc>, _>(column) {
+            BindValue::DateTime(v)
+        } else if let Ok(v) = row.try_get::<chrono::NaiveDateTime, _>(column) {
+            BindValue::NaiveDateTime(v)
+        } else if let Ok(v) = row.try_get::<chrono::NaiveDate, _>(column) {
+            BindValue::NaiveDate(v)
+        } else {
+            return Err(sqlx::Error::Protocol(format!(
+                "Unsupported type for cursor column '{}'",
+                column
+            )));
+        };
+        values.push(value);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trip() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-01-31T10:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let token = encode_cursor(&[
+            BindValue::DateTime(now),
+            BindValue::I64(42),
+            BindValue::String("a/b+c".to_string()),
+        ]);
+        assert!(!token.contains(['/', '+', '=']));
+
+        let values = decode_cursor(&token).unwrap();
+        assert!(matches!(values[0], BindValue::DateTime(v) if v == now));
+        assert!(matches!(values[1], BindValue::I64(42)));
+        assert!(matches!(&values[2], BindValue::String(v) if v == "a/b+c"));
+    }
+
+    #[test]
+    fn cursor_rejects_tampered_tokens() {
+        assert!(decode_cursor("not base64!").is_err());
+        assert!(decode_cursor(&URL_SAFE_NO_PAD.encode("[[\"x\",1]]")).is_err());
+        assert!(decode_cursor(&URL_SAFE_NO_PAD.encode("[[\"i\",\"1\"]]")).is_err());
+    }
+}
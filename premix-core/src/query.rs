@@ -1,23 +1,40 @@
+use crate::column::Condition;
 use crate::dialect::SqlDialect;
+use crate::error::UnknownColumn;
 use crate::executor::Executor;
-use crate::model::Model;
+use crate::explain::QueryPlan;
+use crate::model::{Model, Relation};
+use crate::pagination::{CursorPage, Page};
 use futures_util::StreamExt;
 use smallvec::{SmallVec, smallvec};
 use sqlx::{Database, IntoArguments};
 use std::time::{Duration, Instant};
 
-#[doc(hidden)]
+/// A value bound to a query placeholder.
+///
+/// Filter methods convert their arguments through `Into<BindValue>`; build one
+/// explicitly to mix value types, e.g. in [`QueryBuilder::filter_sql`].
 #[derive(Debug, Clone)]
 pub enum BindValue {
+    /// Text value.
     String(String),
+    /// Integer value; `i32` is widened.
     I64(i64),
+    /// Floating-point value.
     F64(f64),
+    /// Boolean value.
     Bool(bool),
+    /// UUID value.
     Uuid(uuid::Uuid),
+    /// UTC timestamp.
     DateTime(chrono::DateTime<chrono::Utc>),
+    /// Timestamp without time zone.
     NaiveDateTime(chrono::NaiveDateTime),
+    /// Calendar date.
     NaiveDate(chrono::NaiveDate),
+    /// JSON document.
     Json(serde_json::Value),
+    /// SQL `NULL`.
     Null,
 }
 
@@ -224,6 +241,11 @@ impl From<serde_json::Value> for BindValue {
 #[derive(Debug, Clone)]
 pub(crate) enum FilterExpr {
     Raw(String),
+    /// SQL fragment with `?` placeholders, renumbered for the dialect when rendered.
+    Sql {
+        sql: String,
+        values: SmallVec<[BindValue; 2]>,
+    },
     Compare {
         column: ColumnRef,
         op: FilterOp,
@@ -233,352 +255,2432 @@ pub(crate) enum FilterExpr {
         column: ColumnRef,
         is_null: bool,
     },
+    /// Value at `path` inside a JSON column equals `value`; both are bound.
+    JsonPath {
+        column: ColumnRef,
+        path: String,
+        value: BindValue,
+    },
+    /// JSON column contains the JSON document `value`.
+    JsonContains {
+        column: ColumnRef,
+        value: BindValue,
+    },
+    /// Full-text search of `query` over `columns` of `table`.
+    FullText {
+        table: &'static str,
+        primary_key: &'static str,
+        /// Whether the key is a single integer column, usable as an FTS5 rowid.
+        rowid_key: bool,
+        columns: SmallVec<[ColumnRef; 2]>,
+        query: String,
+    },
+    Group {
+        kind: GroupKind,
+        filters: Vec<FilterExpr>,
+    },
+    /// `column IN (SELECT ...)`, or `[NOT] EXISTS (SELECT ...)` when `column` is `None`.
+    Subquery {
+        column: Option<ColumnRef>,
+        negated: bool,
+        query: Box<Subquery>,
+    },
+    /// `inner_table.inner = outer_table.outer`, linking a subquery to its outer query.
+    /// `outer_table` is filled in when the subquery is attached.
+    Correlate {
+        inner_table: &'static str,
+        inner: ColumnRef,
+        outer_table: Option<&'static str>,
+        outer: ColumnRef,
+    },
+    /// Keyset pagination bound: `(columns...) > (values...)` (or `<`).
+    Keyset {
+        columns: SmallVec<[ColumnRef; 2]>,
+        op: FilterOp,
+        values: SmallVec<[BindValue; 2]>,
+    },
 }
 
+/// How the members of a [`FilterExpr::Group`] are combined.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum FilterOp {
-    Eq,
-    Ne,
-    Lt,
-    Lte,
-    Gt,
-    Gte,
-    Like,
-    In,
+pub(crate) enum GroupKind {
+    /// `(a OR b ...)`
+    Any,
+    /// `(a AND b ...)`
+    All,
+    /// `NOT (a AND b ...)`
+    Not,
 }
 
-impl FilterOp {
-    fn as_str(self) -> &'static str {
+impl FilterExpr {
+    fn render_into<DB: SqlDialect>(
+        &self,
+        sql: &mut String,
+        binds: &mut SmallVec<[BindValue; 8]>,
+        idx: &mut usize,
+        scope: Option<&str>,
+    ) {
+        use std::fmt::Write;
+
         match self {
-            FilterOp::Eq => "=",
-            FilterOp::Ne => "!=",
-            FilterOp::Lt => "<",
-            FilterOp::Lte => "<=",
-            FilterOp::Gt => ">",
-            FilterOp::Gte => ">=",
-            FilterOp::Like => "LIKE",
-            FilterOp::In => "IN",
+            FilterExpr::Raw(condition) => sql.push_str(condition),
+            FilterExpr::Sql {
+                sql: fragment,
+                values,
+            } => {
+                sql.push('(');
+                let mut last = 0;
+                for pos in sql_placeholder_positions(fragment) {
+                    sql.push_str(&fragment[last..pos]);
+                    sql.push_str(&DB::placeholder(*idx));
+                    *idx += 1;
+                    last = pos + 1;
+                }
+                sql.push_str(&fragment[last..]);
+                sql.push(')');
+                binds.extend(values.iter().cloned());
+            }
+            FilterExpr::Compare { column, op, values } => {
+                if op.is_in() {
+                    if values.is_empty() {
+                        // Nothing is IN an empty list; everything is NOT IN it.
+                        sql.push_str(if *op == FilterOp::In { "1=0" } else { "1=1" });
+                        return;
+                    }
+                    let _ = write!(sql, "{} {} (", column.render::<DB>(scope), op.as_str());
+                    let placeholders = crate::cached_placeholders_from::<DB>(*idx, values.len());
+                    sql.push_str(placeholders);
+                    sql.push(')');
+                    *idx = idx.saturating_add(values.len());
+                    for v in values {
+                        binds.push(v.clone());
+                    }
+                } else if *op == FilterOp::Between {
+                    let _ = write!(
+                        sql,
+                        "{} BETWEEN {} AND {}",
+                        column.render::<DB>(scope),
+                        DB::placeholder(*idx),
+                        DB::placeholder(*idx + 1)
+                    );
+                    *idx += 2;
+                    binds.extend(values.iter().cloned());
+                } else {
+                    if op.ignores_case() {
+                        sql.push_str(&DB::render_ilike(
+                            &column.render::<DB>(scope),
+                            &DB::placeholder(*idx),
+                        ));
+                    } else {
+                        let _ = write!(
+                            sql,
+                            "{} {} {}",
+                            column.render::<DB>(scope),
+                            op.as_str(),
+                            DB::placeholder(*idx)
+                        );
+                    }
+                    if op.is_escaped() {
+                        let _ = write!(sql, " ESCAPE '{}'", LIKE_ESCAPE);
+                    }
+                    *idx += 1;
+                    if let Some(v) = values.first() {
+                        binds.push(v.clone());
+                    }
+                }
+            }
+            FilterExpr::NullCheck { column, is_null } => {
+                if *is_null {
+                    let _ = write!(sql, "{} IS NULL", column.render::<DB>(scope));
+                } else {
+                    let _ = write!(sql, "{} IS NOT NULL", column.render::<DB>(scope));
+                }
+            }
+            FilterExpr::JsonPath {
+                column,
+                path,
+                value,
+            } => {
+                sql.push_str(&DB::render_json_path_eq(
+                    &column.render::<DB>(scope),
+                    &DB::placeholder(*idx),
+                    &DB::placeholder(*idx + 1),
+                ));
+                *idx += 2;
+                binds.push(BindValue::String(DB::json_path_arg(path)));
+                binds.push(value.clone());
+            }
+            FilterExpr::JsonContains { column, value } => {
+                sql.push_str(&DB::render_json_contains(
+                    &column.render::<DB>(scope),
+                    &DB::placeholder(*idx),
+                ));
+                *idx += 1;
+                binds.push(value.clone());
+            }
+            FilterExpr::FullText { .. } => {
+                if let Some(expr) = self.render_fulltext::<DB>(binds, idx, scope, false) {
+                    sql.push_str(&expr);
+                } else {
+                    // Nothing to search for: match no rows, like plainto_tsquery('').
+                    sql.push_str("1=0");
+                }
+            }
+            FilterExpr::Group { kind, filters } => {
+                if filters.is_empty() {
+                    // An empty OR matches nothing; an empty AND matches everything.
+                    sql.push_str(if *kind == GroupKind::All {
+                        "1=1"
+                    } else {
+                        "1=0"
+                    });
+                    return;
+                }
+                if *kind == GroupKind::Not {
+                    sql.push_str("NOT ");
+                }
+                let joiner = if *kind == GroupKind::Any {
+                    " OR "
+                } else {
+                    " AND "
+                };
+                sql.push('(');
+                for (pos, filter) in filters.iter().enumerate() {
+                    if pos > 0 {
+                        sql.push_str(joiner);
+                    }
+                    filter.render_into::<DB>(sql, binds, idx, scope);
+                }
+                sql.push(')');
+            }
+            FilterExpr::Subquery {
+                column,
+                negated,
+                query,
+            } => {
+                match column {
+                    Some(column) => {
+                        sql.push_str(&column.render::<DB>(scope));
+                        sql.push_str(if *negated { " NOT IN (" } else { " IN (" });
+                    }
+                    None => sql.push_str(if *negated { "NOT EXISTS (" } else { "EXISTS (" }),
+                }
+                query.render_into::<DB>(sql, binds, idx, column.is_none());
+                sql.push(')');
+            }
+            FilterExpr::Correlate {
+                inner_table,
+                inner,
+                outer_table,
+                outer,
+            } => {
+                let _ = write!(
+                    sql,
+                    "{}.{} = {}.{}",
+                    DB::quote_identifier(inner_table),
+                    DB::quote_identifier(inner.as_str()),
+                    DB::quote_identifier(outer_table.unwrap_or_default()),
+                    DB::quote_identifier(outer.as_str())
+                );
+            }
+            FilterExpr::Keyset {
+                columns,
+                op,
+                values,
+            } => {
+                if columns.len() == 1 || DB::supports_row_value_comparison() {
+                    let wrap = columns.len() > 1;
+                    if wrap {
+                        sql.push('(');
+                    }
+                    for (pos, column) in columns.iter().enumerate() {
+                        if pos > 0 {
+                            sql.push_str(", ");
+                        }
+                        sql.push_str(&column.render::<DB>(scope));
+                    }
+                    let _ = write!(sql, "{} {} ", if wrap { ")" } else { "" }, op.as_str());
+                    if wrap {
+                        sql.push('(');
+                    }
+                    for (pos, value) in values.iter().enumerate() {
+                        if pos > 0 {
+                            sql.push_str(", ");
+                        }
+                        sql.push_str(&DB::placeholder(*idx));
+                        *idx += 1;
+                        binds.push(value.clone());
+                    }
+                    if wrap {
+                        sql.push(')');
+                    }
+                } else {
+                    // (a > ? OR (a = ? AND b > ?) OR (a = ? AND b = ? AND c > ?))
+                    sql.push('(');
+                    for depth in 0..columns.len() {
+                        if depth > 0 {
+                            sql.push_str(" OR (");
+                        }
+                        for pos in 0..=depth {
+                            if pos > 0 {
+                                sql.push_str(" AND ");
+                            }
+                            let cmp = if pos == depth { op.as_str() } else { "=" };
+                            let _ = write!(
+                                sql,
+                                "{} {} {}",
+                                columns[pos].render::<DB>(scope),
+                                cmp,
+                                DB::placeholder(*idx)
+                            );
+                            *idx += 1;
+                            binds.push(values[pos].clone());
+                        }
+                        if depth > 0 {
+                            sql.push(')');
+                        }
+                    }
+                    sql.push(')');
+                }
+            }
         }
     }
 
-    fn is_in(self) -> bool {
-        matches!(self, FilterOp::In)
+    /// Renders a full-text match (or its rank when `rank` is set), binding the query.
+    /// Returns `None` for a blank query.
+    fn render_fulltext<DB: SqlDialect>(
+        &self,
+        binds: &mut SmallVec<[BindValue; 8]>,
+        idx: &mut usize,
+        scope: Option<&str>,
+        rank: bool,
+    ) -> Option<String> {
+        let FilterExpr::FullText {
+            table,
+            primary_key,
+            columns,
+            query,
+            ..
+        } = self
+        else {
+            return None;
+        };
+        if query.trim().is_empty() {
+            return None;
+        }
+        let rendered: Vec<String> = columns.iter().map(|c| c.render::<DB>(scope)).collect();
+        let placeholder = DB::placeholder(*idx);
+        let expr = if rank {
+            DB::render_fulltext_rank(table, primary_key, &rendered, &placeholder)
+        } else {
+            DB::render_fulltext_match(table, primary_key, &rendered, &placeholder)
+        };
+        *idx += 1;
+        let names: Vec<&str> = columns.iter().map(ColumnRef::as_str).collect();
+        binds.push(BindValue::String(DB::fulltext_query_arg(&names, query)));
+        Some(expr)
     }
-}
 
-/// Column reference used in filters (static literals or owned names).
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ColumnRef {
-    /// Static column name known at compile time.
-    Static(&'static str),
-    /// Owned column name created at runtime.
-    Owned(String),
-}
+    fn log_into(&self, buf: &mut String, sensitive_fields: &[&str]) {
+        use std::fmt::Write;
 
-impl ColumnRef {
-    /// Builds a static column reference without allocation.
-    pub const fn static_str(value: &'static str) -> Self {
-        ColumnRef::Static(value)
+        match self {
+            FilterExpr::Raw(_) => buf.push_str("RAW(<redacted>)"),
+            FilterExpr::Sql { values, .. } => {
+                let _ = write!(buf, "SQL(<redacted>, {} binds)", values.len());
+            }
+            FilterExpr::Compare { column, op, values } => {
+                let column_name = column.log_name();
+                let is_sensitive = sensitive_fields.contains(&column.as_str());
+                if op.is_in() {
+                    if values.is_empty() {
+                        buf.push_str(if *op == FilterOp::In { "1=0" } else { "1=1" });
+                        return;
+                    }
+                    let _ = write!(buf, "{} {} (", column_name, op.as_str());
+                    for (idx, value) in values.iter().enumerate() {
+                        if idx > 0 {
+                            buf.push_str(", ");
+                        }
+                        if is_sensitive {
+                            buf.push_str("***");
+                        } else {
+                            buf.push_str(&value.to_log_string());
+                        }
+                    }
+                    buf.push(')');
+                } else if *op == FilterOp::Between {
+                    let _ = write!(buf, "{} BETWEEN ", column_name);
+                    for (pos, value) in values.iter().enumerate() {
+                        if pos > 0 {
+                            buf.push_str(" AND ");
+                        }
+                        if is_sensitive {
+                            buf.push_str("***");
+                        } else {
+                            buf.push_str(&value.to_log_string());
+                        }
+                    }
+                } else {
+                    let _ = write!(buf, "{} {} ", column_name, op.as_str());
+                    if is_sensitive {
+                        buf.push_str("***");
+                    } else if let Some(value) = values.first() {
+                        buf.push_str(&value.to_log_string());
+                    } else {
+                        buf.push_str("NULL");
+                    }
+                }
+            }
+            FilterExpr::NullCheck { column, is_null } => {
+                if *is_null {
+                    let _ = write!(buf, "{} IS NULL", column.log_name());
+                } else {
+                    let _ = write!(buf, "{} IS NOT NULL", column.log_name());
+                }
+            }
+            FilterExpr::JsonPath {
+                column,
+                path,
+                value,
+            } => {
+                let value = if sensitive_fields.contains(&column.as_str()) {
+                    "***".to_string()
+                } else {
+                    value.to_log_string()
+                };
+                let _ = write!(buf, "{}->'{}' = {}", column.log_name(), path, value);
+            }
+            FilterExpr::JsonContains { column, value } => {
+                let value = if sensitive_fields.contains(&column.as_str()) {
+                    "***".to_string()
+                } else {
+                    value.to_log_string()
+                };
+                let _ = write!(buf, "{} @> {}", column.log_name(), value);
+            }
+            FilterExpr::FullText { columns, query, .. } => {
+                let names: Vec<String> = columns.iter().map(ColumnRef::log_name).collect();
+                let query = if columns
+                    .iter()
+                    .any(|column| sensitive_fields.contains(&column.as_str()))
+                {
+                    "***"
+                } else {
+                    query.as_str()
+                };
+                let _ = write!(buf, "MATCH({}) '{}'", names.join(", "), query);
+            }
+            FilterExpr::Group { kind, filters } => {
+                if filters.is_empty() {
+                    buf.push_str(if *kind == GroupKind::All {
+                        "1=1"
+                    } else {
+                        "1=0"
+                    });
+                    return;
+                }
+                if *kind == GroupKind::Not {
+                    buf.push_str("NOT ");
+                }
+                let joiner = if *kind == GroupKind::Any {
+                    " OR "
+                } else {
+                    " AND "
+                };
+                buf.push('(');
+                for (pos, filter) in filters.iter().enumerate() {
+                    if pos > 0 {
+                        buf.push_str(joiner);
+                    }
+                    filter.log_into(buf, sensitive_fields);
+                }
+                buf.push(')');
+            }
+            FilterExpr::Subquery {
+                column,
+                negated,
+                query,
+            } => {
+                match column {
+                    Some(column) => {
+                        buf.push_str(column.as_str());
+                        buf.push_str(if *negated { " NOT IN (" } else { " IN (" });
+                    }
+                    None => buf.push_str(if *negated { "NOT EXISTS (" } else { "EXISTS (" }),
+                }
+                query.log_into(buf, column.is_none());
+                buf.push(')');
+            }
+            FilterExpr::Correlate {
+                inner_table,
+                inner,
+                outer_table,
+                outer,
+            } => {
+                let _ = write!(
+                    buf,
+                    "{}.{} = {}.{}",
+                    inner_table,
+                    inner.as_str(),
+                    outer_table.unwrap_or("<outer>"),
+                    outer.as_str()
+                );
+            }
+            FilterExpr::Keyset {
+                columns,
+                op,
+                values,
+            } => {
+                let names: Vec<&str> = columns.iter().map(ColumnRef::as_str).collect();
+                let rendered: Vec<String> = names
+                    .iter()
+                    .zip(values)
+                    .map(|(name, value)| {
+                        if sensitive_fields.contains(name) {
+                            "***".to_string()
+                        } else {
+                            value.to_log_string()
+                        }
+                    })
+                    .collect();
+                let _ = write!(
+                    buf,
+                    "({}) {} ({})",
+                    names.join(", "),
+                    op.as_str(),
+                    rendered.join(", ")
+                );
+            }
+        }
     }
 
-    fn as_str(&self) -> &str {
+    fn bind_count(&self) -> usize {
         match self {
-            ColumnRef::Static(value) => value,
-            ColumnRef::Owned(value) => value,
+            FilterExpr::Compare { op, values, .. } if op.is_in() || *op == FilterOp::Between => {
+                values.len()
+            }
+            FilterExpr::Sql { values, .. } => values.len(),
+            FilterExpr::Compare { .. }
+            | FilterExpr::JsonContains { .. }
+            | FilterExpr::FullText { .. } => 1,
+            FilterExpr::JsonPath { .. } => 2,
+            FilterExpr::Group { filters, .. } => filters.iter().map(FilterExpr::bind_count).sum(),
+            // Upper bound: the expanded OR form repeats the leading columns.
+            FilterExpr::Keyset { values, .. } => values.len() * (values.len() + 1) / 2,
+            FilterExpr::Subquery { query, .. } => query.bind_count(),
+            FilterExpr::Raw(_) | FilterExpr::NullCheck { .. } | FilterExpr::Correlate { .. } => 0,
         }
     }
-}
 
-impl From<&str> for ColumnRef {
-    fn from(value: &str) -> Self {
-        ColumnRef::Owned(value.to_string())
+    /// Checks every column this filter names against `known`; raw SQL is skipped.
+    fn check_columns(&self, tables: &ColumnTables<'_>) -> Result<(), sqlx::Error> {
+        match self {
+            FilterExpr::Raw(_) | FilterExpr::Sql { .. } => Ok(()),
+            FilterExpr::Compare { column, .. }
+            | FilterExpr::NullCheck { column, .. }
+            | FilterExpr::JsonPath { column, .. }
+            | FilterExpr::JsonContains { column, .. } => check_column(column, tables),
+            FilterExpr::FullText { columns, .. } | FilterExpr::Keyset { columns, .. } => columns
+                .iter()
+                .try_for_each(|column| check_column(column, tables)),
+            FilterExpr::Group { filters, .. } => filters
+                .iter()
+                .try_for_each(|filter| filter.check_columns(tables)),
+            FilterExpr::Subquery { column, query, .. } => {
+                if let Some(column) = column {
+                    check_column(column, tables)?;
+                }
+                query.check_columns()
+            }
+            // The outer side belongs to the enclosing query, whose columns are not at hand.
+            FilterExpr::Correlate { inner, .. } => check_column(inner, tables),
+        }
     }
 }
 
-impl From<String> for ColumnRef {
-    fn from(value: String) -> Self {
-        ColumnRef::Owned(value)
-    }
+/// A `SELECT` captured from another model's [`QueryBuilder`] for use inside
+/// `IN (...)` / `EXISTS (...)`. Rendered lazily so its binds continue the outer
+/// placeholder sequence.
+#[derive(Debug, Clone)]
+pub(crate) struct Subquery {
+    table: &'static str,
+    columns: SmallVec<[ColumnRef; 4]>,
+    filters: Vec<FilterExpr>,
+    group_by: SmallVec<[ColumnRef; 2]>,
+    having: SmallVec<[HavingExpr; 1]>,
+    soft_delete: bool,
+    sensitive_fields: &'static [&'static str],
+    /// The subquery model's columns; empty when it opted out of column checks.
+    known_columns: &'static [&'static str],
+    /// How the enclosing query's row is referenced: its table name or [`SUBQUERY_ALIAS`].
+    outer: &'static str,
 }
 
-impl From<&String> for ColumnRef {
-    fn from(value: &String) -> Self {
-        ColumnRef::Owned(value.clone())
-    }
-}
+/// Alias for a subquery over the same table as its enclosing query, so a
+/// correlation compares the inner row with the outer one instead of itself.
+const SUBQUERY_ALIAS: &str = "sq";
 
-/// A type-safe SQL query builder.
-///
-/// `QueryBuilder` provides a fluent interface for building SELECT, UPDATE, and DELETE queries
-/// with support for filtering, pagination, eager loading, and soft deletes.
-pub struct QueryBuilder<'a, T, DB: Database> {
-    executor: Executor<'a, DB>,
-    filters: Vec<FilterExpr>,
-    limit: Option<i32>,
-    offset: Option<i32>,
-    includes: SmallVec<[String; 2]>,
-    include_deleted: bool,
-    allow_unsafe: bool,
-    has_raw_filter: bool,
-    fast_path: bool,
-    unsafe_fast: bool,
-    ultra_fast: bool,
-    prepared: bool,
-    _marker: std::marker::PhantomData<T>,
-}
+impl Subquery {
+    /// How this subquery's own rows are referenced inside it.
+    fn reference(&self) -> &'static str {
+        if self.table == self.outer {
+            SUBQUERY_ALIAS
+        } else {
+            self.table
+        }
+    }
 
-impl<'a, T, DB: Database> std::fmt::Debug for QueryBuilder<'a, T, DB> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("QueryBuilder")
-            .field("filters", &self.filters)
-            .field("limit", &self.limit)
-            .field("offset", &self.offset)
-            .field("includes", &self.includes)
-            .field("include_deleted", &self.include_deleted)
-            .field("allow_unsafe", &self.allow_unsafe)
-            .field("fast_path", &self.fast_path)
-            .field("unsafe_fast", &self.unsafe_fast)
-            .field("ultra_fast", &self.ultra_fast)
-            .field("prepared", &self.prepared)
-            .finish()
+    /// Records the enclosing query's reference and points correlations at it.
+    fn attach_to(&mut self, outer: &'static str) {
+        self.outer = outer;
+        let this = self.reference();
+        for filter in &mut self.filters {
+            match filter {
+                FilterExpr::Correlate {
+                    inner_table,
+                    outer_table,
+                    ..
+                } => {
+                    *inner_table = this;
+                    *outer_table = Some(outer);
+                }
+                FilterExpr::Subquery { query, .. } => query.attach_to(this),
+                _ => {}
+            }
+        }
     }
-}
 
-impl<'a, T, DB> QueryBuilder<'a, T, DB>
-where
-    DB: SqlDialect + sqlx::database::HasStatementCache,
-    T: Model<DB>,
-{
-    /// Creates a new `QueryBuilder` using the provided [`Executor`].
-    pub fn new(executor: Executor<'a, DB>) -> Self {
-        let default_includes = T::default_includes();
-        let mut includes = SmallVec::with_capacity(default_includes.len().max(2));
-        for relation in default_includes {
-            includes.push((*relation).to_string());
+    fn check_columns(&self) -> Result<(), sqlx::Error> {
+        let tables = [(self.table, self.known_columns)];
+        for column in self.columns.iter().chain(self.group_by.iter()) {
+            check_column(column, &tables)?;
         }
-        Self {
-            executor,
-            filters: Vec::with_capacity(4), // Pre-allocate for typical queries (1-4 filters)
-            limit: None,
-            offset: None,
-            includes, // Include eager defaults
-            include_deleted: false,
-            allow_unsafe: false,
-            has_raw_filter: false,
-            fast_path: false,
-            unsafe_fast: false,
-            ultra_fast: false,
-            prepared: true,
-            _marker: std::marker::PhantomData,
+        for having in &self.having {
+            if having.expr.column.as_str() != "*" {
+                check_column(&having.expr.column, &tables)?;
+            }
         }
+        self.filters
+            .iter()
+            .try_for_each(|filter| filter.check_columns(&tables))
     }
 
-    /// Adds a raw SQL filter condition to the query.
-    ///
-    /// # Safety
-    /// This method is potentially unsafe and requires calling [`allow_unsafe`] for the query to execute.
-    pub fn filter(mut self, condition: impl Into<String>) -> Self {
-        self.filters.push(FilterExpr::Raw(condition.into()));
-        self.has_raw_filter = true;
-        self
+    /// Columns in the subquery's `SELECT` list (grouped columns when none were selected).
+    fn selected(&self) -> &[ColumnRef] {
+        if self.columns.is_empty() {
+            &self.group_by
+        } else {
+            &self.columns
+        }
     }
 
-    /// Adds a raw SQL filter condition to the query.
-    pub fn filter_raw(self, condition: impl Into<String>) -> Self {
-        self.filter(condition)
+    fn render_into<DB: SqlDialect>(
+        &self,
+        sql: &mut String,
+        binds: &mut SmallVec<[BindValue; 8]>,
+        idx: &mut usize,
+        exists: bool,
+    ) {
+        sql.push_str("SELECT ");
+        if exists {
+            sql.push('1');
+        } else {
+            for (pos, column) in self.selected().iter().enumerate() {
+                if pos > 0 {
+                    sql.push_str(", ");
+                }
+                sql.push_str(&column.render::<DB>(None));
+            }
+        }
+        sql.push_str(" FROM ");
+        sql.push_str(&DB::quote_identifier(self.table));
+        if self.table == self.outer {
+            sql.push_str(" AS ");
+            sql.push_str(&DB::quote_identifier(SUBQUERY_ALIAS));
+        }
+
+        let mut first = true;
+        for filter in &self.filters {
+            sql.push_str(if first { " WHERE " } else { " AND " });
+            filter.render_into::<DB>(sql, binds, idx, None);
+            first = false;
+        }
+        if self.soft_delete {
+            sql.push_str(if first { " WHERE " } else { " AND " });
+            sql.push_str("deleted_at IS NULL");
+        }
+        *idx = render_group_by_into::<DB>(sql, binds, &self.group_by, &self.having, *idx, None);
     }
 
-    /// Adds an equality filter (`column = value`).
-    pub fn filter_eq(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+    fn log_into(&self, buf: &mut String, exists: bool) {
+        buf.push_str("SELECT ");
+        if exists {
+            buf.push('1');
+        } else {
+            let names: Vec<&str> = self.selected().iter().map(ColumnRef::as_str).collect();
+            buf.push_str(&names.join(", "));
+        }
+        buf.push_str(" FROM ");
+        buf.push_str(self.table);
+        if self.table == self.outer {
+            buf.push_str(" AS ");
+            buf.push_str(SUBQUERY_ALIAS);
+        }
+        let mut first = true;
+        for filter in &self.filters {
+            buf.push_str(if first { " WHERE " } else { " AND " });
+            filter.log_into(buf, self.sensitive_fields);
+            first = false;
+        }
+        if self.soft_delete {
+            buf.push_str(if first { " WHERE " } else { " AND " });
+            buf.push_str("deleted_at IS NULL");
+        }
+    }
+
+    fn bind_count(&self) -> usize {
+        let count: usize = self.filters.iter().map(FilterExpr::bind_count).sum();
+        count + self.having.len()
+    }
+}
+
+fn render_group_by_into<DB: SqlDialect>(
+    sql: &mut String,
+    binds: &mut SmallVec<[BindValue; 8]>,
+    group_by: &[ColumnRef],
+    having: &[HavingExpr],
+    start_index: usize,
+    scope: Option<&str>,
+) -> usize {
+    use std::fmt::Write;
+
+    if !group_by.is_empty() {
+        sql.push_str(" GROUP BY ");
+        for (idx, column) in group_by.iter().enumerate() {
+            if idx > 0 {
+                sql.push_str(", ");
+            }
+            sql.push_str(&column.render::<DB>(scope));
+        }
+    }
+
+    let mut idx = start_index;
+    for (pos, having) in having.iter().enumerate() {
+        sql.push_str(if pos == 0 { " HAVING " } else { " AND " });
+        let _ = write!(
+            sql,
+            "{} {} {}",
+            having.expr.render::<DB>(scope),
+            having.op.as_str(),
+            DB::placeholder(idx)
+        );
+        idx += 1;
+        binds.push(having.value.clone());
+    }
+    idx
+}
+
+/// Byte offsets of the `?` placeholders in `sql`, skipping quoted strings and identifiers.
+fn sql_placeholder_positions(sql: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut quote = None;
+    sql.bytes().enumerate().filter_map(move |(pos, byte)| {
+        match (quote, byte) {
+            (None, b'\'' | b'"' | b'`') => quote = Some(byte),
+            (Some(open), _) if open == byte => quote = None,
+            (None, b'?') => return Some(pos),
+            _ => {}
+        }
+        None
+    })
+}
+
+/// Tables a query's columns may refer to, each with its known columns: the
+/// query's own table first, then any joined tables.
+type ColumnTables<'a> = [(&'a str, &'a [&'a str])];
+
+/// Rejects a `column` qualified with a table outside `tables`, then fails with an
+/// "Unknown column" error (see [`PremixError::UnknownColumn`](crate::PremixError::UnknownColumn))
+/// when it is not among its table's known columns. An empty column list (a
+/// hand-written model without column metadata) disables the name check.
+fn check_column(column: &ColumnRef, tables: &ColumnTables<'_>) -> Result<(), sqlx::Error> {
+    let name = column.as_str();
+    let (table, known) = match column.table() {
+        None => tables[0],
+        Some(qualifier) => tables
+            .iter()
+            .find(|(table, _)| *table == qualifier)
+            .copied()
+            .ok_or_else(|| {
+                sqlx::Error::Protocol(format!(
+                    "Column '{}.{}' must be qualified with {} or a joined table",
+                    qualifier, name, tables[0].0
+                ))
+            })?,
+    };
+    if known.is_empty() || known.contains(&name) {
+        return Ok(());
+    }
+    Err(sqlx::Error::Configuration(Box::new(UnknownColumn {
+        table: table.to_string(),
+        column: name.to_string(),
+        suggestion: closest_column(name, known).map(str::to_string),
+    })))
+}
+
+/// The known column nearest to `name` by edit distance, if it is close enough to be a typo.
+fn closest_column<'k>(name: &str, known: &[&'k str]) -> Option<&'k str> {
+    let threshold = (name.chars().count() / 3).max(2);
+    known
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| *candidate)
+}
+
+/// Case-insensitive Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().flat_map(char::to_lowercase).collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().flat_map(char::to_lowercase).enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Checks that every `filter_sql` fragment has one bind value per `?` placeholder.
+fn check_raw_binds(filters: &[FilterExpr]) -> Result<(), sqlx::Error> {
+    for filter in filters {
+        match filter {
+            FilterExpr::Sql { sql, values } => {
+                let placeholders = sql_placeholder_positions(sql).count();
+                if placeholders != values.len() {
+                    return Err(sqlx::Error::Protocol(format!(
+                        "filter_sql has {} placeholders but {} bind values",
+                        placeholders,
+                        values.len()
+                    )));
+                }
+            }
+            FilterExpr::Subquery { query, .. } => check_raw_binds(&query.filters)?,
+            FilterExpr::Group { filters, .. } => check_raw_binds(filters)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Rejects full-text filters on models without an integer key when the database
+/// matches them by rowid (SQLite FTS5).
+fn check_fulltext_keys<DB: SqlDialect>(filters: &[FilterExpr]) -> Result<(), sqlx::Error> {
+    for filter in filters {
+        match filter {
+            FilterExpr::FullText {
+                table,
+                rowid_key: false,
+                ..
+            } if DB::fulltext_needs_rowid_key() => {
+                return Err(sqlx::Error::Protocol(format!(
+                    "filter_match on {} needs a single integer primary key on this database",
+                    table
+                )));
+            }
+            FilterExpr::Subquery { query, .. } => check_fulltext_keys::<DB>(&query.filters)?,
+            FilterExpr::Group { filters, .. } => check_fulltext_keys::<DB>(filters)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Validates subquery shape and correlation before a query runs.
+fn check_subqueries(filters: &[FilterExpr], nested: bool) -> Result<(), sqlx::Error> {
+    for filter in filters {
+        match filter {
+            FilterExpr::Subquery { column, query, .. } => {
+                if column.is_some() && query.selected().len() != 1 {
+                    return Err(sqlx::Error::Protocol(format!(
+                        "filter_in_query subquery on {} must select exactly one column",
+                        query.table
+                    )));
+                }
+                check_subqueries(&query.filters, true)?;
+            }
+            FilterExpr::Correlate {
+                outer_table: None, ..
+            } if !nested => {
+                return Err(sqlx::Error::Protocol(
+                    "correlate() is only valid in a filter_exists/filter_in_query subquery"
+                        .to_string(),
+                ));
+            }
+            FilterExpr::Group { filters, .. } => check_subqueries(filters, nested)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// A set of bound conditions combined into a nested `OR`/`AND`/`NOT` group.
+///
+/// Built through [`QueryBuilder::any_of`], [`QueryBuilder::all_of`], and
+/// [`QueryBuilder::not`]; groups can nest to any depth.
+///
+/// ```ignore
+/// User::find_in_pool(&pool)
+///     .any_of(|f| f.eq("role", "admin").all_of(|f| f.eq("role", "staff").gte("level", 3)))
+///     .all()
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FilterGroup {
+    filters: Vec<FilterExpr>,
+}
+
+impl FilterGroup {
+    fn compare(mut self, column: ColumnRef, op: FilterOp, value: BindValue) -> Self {
         self.filters.push(FilterExpr::Compare {
-            column: column.into(),
-            op: FilterOp::Eq,
-            values: smallvec![value.into()],
+            column,
+            op,
+            values: smallvec![value],
         });
         self
     }
 
-    /// Adds a not-equal filter (`column != value`).
-    pub fn filter_ne(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+    fn nested(mut self, kind: GroupKind, build: impl FnOnce(FilterGroup) -> FilterGroup) -> Self {
+        self.filters.push(FilterExpr::Group {
+            kind,
+            filters: build(FilterGroup::default()).filters,
+        });
+        self
+    }
+
+    /// Adds a typed condition such as `User::AGE.gt(18)`.
+    pub fn filter_by(mut self, condition: Condition) -> Self {
+        self.filters.push(condition.0);
+        self
+    }
+
+    /// Adds `column = value`.
+    pub fn eq(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::Eq, value.into())
+    }
+
+    /// Adds `column != value`.
+    pub fn ne(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::Ne, value.into())
+    }
+
+    /// Adds `column < value`.
+    pub fn lt(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::Lt, value.into())
+    }
+
+    /// Adds `column <= value`.
+    pub fn lte(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::Lte, value.into())
+    }
+
+    /// Adds `column > value`.
+    pub fn gt(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::Gt, value.into())
+    }
+
+    /// Adds `column >= value`.
+    pub fn gte(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::Gte, value.into())
+    }
+
+    /// Adds `column LIKE value`.
+    pub fn like(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::Like, value.into())
+    }
+
+    /// Adds a case-insensitive `LIKE`; see [`QueryBuilder::filter_ilike`].
+    pub fn ilike(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.compare(column.into(), FilterOp::ILike, value.into())
+    }
+
+    /// Adds a `LIKE` matching `value` anywhere, with wildcards escaped.
+    pub fn contains(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("%{}%", escape_like(value.as_ref()));
+        self.compare(column.into(), FilterOp::LikeEscaped, pattern.into())
+    }
+
+    /// Case-insensitive [`contains`](Self::contains).
+    pub fn icontains(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("%{}%", escape_like(value.as_ref()));
+        self.compare(column.into(), FilterOp::ILikeEscaped, pattern.into())
+    }
+
+    /// Adds a `LIKE` matching values that start with `value`, with wildcards escaped.
+    pub fn starts_with(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("{}%", escape_like(value.as_ref()));
+        self.compare(column.into(), FilterOp::LikeEscaped, pattern.into())
+    }
+
+    /// Adds a `LIKE` matching values that end with `value`, with wildcards escaped.
+    pub fn ends_with(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("%{}", escape_like(value.as_ref()));
+        self.compare(column.into(), FilterOp::LikeEscaped, pattern.into())
+    }
+
+    /// Adds `column BETWEEN low AND high`.
+    pub fn between(
+        mut self,
+        column: impl Into<ColumnRef>,
+        low: impl Into<BindValue>,
+        high: impl Into<BindValue>,
+    ) -> Self {
         self.filters.push(FilterExpr::Compare {
             column: column.into(),
-            op: FilterOp::Ne,
-            values: smallvec![value.into()],
+            op: FilterOp::Between,
+            values: smallvec![low.into(), high.into()],
         });
         self
     }
 
-    /// Adds a less-than filter (`column < value`).
-    pub fn filter_lt(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+    /// Adds `column IN (values...)`.
+    pub fn in_list<I, V>(mut self, column: impl Into<ColumnRef>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<BindValue>,
+    {
         self.filters.push(FilterExpr::Compare {
             column: column.into(),
-            op: FilterOp::Lt,
-            values: smallvec![value.into()],
+            op: FilterOp::In,
+            values: values.into_iter().map(Into::into).collect(),
         });
         self
     }
 
-    /// Adds a less-than-or-equal filter (`column <= value`).
-    pub fn filter_lte(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+    /// Adds `column NOT IN (values...)`.
+    pub fn not_in<I, V>(mut self, column: impl Into<ColumnRef>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<BindValue>,
+    {
         self.filters.push(FilterExpr::Compare {
             column: column.into(),
-            op: FilterOp::Lte,
-            values: smallvec![value.into()],
+            op: FilterOp::NotIn,
+            values: values.into_iter().map(Into::into).collect(),
         });
         self
     }
 
-    /// Adds a greater-than filter (`column > value`).
-    pub fn filter_gt(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
-        self.filters.push(FilterExpr::Compare {
+    /// Adds `column IS NULL`.
+    pub fn is_null(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.filters.push(FilterExpr::NullCheck {
             column: column.into(),
-            op: FilterOp::Gt,
-            values: smallvec![value.into()],
+            is_null: true,
         });
         self
     }
 
-    /// Adds a greater-than-or-equal filter (`column >= value`).
-    pub fn filter_gte(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
-        self.filters.push(FilterExpr::Compare {
+    /// Adds `column IS NOT NULL`.
+    pub fn is_not_null(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.filters.push(FilterExpr::NullCheck {
             column: column.into(),
-            op: FilterOp::Gte,
-            values: smallvec![value.into()],
+            is_null: false,
         });
         self
     }
 
-    /// Adds a LIKE filter (`column LIKE value`).
-    pub fn filter_like(
-        mut self,
+    /// Adds a nested group that matches when any of its conditions match.
+    pub fn any_of(self, build: impl FnOnce(FilterGroup) -> FilterGroup) -> Self {
+        self.nested(GroupKind::Any, build)
+    }
+
+    /// Adds a nested group that matches when all of its conditions match.
+    pub fn all_of(self, build: impl FnOnce(FilterGroup) -> FilterGroup) -> Self {
+        self.nested(GroupKind::All, build)
+    }
+
+    /// Adds a nested group that negates the conjunction of its conditions.
+    pub fn not(self, build: impl FnOnce(FilterGroup) -> FilterGroup) -> Self {
+        self.nested(GroupKind::Not, build)
+    }
+}
+
+/// Column assignments for a bulk [`QueryBuilder::update_with`].
+///
+/// Values are bound parameters; `increment`/`decrement` render
+/// `col = col + ?` so concurrent updates never lose a write.
+///
+/// ```ignore
+/// Post::find_in_pool(&pool)
+///     .filter_eq("id", 7)
+///     .update_with(|u| u.increment("views", 1).set_now("viewed_at"))
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UpdateSet {
+    assignments: Vec<(ColumnRef, Assignment)>,
+}
+
+/// Right-hand side of one `SET` assignment.
+#[derive(Debug, Clone)]
+enum Assignment {
+    Value(BindValue),
+    Add(BindValue),
+    Sub(BindValue),
+    Column(ColumnRef),
+    Null,
+}
+
+impl UpdateSet {
+    /// Creates an empty set of assignments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(mut self, column: impl Into<ColumnRef>, assignment: Assignment) -> Self {
+        self.assignments.push((column.into(), assignment));
+        self
+    }
+
+    /// `column = value`.
+    pub fn set(self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.push(column, Assignment::Value(value.into()))
+    }
+
+    /// `column = column + by`.
+    pub fn increment(self, column: impl Into<ColumnRef>, by: impl Into<BindValue>) -> Self {
+        self.push(column, Assignment::Add(by.into()))
+    }
+
+    /// `column = column - by`.
+    pub fn decrement(self, column: impl Into<ColumnRef>, by: impl Into<BindValue>) -> Self {
+        self.push(column, Assignment::Sub(by.into()))
+    }
+
+    /// `column = source`, copying another column of the same row.
+    pub fn set_column(self, column: impl Into<ColumnRef>, source: impl Into<ColumnRef>) -> Self {
+        self.push(column, Assignment::Column(source.into()))
+    }
+
+    /// `column = NULL`.
+    pub fn set_null(self, column: impl Into<ColumnRef>) -> Self {
+        self.push(column, Assignment::Null)
+    }
+
+    /// `column = ?` bound to the current UTC time, encoded like a `DateTime<Utc>`
+    /// field so the column compares and sorts consistently with saved models.
+    pub fn set_now(self, column: impl Into<ColumnRef>) -> Self {
+        self.set(column, chrono::Utc::now())
+    }
+
+    /// Returns true if no assignment was added.
+    pub fn is_empty(&self) -> bool {
+        self.assignments.is_empty()
+    }
+
+    /// Returns true if `column` is already assigned.
+    fn assigns(&self, column: &str) -> bool {
+        self.assignments
+            .iter()
+            .any(|(assigned, _)| assigned.as_str() == column)
+    }
+
+    /// Converts the JSON object accepted by [`QueryBuilder::update`].
+    fn from_json(values: &serde_json::Value) -> Result<Self, sqlx::Error> {
+        let obj = values.as_object().ok_or_else(|| {
+            sqlx::Error::Protocol("Bulk update requires a JSON object".to_string())
+        })?;
+        Ok(obj.iter().fold(Self::new(), |set, (column, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => BindValue::String(s.clone()),
+                serde_json::Value::Number(n) => match n.as_i64() {
+                    Some(v) => BindValue::I64(v),
+                    None => BindValue::F64(n.as_f64().unwrap_or_default()),
+                },
+                serde_json::Value::Bool(b) => BindValue::Bool(*b),
+                serde_json::Value::Null => BindValue::Null,
+                serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+                    BindValue::Json(value.clone())
+                }
+            };
+            set.set(column.as_str(), value)
+        }))
+    }
+
+    /// Checks assigned columns and `set_column` sources against the model's columns.
+    fn check_columns(&self, table: &str, known: &[&str]) -> Result<(), sqlx::Error> {
+        let tables = [(table, known)];
+        for (column, assignment) in &self.assignments {
+            check_column(column, &tables)?;
+            if let Assignment::Column(source) = assignment {
+                check_column(source, &tables)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders `col = expr, ...`, numbering placeholders from `idx`.
+    fn render_into<DB: SqlDialect>(
+        &self,
+        sql: &mut String,
+        binds: &mut SmallVec<[BindValue; 8]>,
+        idx: &mut usize,
+    ) {
+        use std::fmt::Write;
+
+        for (pos, (column, assignment)) in self.assignments.iter().enumerate() {
+            if pos > 0 {
+                sql.push_str(", ");
+            }
+            let column = DB::quote_identifier(column.as_str());
+            let _ = write!(sql, "{} = ", column);
+            let value = match assignment {
+                Assignment::Value(value) => value,
+                Assignment::Add(value) => {
+                    let _ = write!(sql, "{} + ", column);
+                    value
+                }
+                Assignment::Sub(value) => {
+                    let _ = write!(sql, "{} - ", column);
+                    value
+                }
+                Assignment::Column(source) => {
+                    sql.push_str(&DB::quote_identifier(source.as_str()));
+                    continue;
+                }
+                Assignment::Null => {
+                    sql.push_str("NULL");
+                    continue;
+                }
+            };
+            sql.push_str(&DB::placeholder(*idx));
+            *idx += 1;
+            binds.push(value.clone());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FilterOp {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Like,
+    /// Case-insensitive `LIKE`; rendered through [`SqlDialect::render_ilike`].
+    ILike,
+    /// `LIKE` over a pattern built by [`escape_like`], with an `ESCAPE` clause.
+    LikeEscaped,
+    ILikeEscaped,
+    In,
+    NotIn,
+    Between,
+}
+
+impl FilterOp {
+    fn as_str(self) -> &'static str {
+        match self {
+            FilterOp::Eq => "=",
+            FilterOp::Ne => "!=",
+            FilterOp::Lt => "<",
+            FilterOp::Lte => "<=",
+            FilterOp::Gt => ">",
+            FilterOp::Gte => ">=",
+            FilterOp::Like | FilterOp::LikeEscaped => "LIKE",
+            FilterOp::ILike | FilterOp::ILikeEscaped => "ILIKE",
+            FilterOp::In => "IN",
+            FilterOp::NotIn => "NOT IN",
+            FilterOp::Between => "BETWEEN",
+        }
+    }
+
+    fn is_in(self) -> bool {
+        matches!(self, FilterOp::In | FilterOp::NotIn)
+    }
+
+    fn ignores_case(self) -> bool {
+        matches!(self, FilterOp::ILike | FilterOp::ILikeEscaped)
+    }
+
+    fn is_escaped(self) -> bool {
+        matches!(self, FilterOp::LikeEscaped | FilterOp::ILikeEscaped)
+    }
+}
+
+/// Escape character used by `filter_contains` and friends. `!` needs no quoting
+/// in any dialect's string literals, unlike a backslash on MySQL.
+const LIKE_ESCAPE: char = '!';
+
+/// Escapes `%`, `_` and the escape character so `value` matches literally
+/// inside a `LIKE ... ESCAPE '!'` pattern.
+pub(crate) fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    for ch in value.chars() {
+        if matches!(ch, '%' | '_' | LIKE_ESCAPE) {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Row lock strength requested with [`QueryBuilder::for_update`] / [`QueryBuilder::for_share`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// `FOR UPDATE`: exclusive lock on the selected rows.
+    Update,
+    /// `FOR SHARE`: shared lock; blocks writers but not other readers.
+    Share,
+}
+
+/// What a locking `SELECT` does when a row is already locked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockWait {
+    /// Block until the lock is released (the database default).
+    Wait,
+    /// `SKIP LOCKED`: leave locked rows out of the result.
+    SkipLocked,
+    /// `NOWAIT`: fail immediately instead of waiting.
+    NoWait,
+}
+
+/// SQL aggregate functions usable in projections and `HAVING` clauses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// `COUNT(column)` or `COUNT(*)`.
+    Count,
+    /// `SUM(column)`.
+    Sum,
+    /// `AVG(column)`, cast to the dialect's float type.
+    Avg,
+    /// `MIN(column)`.
+    Min,
+    /// `MAX(column)`.
+    Max,
+}
+
+impl Aggregate {
+    /// Returns the SQL function name (e.g., `"COUNT"`).
+    pub fn function_name(self) -> &'static str {
+        match self {
+            Aggregate::Count => "COUNT",
+            Aggregate::Sum => "SUM",
+            Aggregate::Avg => "AVG",
+            Aggregate::Min => "MIN",
+            Aggregate::Max => "MAX",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct AggregateExpr {
+    aggregate: Aggregate,
+    column: ColumnRef,
+}
+
+impl AggregateExpr {
+    fn render<DB: SqlDialect>(&self, scope: Option<&str>) -> String {
+        if self.column.as_str() == "*" {
+            DB::render_aggregate(self.aggregate, "*")
+        } else {
+            DB::render_aggregate(self.aggregate, &self.column.render::<DB>(scope))
+        }
+    }
+
+    fn log_string(&self) -> String {
+        format!(
+            "{}({})",
+            self.aggregate.function_name(),
+            self.column.as_str()
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct HavingExpr {
+    expr: AggregateExpr,
+    op: FilterOp,
+    value: BindValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JoinKind {
+    Inner,
+    Left,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct JoinExpr {
+    kind: JoinKind,
+    relation: Relation,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum CursorPosition {
+    After(String),
+    Before(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    fn as_str(self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NullsOrder {
+    First,
+    Last,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct OrderExpr {
+    column: ColumnRef,
+    direction: SortDirection,
+    nulls: Option<NullsOrder>,
+}
+
+/// Column reference used in filters (static literals or owned names).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColumnRef {
+    /// Static column name known at compile time.
+    Static(&'static str),
+    /// Owned column name created at runtime.
+    Owned(String),
+    /// Column on a specific (usually joined) table, e.g. `("posts", "title")`.
+    ///
+    /// The table must be the query's own table or one added with `join`/`left_join`.
+    Qualified {
+        /// Table name.
+        table: String,
+        /// Column name.
+        column: String,
+    },
+}
+
+impl ColumnRef {
+    /// Builds a static column reference without allocation.
+    pub const fn static_str(value: &'static str) -> Self {
+        ColumnRef::Static(value)
+    }
+
+    /// The bare column name, without any table qualifier.
+    fn as_str(&self) -> &str {
+        match self {
+            ColumnRef::Static(value) => value,
+            ColumnRef::Owned(value) => value,
+            ColumnRef::Qualified { column, .. } => column,
+        }
+    }
+
+    fn table(&self) -> Option<&str> {
+        match self {
+            ColumnRef::Qualified { table, .. } => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Quotes the column, prefixed with its quoted table or, for bare names, with
+    /// `scope` (set when the query has joins so bare names stay unambiguous).
+    fn render<DB: SqlDialect>(&self, scope: Option<&str>) -> String {
+        let column = DB::quote_identifier(self.as_str());
+        match (self.table(), scope) {
+            (Some(table), _) => format!("{}.{}", DB::quote_identifier(table), column),
+            (None, Some(scope)) => format!("{}.{}", DB::quote_identifier(scope), column),
+            (None, None) => column,
+        }
+    }
+
+    fn log_name(&self) -> String {
+        match self {
+            ColumnRef::Qualified { table, column } => format!("{}.{}", table, column),
+            _ => self.as_str().to_string(),
+        }
+    }
+}
+
+impl From<(&str, &str)> for ColumnRef {
+    fn from((table, column): (&str, &str)) -> Self {
+        ColumnRef::Qualified {
+            table: table.to_string(),
+            column: column.to_string(),
+        }
+    }
+}
+
+impl From<&str> for ColumnRef {
+    fn from(value: &str) -> Self {
+        ColumnRef::Owned(value.to_string())
+    }
+}
+
+impl From<String> for ColumnRef {
+    fn from(value: String) -> Self {
+        ColumnRef::Owned(value)
+    }
+}
+
+impl From<&String> for ColumnRef {
+    fn from(value: &String) -> Self {
+        ColumnRef::Owned(value.clone())
+    }
+}
+
+/// A type-safe SQL query builder.
+///
+/// `QueryBuilder` provides a fluent interface for building SELECT, UPDATE, and DELETE queries
+/// with support for filtering, pagination, eager loading, and soft deletes.
+pub struct QueryBuilder<'a, T, DB: Database> {
+    executor: Executor<'a, DB>,
+    filters: Vec<FilterExpr>,
+    joins: SmallVec<[JoinExpr; 1]>,
+    distinct: bool,
+    columns: SmallVec<[ColumnRef; 4]>,
+    aggregates: SmallVec<[(AggregateExpr, String); 2]>,
+    group_by: SmallVec<[ColumnRef; 2]>,
+    having: SmallVec<[HavingExpr; 1]>,
+    orders: SmallVec<[OrderExpr; 2]>,
+    order_by_rank: bool,
+    keyset: SmallVec<[ColumnRef; 2]>,
+    keyset_desc: bool,
+    cursor: Option<CursorPosition>,
+    lock: Option<(LockMode, LockWait)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+    includes: SmallVec<[String; 2]>,
+    include_deleted: bool,
+    skip_timestamps: bool,
+    allow_unsafe: bool,
+    has_raw_filter: bool,
+    fast_path: bool,
+    unsafe_fast: bool,
+    ultra_fast: bool,
+    prepared: bool,
+    unchecked_columns: bool,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T, DB: Database> std::fmt::Debug for QueryBuilder<'a, T, DB> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryBuilder")
+            .field("filters", &self.filters)
+            .field("joins", &self.joins)
+            .field("distinct", &self.distinct)
+            .field("columns", &self.columns)
+            .field("aggregates", &self.aggregates)
+            .field("group_by", &self.group_by)
+            .field("having", &self.having)
+            .field("orders", &self.orders)
+            .field("order_by_rank", &self.order_by_rank)
+            .field("keyset", &self.keyset)
+            .field("keyset_desc", &self.keyset_desc)
+            .field("cursor", &self.cursor)
+            .field("lock", &self.lock)
+            .field("limit", &self.limit)
+            .field("offset", &self.offset)
+            .field("includes", &self.includes)
+            .field("include_deleted", &self.include_deleted)
+            .field("skip_timestamps", &self.skip_timestamps)
+            .field("allow_unsafe", &self.allow_unsafe)
+            .field("fast_path", &self.fast_path)
+            .field("unsafe_fast", &self.unsafe_fast)
+            .field("ultra_fast", &self.ultra_fast)
+            .field("prepared", &self.prepared)
+            .field("unchecked_columns", &self.unchecked_columns)
+            .finish()
+    }
+}
+
+impl<'a, T, DB> QueryBuilder<'a, T, DB>
+where
+    DB: SqlDialect + sqlx::database::HasStatementCache,
+    T: Model<DB>,
+{
+    /// Creates a new `QueryBuilder` using the provided [`Executor`].
+    pub fn new(executor: Executor<'a, DB>) -> Self {
+        let default_includes = T::default_includes();
+        let mut includes = SmallVec::with_capacity(default_includes.len().max(2));
+        for relation in default_includes {
+            includes.push((*relation).to_string());
+        }
+        Self {
+            executor,
+            filters: Vec::with_capacity(4), // Pre-allocate for typical queries (1-4 filters)
+            joins: SmallVec::new(),
+            distinct: false,
+            columns: SmallVec::new(),
+            aggregates: SmallVec::new(),
+            group_by: SmallVec::new(),
+            having: SmallVec::new(),
+            orders: SmallVec::new(),
+            order_by_rank: false,
+            keyset: SmallVec::new(),
+            keyset_desc: false,
+            cursor: None,
+            lock: None,
+            limit: None,
+            offset: None,
+            includes, // Include eager defaults
+            include_deleted: false,
+            skip_timestamps: false,
+            allow_unsafe: false,
+            has_raw_filter: false,
+            fast_path: false,
+            unsafe_fast: false,
+            ultra_fast: false,
+            prepared: true,
+            unchecked_columns: false,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Adds a raw SQL filter condition to the query.
+    ///
+    /// # Safety
+    /// This method is potentially unsafe and requires calling [`allow_unsafe`] for the query to execute.
+    pub fn filter(mut self, condition: impl Into<String>) -> Self {
+        self.filters.push(FilterExpr::Raw(condition.into()));
+        self.has_raw_filter = true;
+        self
+    }
+
+    /// Adds a raw SQL filter condition to the query.
+    pub fn filter_raw(self, condition: impl Into<String>) -> Self {
+        self.filter(condition)
+    }
+
+    /// Adds a SQL condition with `?` placeholders bound to `values`, in order.
+    ///
+    /// Placeholders are renumbered for the dialect (`$n` on Postgres, continuing
+    /// the query's bind sequence) and the fragment is wrapped in parentheses. A `?`
+    /// inside a quoted string is left alone. Since values are never interpolated,
+    /// this does not need [`allow_unsafe`](Self::allow_unsafe); a placeholder count
+    /// that differs from `values.len()` is reported when the query runs.
+    ///
+    /// ```ignore
+    /// User::find_in_pool(&pool)
+    ///     .filter_sql("age > ? AND lower(name) = lower(?)", [BindValue::from(18), "ann".into()])
+    ///     .all()
+    ///     .await?;
+    /// ```
+    pub fn filter_sql<I, V>(mut self, sql: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<BindValue>,
+    {
+        self.filters.push(FilterExpr::Sql {
+            sql: sql.into(),
+            values: values.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Adds a typed condition built from a generated column handle.
+    ///
+    /// ```ignore
+    /// User::find_in_pool(&pool)
+    ///     .filter_by(User::AGE.gte(18))
+    ///     .filter_by(User::NAME.starts_with("a"))
+    ///     .all()
+    ///     .await?;
+    /// ```
+    pub fn filter_by(mut self, condition: Condition) -> Self {
+        self.filters.push(condition.0);
+        self
+    }
+
+    /// Adds an equality filter (`column = value`).
+    pub fn filter_eq(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Eq,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    /// Adds a not-equal filter (`column != value`).
+    pub fn filter_ne(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Ne,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    /// Adds a less-than filter (`column < value`).
+    pub fn filter_lt(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Lt,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    /// Adds a less-than-or-equal filter (`column <= value`).
+    pub fn filter_lte(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Lte,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    /// Adds a greater-than filter (`column > value`).
+    pub fn filter_gt(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Gt,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    /// Adds a greater-than-or-equal filter (`column >= value`).
+    pub fn filter_gte(mut self, column: impl Into<ColumnRef>, value: impl Into<BindValue>) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Gte,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    /// Adds a LIKE filter (`column LIKE value`).
+    pub fn filter_like(
+        mut self,
+        column: impl Into<ColumnRef>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Like,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    /// Adds a case-insensitive LIKE filter.
+    ///
+    /// Renders `ILIKE` on Postgres and `LOWER(column) LIKE LOWER(value)` elsewhere.
+    pub fn filter_ilike(
+        mut self,
+        column: impl Into<ColumnRef>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::ILike,
+            values: smallvec![value.into()],
+        });
+        self
+    }
+
+    fn push_escaped_like(mut self, column: ColumnRef, pattern: String, ignore_case: bool) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column,
+            op: if ignore_case {
+                FilterOp::ILikeEscaped
+            } else {
+                FilterOp::LikeEscaped
+            },
+            values: smallvec![pattern.into()],
+        });
+        self
+    }
+
+    /// Filters rows where the column contains `value` (`LIKE '%value%'`).
+    ///
+    /// `%`, `_` and `!` in `value` are escaped, so user input always matches literally.
+    /// Case sensitivity follows the database's `LIKE`; see [`filter_icontains`](Self::filter_icontains).
+    pub fn filter_contains(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("%{}%", escape_like(value.as_ref()));
+        self.push_escaped_like(column.into(), pattern, false)
+    }
+
+    /// Case-insensitive [`filter_contains`](Self::filter_contains).
+    pub fn filter_icontains(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("%{}%", escape_like(value.as_ref()));
+        self.push_escaped_like(column.into(), pattern, true)
+    }
+
+    /// Filters rows where the column starts with `value` (`LIKE 'value%'`), escaping wildcards.
+    pub fn filter_starts_with(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("{}%", escape_like(value.as_ref()));
+        self.push_escaped_like(column.into(), pattern, false)
+    }
+
+    /// Filters rows where the column ends with `value` (`LIKE '%value'`), escaping wildcards.
+    pub fn filter_ends_with(self, column: impl Into<ColumnRef>, value: impl AsRef<str>) -> Self {
+        let pattern = format!("%{}", escape_like(value.as_ref()));
+        self.push_escaped_like(column.into(), pattern, false)
+    }
+
+    /// Adds an inclusive range filter (`column BETWEEN low AND high`).
+    pub fn filter_between(
+        mut self,
+        column: impl Into<ColumnRef>,
+        low: impl Into<BindValue>,
+        high: impl Into<BindValue>,
+    ) -> Self {
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::Between,
+            values: smallvec![low.into(), high.into()],
+        });
+        self
+    }
+
+    /// Filters on a value inside a JSON column (`json_extract(column, path) = value`).
+    ///
+    /// `path` uses the `$.key.nested[0]` syntax on every database; on Postgres it is
+    /// converted to a `#>>` path array and both sides compare as text. The path and
+    /// value are bound, never inlined.
+    pub fn filter_json_path(
+        mut self,
+        column: impl Into<ColumnRef>,
+        path: impl Into<String>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.filters.push(FilterExpr::JsonPath {
+            column: column.into(),
+            path: path.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Filters rows whose JSON column contains `value` (`@>` on Postgres,
+    /// `JSON_CONTAINS` on MySQL).
+    ///
+    /// SQLite has no containment operator; there objects and arrays are matched
+    /// one level deep (see [`SqlDialect::render_json_contains`]).
+    pub fn json_contains(
+        mut self,
+        column: impl Into<ColumnRef>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.filters.push(FilterExpr::JsonContains {
+            column: column.into(),
+            value: BindValue::Json(value.into()),
+        });
+        self
+    }
+
+    /// Filters rows where the column IS NULL.
+    pub fn filter_is_null(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.filters.push(FilterExpr::NullCheck {
+            column: column.into(),
+            is_null: true,
+        });
+        self
+    }
+
+    /// Filters rows where the column IS NOT NULL.
+    pub fn filter_is_not_null(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.filters.push(FilterExpr::NullCheck {
+            column: column.into(),
+            is_null: false,
+        });
+        self
+    }
+
+    /// Filters by primary key (`pk = value`, or `a = value AND b = value` for a
+    /// composite key given as a tuple).
+    pub fn filter_key(mut self, key: T::Id) -> Self {
+        for (column, value) in T::primary_key_columns()
+            .iter()
+            .zip(crate::model::PrimaryKey::into_bind_values(key))
+        {
+            self = self.filter_eq(*column, value);
+        }
+        self
+    }
+
+    /// Adds an IN filter (`column IN (values...)`).
+    pub fn filter_in<I, V>(mut self, column: impl Into<ColumnRef>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<BindValue>,
+    {
+        let values: SmallVec<[BindValue; 2]> = values.into_iter().map(Into::into).collect();
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::In,
+            values,
+        });
+        self
+    }
+
+    /// Adds a NOT IN filter (`column NOT IN (values...)`). An empty list matches every row.
+    pub fn filter_not_in<I, V>(mut self, column: impl Into<ColumnRef>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<BindValue>,
+    {
+        let values: SmallVec<[BindValue; 2]> = values.into_iter().map(Into::into).collect();
+        self.filters.push(FilterExpr::Compare {
+            column: column.into(),
+            op: FilterOp::NotIn,
+            values,
+        });
+        self
+    }
+
+    fn push_group(
+        mut self,
+        kind: GroupKind,
+        build: impl FnOnce(FilterGroup) -> FilterGroup,
+    ) -> Self {
+        self.filters.push(FilterExpr::Group {
+            kind,
+            filters: build(FilterGroup::default()).filters,
+        });
+        self
+    }
+
+    /// Locks the selected rows with `FOR UPDATE` until the transaction ends.
+    ///
+    /// Locks need a transaction: run the query through
+    /// [`find_in_tx`](crate::Model::find_in_tx); a pool executor is rejected.
+    /// SQLite has no row locks, so the clause is omitted there (a write
+    /// transaction already serializes writers).
+    pub fn for_update(mut self) -> Self {
+        self.lock = Some((LockMode::Update, self.lock.map_or(LockWait::Wait, |l| l.1)));
+        self
+    }
+
+    /// Locks the selected rows with `FOR SHARE`; see [`for_update`](Self::for_update).
+    pub fn for_share(mut self) -> Self {
+        self.lock = Some((LockMode::Share, self.lock.map_or(LockWait::Wait, |l| l.1)));
+        self
+    }
+
+    /// Skips rows locked by other transactions (`SKIP LOCKED`), the usual way to
+    /// pull jobs from a work table. Implies [`for_update`](Self::for_update) unless
+    /// `for_share` was chosen.
+    pub fn skip_locked(mut self) -> Self {
+        self.lock = Some((
+            self.lock.map_or(LockMode::Update, |l| l.0),
+            LockWait::SkipLocked,
+        ));
+        self
+    }
+
+    /// Fails immediately when a row is already locked (`NOWAIT`). Implies
+    /// [`for_update`](Self::for_update) unless `for_share` was chosen.
+    pub fn nowait(mut self) -> Self {
+        self.lock = Some((
+            self.lock.map_or(LockMode::Update, |l| l.0),
+            LockWait::NoWait,
+        ));
+        self
+    }
+
+    /// Adds a full-text search over `columns` (plain words, not operator syntax).
+    ///
+    /// Uses the model's FTS5 table on SQLite, `to_tsvector(...) @@ plainto_tsquery(...)`
+    /// on Postgres and `MATCH (...) AGAINST (...)` on MySQL. Mark the columns with
+    /// `#[premix(fulltext)]` and pass them in the same order so the generated index is
+    /// used (MySQL requires it). Combine with [`order_by_rank`](Self::order_by_rank)
+    /// to sort by relevance. SQLite ties FTS5 rows back by rowid, so there the model
+    /// needs a single integer primary key.
+    pub fn filter_match(mut self, columns: &[&str], query: impl Into<String>) -> Self {
+        self.filters.push(FilterExpr::FullText {
+            table: T::table_name(),
+            primary_key: T::primary_key(),
+            rowid_key: T::primary_key_columns().len() == 1
+                && <T::Id as crate::model::PrimaryKey>::from_generated_id(1).is_some(),
+            columns: columns.iter().map(|c| ColumnRef::from(*c)).collect(),
+            query: query.into(),
+        });
+        self
+    }
+
+    /// Orders results by relevance to the first [`filter_match`](Self::filter_match),
+    /// best matches first. Applied before any `order_by` columns.
+    pub fn order_by_rank(mut self) -> Self {
+        self.order_by_rank = true;
+        self
+    }
+
+    /// Adds an `OR` group: matches rows where any condition in the group holds.
+    ///
+    /// ```ignore
+    /// User::find_in_pool(&pool).any_of(|f| f.eq("status", "active").gt("age", 65));
+    /// // WHERE ("status" = $1 OR "age" > $2)
+    /// ```
+    pub fn any_of(self, build: impl FnOnce(FilterGroup) -> FilterGroup) -> Self {
+        self.push_group(GroupKind::Any, build)
+    }
+
+    /// Adds a parenthesized `AND` group, mainly useful inside [`FilterGroup::any_of`].
+    pub fn all_of(self, build: impl FnOnce(FilterGroup) -> FilterGroup) -> Self {
+        self.push_group(GroupKind::All, build)
+    }
+
+    /// Adds a `NOT (...)` group negating the conjunction of its conditions.
+    pub fn not(self, build: impl FnOnce(FilterGroup) -> FilterGroup) -> Self {
+        self.push_group(GroupKind::Not, build)
+    }
+
+    fn push_subquery<'b, U>(
+        mut self,
+        column: Option<ColumnRef>,
+        negated: bool,
+        sub: QueryBuilder<'b, U, DB>,
+    ) -> Self
+    where
+        U: Model<DB>,
+    {
+        // A raw filter in the subquery needs the same opt-in as one on this query.
+        if sub.has_raw_filter && !sub.allow_unsafe {
+            self.has_raw_filter = true;
+        }
+        let mut query = sub.into_subquery();
+        query.attach_to(T::table_name());
+        self.filters.push(FilterExpr::Subquery {
+            column,
+            negated,
+            query: Box::new(query),
+        });
+        self
+    }
+
+    /// Adds `column IN (SELECT ...)` using another model's query.
+    ///
+    /// The subquery must select exactly one column (via [`select`](Self::select) or a
+    /// single [`group_by`](Self::group_by)). Its filters and soft-delete scoping are
+    /// kept and its binds are numbered after the outer ones; ordering, limits, and
+    /// includes on the subquery are ignored.
+    ///
+    /// ```ignore
+    /// let big_spenders = User::find_in_pool(&pool)
+    ///     .filter_in_query(
+    ///         "id",
+    ///         Order::find_in_pool(&pool).select(&["user_id"]).filter_gt("total", 100),
+    ///     )
+    ///     .all()
+    ///     .await?;
+    /// ```
+    pub fn filter_in_query<'b, U>(
+        self,
+        column: impl Into<ColumnRef>,
+        sub: QueryBuilder<'b, U, DB>,
+    ) -> Self
+    where
+        U: Model<DB>,
+    {
+        self.push_subquery(Some(column.into()), false, sub)
+    }
+
+    /// Adds `column NOT IN (SELECT ...)`; see [`filter_in_query`](Self::filter_in_query).
+    ///
+    /// As in SQL, a `NULL` in the subquery result makes `NOT IN` match nothing.
+    pub fn filter_not_in_query<'b, U>(
+        self,
+        column: impl Into<ColumnRef>,
+        sub: QueryBuilder<'b, U, DB>,
+    ) -> Self
+    where
+        U: Model<DB>,
+    {
+        self.push_subquery(Some(column.into()), true, sub)
+    }
+
+    /// Adds `EXISTS (SELECT 1 ...)` using another model's query.
+    ///
+    /// Link the subquery to this query with [`correlate`](Self::correlate):
+    ///
+    /// ```ignore
+    /// let with_posts = User::find_in_pool(&pool)
+    ///     .filter_exists(Post::find_in_pool(&pool).correlate("user_id", "id"))
+    ///     .all()
+    ///     .await?;
+    /// ```
+    pub fn filter_exists<'b, U>(self, sub: QueryBuilder<'b, U, DB>) -> Self
+    where
+        U: Model<DB>,
+    {
+        self.push_subquery(None, false, sub)
+    }
+
+    /// Adds `NOT EXISTS (SELECT 1 ...)`; see [`filter_exists`](Self::filter_exists).
+    pub fn filter_not_exists<'b, U>(self, sub: QueryBuilder<'b, U, DB>) -> Self
+    where
+        U: Model<DB>,
+    {
+        self.push_subquery(None, true, sub)
+    }
+
+    /// Adds `this_table.column = outer_table.outer_column` to a subquery.
+    ///
+    /// Only valid on a query passed to [`filter_exists`](Self::filter_exists) or
+    /// [`filter_in_query`](Self::filter_in_query); the outer table is the model of
+    /// the query the subquery is attached to.
+    pub fn correlate(
+        mut self,
+        column: impl Into<ColumnRef>,
+        outer_column: impl Into<ColumnRef>,
+    ) -> Self {
+        self.filters.push(FilterExpr::Correlate {
+            inner_table: T::table_name(),
+            inner: column.into(),
+            outer_table: None,
+            outer: outer_column.into(),
+        });
+        self
+    }
+
+    fn into_subquery(self) -> Subquery {
+        Subquery {
+            table: T::table_name(),
+            columns: self.columns,
+            filters: self.filters,
+            group_by: self.group_by,
+            having: self.having,
+            soft_delete: T::has_soft_delete() && !self.include_deleted,
+            sensitive_fields: T::sensitive_fields(),
+            known_columns: if self.unchecked_columns {
+                &[]
+            } else {
+                T::column_names()
+            },
+            outer: "",
+        }
+    }
+
+    fn format_filters_for_log(&self) -> String {
+        let sensitive_fields = T::sensitive_fields();
+        let mut rendered = String::with_capacity(128);
+        let mut first_clause = true;
+        let mut append_and = |buf: &mut String| {
+            if first_clause {
+                first_clause = false;
+            } else {
+                buf.push_str(" AND ");
+            }
+        };
+        use std::fmt::Write;
+
+        for filter in &self.filters {
+            append_and(&mut rendered);
+            filter.log_into(&mut rendered, sensitive_fields);
+        }
+
+        if T::has_soft_delete() && !self.include_deleted {
+            append_and(&mut rendered);
+            rendered.push_str("deleted_at IS NULL");
+        }
+
+        for (pos, having) in self.having.iter().enumerate() {
+            rendered.push_str(if pos == 0 { " HAVING " } else { " AND " });
+            let _ = write!(
+                rendered,
+                "{} {} ",
+                having.expr.log_string(),
+                having.op.as_str()
+            );
+            if sensitive_fields.contains(&having.expr.column.as_str()) {
+                rendered.push_str("***");
+            } else {
+                rendered.push_str(&having.value.to_log_string());
+            }
+        }
+
+        rendered
+    }
+
+    fn estimate_bind_count(&self) -> usize {
+        let count: usize = self.filters.iter().map(FilterExpr::bind_count).sum();
+        count.saturating_add(self.having.len())
+    }
+
+    /// Restricts the SELECT list to the given columns.
+    ///
+    /// Columns must belong to the model (see [`Model::column_names`]); unknown names
+    /// are rejected when the query runs. Use with [`fetch_as`](Self::fetch_as) to
+    /// decode into a DTO. `all()` still decodes into the model, so it needs every
+    /// column the model's `FromRow` reads.
+    pub fn select(mut self, columns: &[&str]) -> Self {
+        self.columns = columns
+            .iter()
+            .map(|column| ColumnRef::Owned((*column).to_string()))
+            .collect();
+        self
+    }
+
+    /// Adds an `INNER JOIN` on a declared relation (e.g. `User::posts`).
+    ///
+    /// Once a query has joins, bare column names refer to this model's table; use
+    /// `("posts", "title")` to filter on the joined table. Joining a `has_many`
+    /// relation repeats parent rows per matching child, so pair it with
+    /// [`distinct`](Self::distinct) when loading parents.
+    pub fn join(mut self, relation: Relation) -> Self {
+        self.joins.push(JoinExpr {
+            kind: JoinKind::Inner,
+            relation,
+        });
+        self
+    }
+
+    /// Adds a `LEFT JOIN` on a declared relation; see [`join`](Self::join).
+    pub fn left_join(mut self, relation: Relation) -> Self {
+        self.joins.push(JoinExpr {
+            kind: JoinKind::Left,
+            relation,
+        });
+        self
+    }
+
+    /// Selects only distinct rows (`SELECT DISTINCT`).
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Table prefix for bare column names; only needed once joins are involved.
+    fn scope(&self) -> Option<&'static str> {
+        if self.joins.is_empty() {
+            None
+        } else {
+            Some(T::table_name())
+        }
+    }
+
+    fn render_from_into(&self, sql: &mut String) {
+        use std::fmt::Write;
+
+        let base = DB::quote_identifier(T::table_name());
+        sql.push_str(&base);
+        for join in &self.joins {
+            // Relations without join metadata are rejected before execution.
+            let Some(info) = join.relation.join_info() else {
+                continue;
+            };
+            let keyword = match join.kind {
+                JoinKind::Inner => "INNER JOIN",
+                JoinKind::Left => "LEFT JOIN",
+            };
+            let table = DB::quote_identifier(info.table);
+            let _ = write!(
+                sql,
+                " {} {} ON {}.{} = {}.{}",
+                keyword,
+                table,
+                table,
+                DB::quote_identifier(info.column),
+                base,
+                DB::quote_identifier(info.local_column)
+            );
+            if info.soft_delete {
+                let _ = write!(sql, " AND {}.deleted_at IS NULL", table);
+            }
+        }
+    }
+
+    fn ensure_no_joins(&self, operation: &str) -> Result<(), sqlx::Error> {
+        if self.joins.is_empty() {
+            Ok(())
+        } else {
+            Err(sqlx::Error::Protocol(format!(
+                "join() is not supported for {}; use filter_exists or filter_in_query",
+                operation
+            )))
+        }
+    }
+
+    /// Adds a `GROUP BY` column. Can be chained for multiple columns.
+    ///
+    /// When no explicit [`select`](Self::select) is given, the grouped columns
+    /// are selected ahead of any aggregates.
+    pub fn group_by(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.group_by.push(column.into());
+        self
+    }
+
+    /// Selects an aggregate over `column` (use `"*"` with [`Aggregate::Count`]) as `alias`.
+    pub fn aggregate(
+        mut self,
+        aggregate: Aggregate,
+        column: impl Into<ColumnRef>,
+        alias: impl Into<String>,
+    ) -> Self {
+        self.aggregates.push((
+            AggregateExpr {
+                aggregate,
+                column: column.into(),
+            },
+            alias.into(),
+        ));
+        self
+    }
+
+    /// Selects `COUNT(*)` as `alias`.
+    pub fn count_as(self, alias: impl Into<String>) -> Self {
+        self.aggregate(Aggregate::Count, "*", alias)
+    }
+
+    /// Selects `SUM(column)` as `alias`.
+    ///
+    /// Postgres and MySQL return `NUMERIC`/`DECIMAL` when summing integer columns.
+    pub fn sum_as(self, column: impl Into<ColumnRef>, alias: impl Into<String>) -> Self {
+        self.aggregate(Aggregate::Sum, column, alias)
+    }
+
+    /// Selects `AVG(column)` as `alias`, decoded as `f64` on every dialect.
+    pub fn avg_as(self, column: impl Into<ColumnRef>, alias: impl Into<String>) -> Self {
+        self.aggregate(Aggregate::Avg, column, alias)
+    }
+
+    /// Selects `MIN(column)` as `alias`.
+    pub fn min_as(self, column: impl Into<ColumnRef>, alias: impl Into<String>) -> Self {
+        self.aggregate(Aggregate::Min, column, alias)
+    }
+
+    /// Selects `MAX(column)` as `alias`.
+    pub fn max_as(self, column: impl Into<ColumnRef>, alias: impl Into<String>) -> Self {
+        self.aggregate(Aggregate::Max, column, alias)
+    }
+
+    fn push_having(
+        mut self,
+        aggregate: Aggregate,
+        column: ColumnRef,
+        op: FilterOp,
+        value: BindValue,
+    ) -> Self {
+        self.having.push(HavingExpr {
+            expr: AggregateExpr { aggregate, column },
+            op,
+            value,
+        });
+        self
+    }
+
+    /// Adds a `HAVING aggregate(column) = value` condition.
+    pub fn having_eq(
+        self,
+        aggregate: Aggregate,
+        column: impl Into<ColumnRef>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.push_having(aggregate, column.into(), FilterOp::Eq, value.into())
+    }
+
+    /// Adds a `HAVING aggregate(column) != value` condition.
+    pub fn having_ne(
+        self,
+        aggregate: Aggregate,
+        column: impl Into<ColumnRef>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.push_having(aggregate, column.into(), FilterOp::Ne, value.into())
+    }
+
+    /// Adds a `HAVING aggregate(column) > value` condition.
+    pub fn having_gt(
+        self,
+        aggregate: Aggregate,
+        column: impl Into<ColumnRef>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.push_having(aggregate, column.into(), FilterOp::Gt, value.into())
+    }
+
+    /// Adds a `HAVING aggregate(column) >= value` condition.
+    pub fn having_gte(
+        self,
+        aggregate: Aggregate,
+        column: impl Into<ColumnRef>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.push_having(aggregate, column.into(), FilterOp::Gte, value.into())
+    }
+
+    /// Adds a `HAVING aggregate(column) < value` condition.
+    pub fn having_lt(
+        self,
+        aggregate: Aggregate,
         column: impl Into<ColumnRef>,
         value: impl Into<BindValue>,
     ) -> Self {
-        self.filters.push(FilterExpr::Compare {
-            column: column.into(),
-            op: FilterOp::Like,
-            values: smallvec![value.into()],
-        });
-        self
+        self.push_having(aggregate, column.into(), FilterOp::Lt, value.into())
     }
 
-    /// Filters rows where the column IS NULL.
-    pub fn filter_is_null(mut self, column: impl Into<ColumnRef>) -> Self {
-        self.filters.push(FilterExpr::NullCheck {
-            column: column.into(),
-            is_null: true,
-        });
-        self
+    /// Adds a `HAVING aggregate(column) <= value` condition.
+    pub fn having_lte(
+        self,
+        aggregate: Aggregate,
+        column: impl Into<ColumnRef>,
+        value: impl Into<BindValue>,
+    ) -> Self {
+        self.push_having(aggregate, column.into(), FilterOp::Lte, value.into())
     }
 
-    /// Filters rows where the column IS NOT NULL.
-    pub fn filter_is_not_null(mut self, column: impl Into<ColumnRef>) -> Self {
-        self.filters.push(FilterExpr::NullCheck {
+    /// Sorts the results by `column` in ascending order.
+    ///
+    /// Can be chained to sort by several columns; earlier calls take precedence.
+    pub fn order_by(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.orders.push(OrderExpr {
             column: column.into(),
-            is_null: false,
+            direction: SortDirection::Asc,
+            nulls: None,
         });
         self
     }
 
-    /// Adds an IN filter (`column IN (values...)`).
-    pub fn filter_in<I, V>(mut self, column: impl Into<ColumnRef>, values: I) -> Self
-    where
-        I: IntoIterator<Item = V>,
-        V: Into<BindValue>,
-    {
-        let values: SmallVec<[BindValue; 2]> = values.into_iter().map(Into::into).collect();
-        self.filters.push(FilterExpr::Compare {
+    /// Sorts the results by `column` in descending order.
+    pub fn order_by_desc(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.orders.push(OrderExpr {
             column: column.into(),
-            op: FilterOp::In,
-            values,
+            direction: SortDirection::Desc,
+            nulls: None,
         });
         self
     }
 
-    fn format_filters_for_log(&self) -> String {
-        let sensitive_fields = T::sensitive_fields();
-        let mut rendered = String::with_capacity(128);
-        let mut first_clause = true;
-        let mut append_and = |buf: &mut String| {
-            if first_clause {
-                first_clause = false;
-            } else {
-                buf.push_str(" AND ");
-            }
-        };
-        use std::fmt::Write;
-
-        for filter in &self.filters {
-            match filter {
-                FilterExpr::Raw(_) => {
-                    append_and(&mut rendered);
-                    rendered.push_str("RAW(<redacted>)");
-                }
-                FilterExpr::Compare { column, op, values } => {
-                    let column_name = column.as_str();
-                    let is_sensitive = sensitive_fields.contains(&column_name);
-                    if op.is_in() {
-                        if values.is_empty() {
-                            append_and(&mut rendered);
-                            rendered.push_str("1=0");
-                            continue;
-                        }
-                        append_and(&mut rendered);
-                        let _ = write!(rendered, "{} IN (", column_name);
-                        for (idx, value) in values.iter().enumerate() {
-                            if idx > 0 {
-                                rendered.push_str(", ");
-                            }
-                            if is_sensitive {
-                                rendered.push_str("***");
-                            } else {
-                                rendered.push_str(&value.to_log_string());
-                            }
-                        }
-                        rendered.push(')');
-                    } else {
-                        append_and(&mut rendered);
-                        let _ = write!(rendered, "{} {} ", column_name, op.as_str());
-                        if is_sensitive {
-                            rendered.push_str("***");
-                        } else if let Some(value) = values.first() {
-                            rendered.push_str(&value.to_log_string());
-                        } else {
-                            rendered.push_str("NULL");
-                        }
-                    }
-                }
-                FilterExpr::NullCheck { column, is_null } => {
-                    append_and(&mut rendered);
-                    if *is_null {
-                        let _ = write!(rendered, "{} IS NULL", column.as_str());
-                    } else {
-                        let _ = write!(rendered, "{} IS NOT NULL", column.as_str());
-                    }
-                }
-            }
-        }
-
-        if T::has_soft_delete() && !self.include_deleted {
-            append_and(&mut rendered);
-            rendered.push_str("deleted_at IS NULL");
+    /// Places NULL values first for the most recent `order_by` column.
+    ///
+    /// Has no effect when no ordering has been added yet.
+    pub fn nulls_first(mut self) -> Self {
+        if let Some(order) = self.orders.last_mut() {
+            order.nulls = Some(NullsOrder::First);
         }
-
-        rendered
+        self
     }
 
-    fn estimate_bind_count(&self) -> usize {
-        let mut count = 0usize;
-        for filter in &self.filters {
-            if let FilterExpr::Compare { op, values, .. } = filter {
-                if op.is_in() {
-                    count = count.saturating_add(values.len());
-                } else {
-                    count = count.saturating_add(1);
-                }
-            }
+    /// Places NULL values last for the most recent `order_by` column.
+    ///
+    /// Has no effect when no ordering has been added yet.
+    pub fn nulls_last(mut self) -> Self {
+        if let Some(order) = self.orders.last_mut() {
+            order.nulls = Some(NullsOrder::Last);
         }
-        count
+        self
     }
 
     /// Limits the number of rows returned by the query.
@@ -595,6 +2697,40 @@ where
         self
     }
 
+    /// Enables keyset pagination ordered by `columns` ascending.
+    ///
+    /// The columns must be `NOT NULL` and together unique (end with the primary
+    /// key, e.g. `&["created_at", "id"]`). Run with [`cursor_page`](Self::cursor_page).
+    pub fn paginate_by(mut self, columns: &[&str]) -> Self {
+        self.keyset = columns
+            .iter()
+            .map(|column| ColumnRef::Owned((*column).to_string()))
+            .collect();
+        self.keyset_desc = false;
+        self
+    }
+
+    /// Same as [`paginate_by`](Self::paginate_by), but ordered descending (newest first).
+    pub fn paginate_by_desc(self, columns: &[&str]) -> Self {
+        let mut query = self.paginate_by(columns);
+        query.keyset_desc = true;
+        query
+    }
+
+    /// Starts the page right after the row encoded in `cursor`
+    /// (a [`CursorPage::next_cursor`](crate::CursorPage::next_cursor)).
+    pub fn after(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(CursorPosition::After(cursor.into()));
+        self
+    }
+
+    /// Ends the page right before the row encoded in `cursor`
+    /// (a [`CursorPage::prev_cursor`](crate::CursorPage::prev_cursor)).
+    pub fn before(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(CursorPosition::Before(cursor.into()));
+        self
+    }
+
     /// Eager loads a related model.
     pub fn include(mut self, relation: impl Into<String>) -> Self {
         self.includes.push(relation.into());
@@ -607,6 +2743,15 @@ where
         self
     }
 
+    /// Leaves the model's `updated_at` column alone in bulk updates.
+    ///
+    /// By default bulk updates also set a managed `updated_at` column to the
+    /// current time (see [`Model::touch_updated_at`]).
+    pub fn without_timestamps(mut self) -> Self {
+        self.skip_timestamps = true;
+        self
+    }
+
     /// Explicitly allows potentially unsafe raw filters.
     /// Enables execution of queries with raw SQL filters.
     pub fn allow_unsafe(mut self) -> Self {
@@ -614,6 +2759,16 @@ where
         self
     }
 
+    /// Skips checking column names against [`Model::column_names`] before execution.
+    ///
+    /// By default every selected, filtered, ordered, and updated column must be a
+    /// model column; use this when a name refers to a computed expression or a
+    /// column the model does not map.
+    pub fn allow_unknown_columns(mut self) -> Self {
+        self.unchecked_columns = true;
+        self
+    }
+
     /// Enables a fast path that skips logging and metrics for hot queries.
     pub fn fast(mut self) -> Self {
         self.fast_path = true;
@@ -651,56 +2806,135 @@ where
     /// Returns the SELECT SQL that would be executed for this query.
     pub fn to_sql(&self) -> String {
         let mut sql = String::with_capacity(128); // Pre-allocate reasonable size
-        use std::fmt::Write;
+        let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
+        self.render_select_into(&mut sql, &mut dummy_binds);
+        sql
+    }
 
-        sql.push_str("SELECT * FROM ");
-        sql.push_str(T::table_name());
+    /// Returns the `SELECT COUNT(*)` SQL that would be executed by [`count`](Self::count).
+    pub fn to_count_sql(&self) -> String {
+        let mut sql = String::with_capacity(128);
+        let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
+        self.render_count_into(&mut sql, &mut dummy_binds);
+        sql
+    }
 
+    /// Returns the `SELECT EXISTS(...)` SQL that would be executed by [`exists`](Self::exists).
+    pub fn to_exists_sql(&self) -> String {
+        let mut sql = String::with_capacity(128);
         let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
-        self.render_where_clause_into(&mut sql, &mut dummy_binds, 1);
+        self.render_exists_into(&mut sql, &mut dummy_binds);
+        sql
+    }
 
-        if let Some(limit) = self.limit {
-            let _ = write!(sql, " LIMIT {}", limit);
-        }
+    /// Whether the SELECT collapses rows (`DISTINCT`, `GROUP BY` or `HAVING`), so
+    /// counting has to go through it.
+    fn collapses_rows(&self) -> bool {
+        self.distinct || !self.group_by.is_empty() || !self.having.is_empty()
+    }
 
-        if let Some(offset) = self.offset {
-            let _ = write!(sql, " OFFSET {}", offset);
+    fn render_count_into(&self, sql: &mut String, binds: &mut SmallVec<[BindValue; 8]>) {
+        use std::fmt::Write;
+
+        if self.collapses_rows() {
+            // Count the rows the SELECT returns: distinct rows or groups.
+            sql.push_str("SELECT COUNT(*) FROM (");
+            self.render_rows_into(sql, binds);
+            sql.push_str(") AS premix_count");
+        } else if !self.joins.is_empty() {
+            // A to-many join repeats a row per match; count each model row once.
+            let table = DB::quote_identifier(T::table_name());
+            let keys: Vec<String> = T::primary_key_columns()
+                .iter()
+                .map(|column| format!("{}.{}", table, DB::quote_identifier(column)))
+                .collect();
+            let _ = write!(
+                sql,
+                "SELECT COUNT(*) FROM (SELECT DISTINCT {} FROM ",
+                keys.join(", ")
+            );
+            self.render_from_into(sql);
+            self.render_where_clause_into(sql, binds, 1);
+            sql.push_str(") AS premix_count");
+        } else {
+            sql.push_str("SELECT COUNT(*) FROM ");
+            self.render_from_into(sql);
+            self.render_where_clause_into(sql, binds, 1);
         }
+    }
 
-        sql
+    fn render_exists_into(&self, sql: &mut String, binds: &mut SmallVec<[BindValue; 8]>) {
+        if !self.group_by.is_empty() || !self.having.is_empty() {
+            // HAVING can drop every group, so ask whether any group survives.
+            sql.push_str("SELECT EXISTS(");
+            self.render_rows_into(sql, binds);
+            sql.push(')');
+            return;
+        }
+        sql.push_str("SELECT EXISTS(SELECT 1 FROM ");
+        self.render_from_into(sql);
+        self.render_where_clause_into(sql, binds, 1);
+        sql.push(')');
     }
 
     /// Returns the UPDATE SQL that would be executed for this query.
     pub fn to_update_sql(&self, values: &serde_json::Value) -> Result<String, sqlx::Error> {
-        let obj = values.as_object().ok_or_else(|| {
-            sqlx::Error::Protocol("Bulk update requires a JSON object".to_string())
-        })?;
-
-        let mut sql = String::with_capacity(256);
-        use std::fmt::Write;
-
-        let _ = write!(sql, "UPDATE {} SET ", T::table_name());
+        let set = self.touch_updated_at(UpdateSet::from_json(values)?);
+        let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
+        self.render_update_sql(&set, &mut dummy_binds)
+    }
 
-        let mut i = 1;
-        let mut first = true;
+    /// Returns the UPDATE SQL that [`update_with`](Self::update_with) would execute.
+    pub fn to_update_with_sql(
+        &self,
+        build: impl FnOnce(UpdateSet) -> UpdateSet,
+    ) -> Result<String, sqlx::Error> {
+        let set = self.touch_updated_at(build(UpdateSet::new()));
+        let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
+        self.render_update_sql(&set, &mut dummy_binds)
+    }
 
-        for k in obj.keys() {
-            if !first {
-                sql.push_str(", ");
+    /// Adds the model's `updated_at` assignment unless disabled or already assigned.
+    fn touch_updated_at(&self, set: UpdateSet) -> UpdateSet {
+        match T::touch_updated_at() {
+            Some((column, now))
+                if !self.skip_timestamps && !set.is_empty() && !set.assigns(column) =>
+            {
+                set.set(column, now)
             }
-            let p = DB::placeholder(i);
-            let _ = write!(sql, "{} = {}", DB::quote_identifier(k), p);
-            i += 1;
-            first = false;
+            _ => set,
         }
+    }
 
-        let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
-        self.render_where_clause_into(&mut sql, &mut dummy_binds, obj.len() + 1);
+    fn render_update_sql(
+        &self,
+        set: &UpdateSet,
+        binds: &mut SmallVec<[BindValue; 8]>,
+    ) -> Result<String, sqlx::Error> {
+        self.ensure_no_joins("update")?;
+        if set.is_empty() {
+            return Err(sqlx::Error::Protocol(
+                "Bulk update requires at least one column".to_string(),
+            ));
+        }
+
+        let mut sql = String::with_capacity(256);
+        sql.push_str("UPDATE ");
+        sql.push_str(&DB::quote_identifier(T::table_name()));
+        sql.push_str(" SET ");
+        let mut idx = 1;
+        set.render_into::<DB>(&mut sql, binds, &mut idx);
+        self.render_where_clause_into(&mut sql, binds, idx);
         Ok(sql)
     }
 
     /// Returns the DELETE (or soft delete) SQL that would be executed for this query.
     pub fn to_delete_sql(&self) -> String {
+        let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
+        self.render_delete_sql(&mut dummy_binds)
+    }
+
+    fn render_delete_sql(&self, binds: &mut SmallVec<[BindValue; 8]>) -> String {
         let mut sql = String::with_capacity(128);
         use std::fmt::Write;
 
@@ -708,19 +2942,205 @@ where
             let _ = write!(
                 sql,
                 "UPDATE {} SET {} = {}",
-                T::table_name(),
+                DB::quote_identifier(T::table_name()),
                 DB::quote_identifier("deleted_at"),
                 DB::current_timestamp_fn()
             );
         } else {
-            let _ = write!(sql, "DELETE FROM {}", T::table_name());
+            let _ = write!(sql, "DELETE FROM {}", DB::quote_identifier(T::table_name()));
         }
 
-        let mut dummy_binds: SmallVec<[BindValue; 8]> = SmallVec::new();
-        self.render_where_clause_into(&mut sql, &mut dummy_binds, 1);
+        self.render_where_clause_into(&mut sql, binds, 1);
         sql
     }
 
+    fn render_select_into(&self, sql: &mut String, binds: &mut SmallVec<[BindValue; 8]>) {
+        use std::fmt::Write;
+
+        let next_idx = self.render_rows_into(sql, binds);
+        self.render_order_by_into(sql, binds, next_idx);
+
+        if let Some(limit) = self.limit {
+            let _ = write!(sql, " LIMIT {}", limit);
+        }
+
+        if let Some(offset) = self.offset {
+            let _ = write!(sql, " OFFSET {}", offset);
+        }
+
+        if let Some((mode, wait)) = self.lock {
+            let clause = DB::render_row_lock(mode, wait);
+            if !clause.is_empty() {
+                sql.push(' ');
+                sql.push_str(&clause);
+            }
+        }
+    }
+
+    /// Renders the SELECT up to `GROUP BY`/`HAVING`, without ordering or paging,
+    /// and returns the next placeholder index.
+    fn render_rows_into(&self, sql: &mut String, binds: &mut SmallVec<[BindValue; 8]>) -> usize {
+        use std::fmt::Write;
+
+        let scope = self.scope();
+        sql.push_str(if self.distinct {
+            "SELECT DISTINCT "
+        } else {
+            "SELECT "
+        });
+        if self.columns.is_empty() && self.aggregates.is_empty() {
+            if let Some(table) = scope {
+                sql.push_str(&DB::quote_identifier(table));
+                sql.push('.');
+            }
+            sql.push('*');
+        } else {
+            // Grouped columns lead the projection unless an explicit select was given.
+            let columns: &[ColumnRef] = if self.columns.is_empty() {
+                &self.group_by
+            } else {
+                &self.columns
+            };
+            let mut first = true;
+            for column in columns {
+                if !first {
+                    sql.push_str(", ");
+                }
+                sql.push_str(&column.render::<DB>(scope));
+                first = false;
+            }
+            for (expr, alias) in &self.aggregates {
+                if !first {
+                    sql.push_str(", ");
+                }
+                let _ = write!(
+                    sql,
+                    "{} AS {}",
+                    expr.render::<DB>(scope),
+                    DB::quote_identifier(alias)
+                );
+                first = false;
+            }
+        }
+        sql.push_str(" FROM ");
+        self.render_from_into(sql);
+
+        let next_idx = self.render_where_clause_into(sql, binds, 1);
+        render_group_by_into::<DB>(sql, binds, &self.group_by, &self.having, next_idx, scope)
+    }
+
+    /// Checks selected, grouped, filtered, ordered, and aggregated columns against
+    /// [`Model::column_names`], and qualified ones against their joined table;
+    /// filters and orders are skipped on the unsafe fast paths.
+    fn ensure_known_columns(&self) -> Result<(), sqlx::Error> {
+        let mut tables: SmallVec<[(&str, &[&str]); 2]> =
+            smallvec![(T::table_name(), T::column_names())];
+        for join in &self.joins {
+            if let Some(info) = join.relation.join_info() {
+                tables.push((info.table, info.columns));
+            }
+        }
+        if self.unchecked_columns {
+            // Qualifiers are still limited to joined tables; only names go unchecked.
+            for (_, known) in &mut tables {
+                *known = &[];
+            }
+        }
+        let aggregate_columns = self
+            .aggregates
+            .iter()
+            .map(|(expr, _)| &expr.column)
+            .chain(self.having.iter().map(|having| &having.expr.column))
+            .filter(|column| column.as_str() != "*");
+        for column in self
+            .columns
+            .iter()
+            .chain(self.group_by.iter())
+            .chain(self.keyset.iter())
+            .chain(aggregate_columns)
+        {
+            check_column(column, &tables)?;
+        }
+        if self.unsafe_fast {
+            return Ok(());
+        }
+        for filter in &self.filters {
+            filter.check_columns(&tables)?;
+        }
+        for order in &self.orders {
+            check_column(&order.column, &tables)?;
+        }
+        Ok(())
+    }
+
+    fn ensure_known_set_columns(&self, set: &UpdateSet) -> Result<(), sqlx::Error> {
+        if self.unchecked_columns || self.unsafe_fast {
+            return Ok(());
+        }
+        set.check_columns(T::table_name(), T::column_names())
+    }
+
+    fn render_order_by_into(
+        &self,
+        sql: &mut String,
+        binds: &mut SmallVec<[BindValue; 8]>,
+        start_index: usize,
+    ) {
+        let rank = if self.order_by_rank {
+            let mut idx = start_index;
+            self.filters
+                .iter()
+                .find(|filter| matches!(filter, FilterExpr::FullText { .. }))
+                .and_then(|filter| {
+                    filter.render_fulltext::<DB>(binds, &mut idx, self.scope(), true)
+                })
+        } else {
+            None
+        };
+        if self.orders.is_empty() && rank.is_none() {
+            return;
+        }
+        use std::fmt::Write;
+
+        sql.push_str(" ORDER BY ");
+        if let Some(rank) = &rank {
+            sql.push_str(rank);
+        }
+        for (idx, order) in self.orders.iter().enumerate() {
+            if idx > 0 || rank.is_some() {
+                sql.push_str(", ");
+            }
+            let column = order.column.render::<DB>(self.scope());
+            match order.nulls {
+                Some(nulls) if DB::supports_nulls_order() => {
+                    let nulls = match nulls {
+                        NullsOrder::First => "NULLS FIRST",
+                        NullsOrder::Last => "NULLS LAST",
+                    };
+                    let _ = write!(sql, "{} {} {}", column, order.direction.as_str(), nulls);
+                }
+                Some(nulls) => {
+                    // Emulate NULLS FIRST/LAST with a leading `IS NULL` sort key.
+                    let null_key = match nulls {
+                        NullsOrder::First => "DESC",
+                        NullsOrder::Last => "ASC",
+                    };
+                    let _ = write!(
+                        sql,
+                        "{} IS NULL {}, {} {}",
+                        column,
+                        null_key,
+                        column,
+                        order.direction.as_str()
+                    );
+                }
+                None => {
+                    let _ = write!(sql, "{} {}", column, order.direction.as_str());
+                }
+            }
+        }
+    }
+
     // Optimized version that writes to buffer
     #[inline(always)]
     fn render_where_clause_into(
@@ -728,10 +3148,10 @@ where
         sql: &mut String,
         binds: &mut SmallVec<[BindValue; 8]>,
         start_index: usize,
-    ) {
+    ) -> usize {
         let mut idx = start_index;
         let mut first_clause = true;
-        use std::fmt::Write;
+        let scope = self.scope();
 
         // Helper to handle AND prefix
         let mut append_and = |sql: &mut String| {
@@ -739,63 +3159,25 @@ where
                 sql.push_str(" WHERE ");
                 first_clause = false;
             } else {
-                sql.push_str(" AND ");
-            }
-        };
-
-        for filter in &self.filters {
-            match filter {
-                FilterExpr::Raw(condition) => {
-                    append_and(sql);
-                    sql.push_str(condition);
-                }
-                FilterExpr::Compare { column, op, values } => {
-                    if op.is_in() {
-                        if values.is_empty() {
-                            append_and(sql);
-                            sql.push_str("1=0");
-                            continue;
-                        }
-                        append_and(sql);
-                        let _ = write!(sql, "{} IN (", DB::quote_identifier(column.as_str()));
-                        let placeholders = crate::cached_placeholders_from::<DB>(idx, values.len());
-                        sql.push_str(placeholders);
-                        sql.push(')');
-                        idx = idx.saturating_add(values.len());
-                        for v in values {
-                            binds.push(v.clone());
-                        }
-                    } else {
-                        append_and(sql);
-                        let _ = write!(
-                            sql,
-                            "{} {} {}",
-                            DB::quote_identifier(column.as_str()),
-                            op.as_str(),
-                            DB::placeholder(idx)
-                        );
-                        idx += 1;
-                        if let Some(v) = values.first() {
-                            binds.push(v.clone());
-                        }
-                    }
-                }
-                FilterExpr::NullCheck { column, is_null } => {
-                    append_and(sql);
-                    if *is_null {
-                        let _ = write!(sql, "{} IS NULL", DB::quote_identifier(column.as_str()));
-                    } else {
-                        let _ =
-                            write!(sql, "{} IS NOT NULL", DB::quote_identifier(column.as_str()));
-                    }
-                }
+                sql.push_str(" AND ");
             }
+        };
+
+        for filter in &self.filters {
+            append_and(sql);
+            filter.render_into::<DB>(sql, binds, &mut idx, scope);
         }
 
         if T::has_soft_delete() && !self.include_deleted {
             append_and(sql);
+            if let Some(table) = scope {
+                sql.push_str(&DB::quote_identifier(table));
+                sql.push('.');
+            }
             sql.push_str("deleted_at IS NULL");
         }
+
+        idx
     }
 }
 
@@ -819,7 +3201,50 @@ where
     chrono::NaiveDate: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
     sqlx::types::Json<serde_json::Value>: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
 {
+    /// Guards shared by the bulk `update*`/`delete*` methods.
+    fn ensure_bulk_allowed(&self, operation: &str) -> Result<(), sqlx::Error> {
+        self.ensure_safe_filters()?;
+        self.ensure_no_joins(operation)?;
+        if self.filters.is_empty() && !self.allow_unsafe && !self.unsafe_fast {
+            return Err(sqlx::Error::Protocol(format!(
+                "Refusing bulk {} without filters",
+                operation
+            )));
+        }
+        Ok(())
+    }
+
     fn ensure_safe_filters(&self) -> Result<(), sqlx::Error> {
+        check_raw_binds(&self.filters)?;
+        check_subqueries(&self.filters, false)?;
+        check_fulltext_keys::<DB>(&self.filters)?;
+        if self.lock.is_some() && matches!(self.executor, Executor::Pool(_)) {
+            return Err(sqlx::Error::Protocol(
+                "Row locks (for_update/for_share) require a transaction; use find_in_tx"
+                    .to_string(),
+            ));
+        }
+        if self.order_by_rank
+            && !self
+                .filters
+                .iter()
+                .any(|filter| matches!(filter, FilterExpr::FullText { .. }))
+        {
+            return Err(sqlx::Error::Protocol(
+                "order_by_rank requires a top-level filter_match".to_string(),
+            ));
+        }
+        for join in &self.joins {
+            let name = join.relation.name();
+            if join.relation.join_info().is_none() || !T::relation_names().contains(&name) {
+                return Err(sqlx::Error::Protocol(format!(
+                    "Relation '{}' cannot be joined from {}; declare it with #[has_many]/#[belongs_to] on a field",
+                    name,
+                    T::table_name()
+                )));
+            }
+        }
+        self.ensure_known_columns()?;
         if self.unsafe_fast {
             return Ok(());
         }
@@ -837,25 +3262,16 @@ where
     /// eager loading for any included relations.
     #[tracing::instrument(skip(self), fields(table = T::table_name()))]
     pub async fn all(mut self) -> Result<Vec<T>, sqlx::Error> {
+        self.fetch_models().await
+    }
+
+    async fn fetch_models(&mut self) -> Result<Vec<T>, sqlx::Error> {
         self.ensure_safe_filters()?;
 
         let mut sql = String::with_capacity(128);
-        sql.push_str("SELECT * FROM ");
-        sql.push_str(T::table_name());
-
         let mut where_binds: SmallVec<[BindValue; 8]> =
             SmallVec::with_capacity(self.estimate_bind_count());
-        self.render_where_clause_into(&mut sql, &mut where_binds, 1);
-
-        if let Some(limit) = self.limit {
-            use std::fmt::Write;
-            let _ = write!(sql, " LIMIT {}", limit);
-        }
-
-        if let Some(offset) = self.offset {
-            use std::fmt::Write;
-            let _ = write!(sql, " OFFSET {}", offset);
-        }
+        self.render_select_into(&mut sql, &mut where_binds);
 
         #[cfg(debug_assertions)]
         if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
@@ -889,18 +3305,477 @@ where
             return Ok(results);
         }
 
-        for relation in self.includes {
+        for relation in &self.includes {
             match &mut self.executor {
                 Executor::Pool(pool) => {
-                    T::eager_load(&mut results, &relation, Executor::Pool(*pool)).await?;
+                    T::eager_load(&mut results, relation, Executor::Pool(*pool)).await?;
                 }
                 Executor::Conn(conn) => {
-                    T::eager_load(&mut results, &relation, Executor::Conn(&mut **conn)).await?;
+                    T::eager_load(&mut results, relation, Executor::Conn(&mut **conn)).await?;
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Executes the query and decodes each row into `R` instead of the model.
+    ///
+    /// Pair with [`select`](Self::select) to read only the columns a view needs.
+    /// Filters, ordering, pagination, and soft-delete scoping apply as usual;
+    /// relations are not eager-loaded.
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn fetch_as<R>(mut self) -> Result<Vec<R>, sqlx::Error>
+    where
+        R: for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+    {
+        self.ensure_safe_filters()?;
+
+        let mut sql = String::with_capacity(128);
+        let mut where_binds: SmallVec<[BindValue; 8]> =
+            SmallVec::with_capacity(self.estimate_bind_count());
+        self.render_select_into(&mut sql, &mut where_binds);
+
+        #[cfg(debug_assertions)]
+        if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
+            let filters = self.format_filters_for_log();
+            tracing::debug!(
+                operation = "select",
+                sql = %sql,
+                filters = %filters,
+                "premix query"
+            );
+        }
+
+        let start = Instant::now();
+        let results: Vec<R> = match &mut self.executor {
+            Executor::Pool(pool) => {
+                let base = apply_persistent_query_as(sqlx::query_as::<DB, R>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_all(*pool).await?
+            }
+            Executor::Conn(conn) => {
+                let base = apply_persistent_query_as(sqlx::query_as::<DB, R>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_all(&mut **conn).await?
+            }
+        };
+        if !self.fast_path {
+            record_query_metrics("select", T::table_name(), start.elapsed());
+        }
+        Ok(results)
+    }
+
+    /// Asks the database how it would run this query's `SELECT`, with the real binds.
+    ///
+    /// Runs `EXPLAIN QUERY PLAN` on SQLite and `EXPLAIN ... FORMAT JSON` on
+    /// Postgres/MySQL, and returns the parsed [`QueryPlan`]. The query itself is
+    /// not executed.
+    ///
+    /// ```ignore
+    /// let plan = User::find_in_pool(&pool).filter_eq("email", email).explain().await?;
+    /// assert!(!plan.has_full_scan(), "{}", plan.raw);
+    /// ```
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn explain(mut self) -> Result<QueryPlan, sqlx::Error>
+    where
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        sqlx::types::Json<serde_json::Value>: for<'r> sqlx::Decode<'r, DB>,
+    {
+        self.fetch_plan(false).await
+    }
+
+    /// Like [`explain`](Self::explain), but executes the query and reports actual
+    /// row counts (`EXPLAIN ANALYZE`). Not supported on SQLite.
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn explain_analyze(mut self) -> Result<QueryPlan, sqlx::Error>
+    where
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        sqlx::types::Json<serde_json::Value>: for<'r> sqlx::Decode<'r, DB>,
+    {
+        self.fetch_plan(true).await
+    }
+
+    async fn fetch_plan(&mut self, analyze: bool) -> Result<QueryPlan, sqlx::Error>
+    where
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        sqlx::types::Json<serde_json::Value>: for<'r> sqlx::Decode<'r, DB>,
+    {
+        let prefix = DB::explain_prefix(analyze).ok_or_else(|| {
+            sqlx::Error::Protocol("explain_analyze is not supported by this database".to_string())
+        })?;
+        self.ensure_safe_filters()?;
+
+        let mut sql = String::with_capacity(128);
+        let mut where_binds: SmallVec<[BindValue; 8]> =
+            SmallVec::with_capacity(self.estimate_bind_count());
+        self.render_select_into(&mut sql, &mut where_binds);
+        let explain_sql = format!("{}{}", prefix, sql);
+
+        if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
+            let filters = self.format_filters_for_log();
+            tracing::debug!(
+                operation = "explain",
+                sql = %explain_sql,
+                filters = %filters,
+                "premix query"
+            );
+        }
+
+        let base = apply_persistent_query(sqlx::query::<DB>(&explain_sql), false);
+        let query = where_binds.into_iter().fold(base, bind_value_query);
+        let rows = match &mut self.executor {
+            Executor::Pool(pool) => query.fetch_all(*pool).await?,
+            Executor::Conn(conn) => query.fetch_all(&mut **conn).await?,
+        };
+        crate::explain::read_plan::<DB>(sql, &rows)
+    }
+
+    /// Executes a keyset-paginated query and returns one [`CursorPage`].
+    ///
+    /// Requires [`paginate_by`](Self::paginate_by); the position comes from
+    /// [`after`](Self::after) / [`before`](Self::before) (neither means the first page).
+    /// Filters, soft-delete scoping, and includes apply as with [`all`](Self::all).
+    ///
+    /// ```ignore
+    /// let page = Post::find_in_pool(&pool)
+    ///     .paginate_by(&["created_at", "id"])
+    ///     .cursor_page(20)
+    ///     .await?;
+    /// let next = Post::find_in_pool(&pool)
+    ///     .paginate_by(&["created_at", "id"])
+    ///     .after(page.next_cursor.unwrap())
+    ///     .cursor_page(20)
+    ///     .await?;
+    /// ```
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn cursor_page(mut self, per_page: u32) -> Result<CursorPage<T>, sqlx::Error>
+    where
+        i32: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        f64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        bool: for<'r> sqlx::Decode<'r, DB>,
+        uuid::Uuid: for<'r> sqlx::Decode<'r, DB>,
+        chrono::DateTime<chrono::Utc>: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDateTime: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDate: for<'r> sqlx::Decode<'r, DB>,
+    {
+        if self.keyset.is_empty() {
+            return Err(sqlx::Error::Protocol(
+                "cursor_page requires paginate_by(...)".to_string(),
+            ));
+        }
+        if !self.orders.is_empty() || self.order_by_rank {
+            return Err(sqlx::Error::Protocol(
+                "cursor_page orders by the paginate_by columns; remove order_by".to_string(),
+            ));
+        }
+        self.ensure_safe_filters()?;
+
+        // Walking backwards flips the sort; rows are reversed again after fetching.
+        let cursor = self.cursor.take();
+        let backwards = matches!(cursor, Some(CursorPosition::Before(_)));
+        let ascending = self.keyset_desc == backwards;
+        if let Some(CursorPosition::After(token) | CursorPosition::Before(token)) = &cursor {
+            let values = crate::pagination::decode_cursor(token)?;
+            if values.len() != self.keyset.len() {
+                return Err(sqlx::Error::Protocol(format!(
+                    "Pagination cursor has {} values but paginate_by has {} columns",
+                    values.len(),
+                    self.keyset.len()
+                )));
+            }
+            self.filters.push(FilterExpr::Keyset {
+                columns: self.keyset.clone(),
+                op: if ascending {
+                    FilterOp::Gt
+                } else {
+                    FilterOp::Lt
+                },
+                values,
+            });
+        }
+        let direction = if ascending {
+            SortDirection::Asc
+        } else {
+            SortDirection::Desc
+        };
+        self.orders = self
+            .keyset
+            .iter()
+            .map(|column| OrderExpr {
+                column: column.clone(),
+                direction,
+                nulls: None,
+            })
+            .collect();
+        let per_page = per_page.max(1);
+        self.limit = Some(i32::try_from(per_page.saturating_add(1)).unwrap_or(i32::MAX));
+        self.offset = None;
+
+        let mut sql = String::with_capacity(160);
+        let mut where_binds: SmallVec<[BindValue; 8]> =
+            SmallVec::with_capacity(self.estimate_bind_count());
+        self.render_select_into(&mut sql, &mut where_binds);
+
+        #[cfg(debug_assertions)]
+        if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
+            let filters = self.format_filters_for_log();
+            tracing::debug!(
+                operation = "select",
+                sql = %sql,
+                filters = %filters,
+                "premix query"
+            );
+        }
+
+        let start = Instant::now();
+        let mut rows: Vec<DB::Row> = match &mut self.executor {
+            Executor::Pool(pool) => {
+                let base = apply_persistent_query(sqlx::query::<DB>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query);
+                query.fetch_all(*pool).await?
+            }
+            Executor::Conn(conn) => {
+                let base = apply_persistent_query(sqlx::query::<DB>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query);
+                query.fetch_all(&mut **conn).await?
+            }
+        };
+        if !self.fast_path {
+            record_query_metrics("select", T::table_name(), start.elapsed());
+        }
+
+        let has_more = rows.len() > per_page as usize;
+        rows.truncate(per_page as usize);
+        if backwards {
+            rows.reverse();
+        }
+
+        let columns: SmallVec<[&str; 2]> = self.keyset.iter().map(ColumnRef::as_str).collect();
+        let token_for = |row: Option<&DB::Row>| -> Result<Option<String>, sqlx::Error> {
+            row.map(|row| {
+                crate::pagination::read_cursor_values::<DB>(row, &columns)
+                    .map(|values| crate::pagination::encode_cursor(&values))
+            })
+            .transpose()
+        };
+        let (next_cursor, prev_cursor) = if backwards {
+            let prev = if has_more {
+                token_for(rows.first())?
+            } else {
+                None
+            };
+            (token_for(rows.last())?, prev)
+        } else {
+            let next = if has_more {
+                token_for(rows.last())?
+            } else {
+                None
+            };
+            let prev = if cursor.is_some() {
+                token_for(rows.first())?
+            } else {
+                None
+            };
+            (next, prev)
+        };
+
+        let mut items = rows
+            .iter()
+            .map(T::from_row)
+            .collect::<Result<Vec<T>, sqlx::Error>>()?;
+
+        if !self.ultra_fast {
+            for relation in &self.includes {
+                match &mut self.executor {
+                    Executor::Pool(pool) => {
+                        T::eager_load(&mut items, relation, Executor::Pool(*pool)).await?;
+                    }
+                    Executor::Conn(conn) => {
+                        T::eager_load(&mut items, relation, Executor::Conn(&mut **conn)).await?;
+                    }
                 }
             }
         }
-
-        Ok(results)
+
+        Ok(CursorPage {
+            items,
+            next_cursor,
+            prev_cursor,
+        })
+    }
+
+    /// Executes the query and returns the first matching row, if any.
+    ///
+    /// Applies `LIMIT 1` on top of the current filters and ordering; included
+    /// relations are eager-loaded as with [`all`](Self::all).
+    pub async fn first(self) -> Result<Option<T>, sqlx::Error> {
+        let results = self.limit(1).all().await?;
+        Ok(results.into_iter().next())
+    }
+
+    /// Executes the query and returns exactly one row.
+    ///
+    /// # Errors
+    /// Returns [`sqlx::Error::RowNotFound`] when no row matches, and a protocol
+    /// error when more than one row matches.
+    pub async fn one(self) -> Result<T, sqlx::Error> {
+        let mut results = self.limit(2).all().await?;
+        if results.len() > 1 {
+            return Err(sqlx::Error::Protocol(format!(
+                "Expected exactly one row from {} but found more",
+                T::table_name()
+            )));
+        }
+        results.pop().ok_or(sqlx::Error::RowNotFound)
+    }
+
+    /// Counts the rows matching the current filters.
+    ///
+    /// `limit`, `offset`, and ordering are ignored; soft-deleted rows are excluded
+    /// unless [`with_deleted`](Self::with_deleted) is set.
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn count(mut self) -> Result<u64, sqlx::Error>
+    where
+        (i64,): for<'r> sqlx::FromRow<'r, DB::Row>,
+    {
+        self.count_rows().await
+    }
+
+    async fn count_rows(&mut self) -> Result<u64, sqlx::Error>
+    where
+        (i64,): for<'r> sqlx::FromRow<'r, DB::Row>,
+    {
+        self.ensure_safe_filters()?;
+
+        let mut sql = String::with_capacity(128);
+        let mut where_binds: SmallVec<[BindValue; 8]> =
+            SmallVec::with_capacity(self.estimate_bind_count());
+        self.render_count_into(&mut sql, &mut where_binds);
+
+        #[cfg(debug_assertions)]
+        if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
+            let filters = self.format_filters_for_log();
+            tracing::debug!(
+                operation = "count",
+                sql = %sql,
+                filters = %filters,
+                "premix query"
+            );
+        }
+
+        let start = Instant::now();
+        let (count,): (i64,) = match &mut self.executor {
+            Executor::Pool(pool) => {
+                let base =
+                    apply_persistent_query_as(sqlx::query_as::<DB, (i64,)>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_one(*pool).await?
+            }
+            Executor::Conn(conn) => {
+                let base =
+                    apply_persistent_query_as(sqlx::query_as::<DB, (i64,)>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_one(&mut **conn).await?
+            }
+        };
+        if !self.fast_path {
+            record_query_metrics("count", T::table_name(), start.elapsed());
+        }
+        Ok(u64::try_from(count).unwrap_or_default())
+    }
+
+    /// Runs the filtered `COUNT(*)` and then fetches one page of models.
+    ///
+    /// `page` is 1-based; `page` and `per_page` are clamped to at least 1. Filters,
+    /// ordering, soft-delete scoping, and `include()` eager loading apply to the
+    /// page query. Any `limit`/`offset` set on the builder is replaced. Both queries
+    /// use the builder's executor, so build from [`Model::find_in_tx`] to run them
+    /// inside one transaction and get a total consistent with the page:
+    ///
+    /// ```ignore
+    /// let mut tx = pool.begin().await?;
+    /// let page = User::find_in_tx(&mut *tx).paginate(1, 20).await?;
+    /// tx.commit().await?;
+    /// ```
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn paginate(mut self, page: u32, per_page: u32) -> Result<Page<T>, sqlx::Error>
+    where
+        (i64,): for<'r> sqlx::FromRow<'r, DB::Row>,
+    {
+        let page = page.max(1);
+        let per_page = per_page.max(1);
+        let total = self.count_rows().await?;
+
+        let offset = u64::from(page - 1) * u64::from(per_page);
+        self.limit = Some(i32::try_from(per_page).unwrap_or(i32::MAX));
+        self.offset = Some(
+            i32::try_from(offset)
+                .map_err(|_| sqlx::Error::Protocol(format!("Page {} is out of range", page)))?,
+        );
+        let items = if offset < total {
+            self.fetch_models().await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Page {
+            items,
+            total,
+            page,
+            per_page,
+            total_pages: total.div_ceil(u64::from(per_page)),
+        })
+    }
+
+    /// Returns true if at least one row matches the current filters.
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn exists(mut self) -> Result<bool, sqlx::Error>
+    where
+        (bool,): for<'r> sqlx::FromRow<'r, DB::Row>,
+    {
+        self.ensure_safe_filters()?;
+
+        let mut sql = String::with_capacity(128);
+        let mut where_binds: SmallVec<[BindValue; 8]> =
+            SmallVec::with_capacity(self.estimate_bind_count());
+        self.render_exists_into(&mut sql, &mut where_binds);
+
+        #[cfg(debug_assertions)]
+        if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
+            let filters = self.format_filters_for_log();
+            tracing::debug!(
+                operation = "exists",
+                sql = %sql,
+                filters = %filters,
+                "premix query"
+            );
+        }
+
+        let start = Instant::now();
+        let (exists,): (bool,) = match &mut self.executor {
+            Executor::Pool(pool) => {
+                let base =
+                    apply_persistent_query_as(sqlx::query_as::<DB, (bool,)>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_one(*pool).await?
+            }
+            Executor::Conn(conn) => {
+                let base =
+                    apply_persistent_query_as(sqlx::query_as::<DB, (bool,)>(&sql), self.prepared);
+                let query = where_binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_one(&mut **conn).await?
+            }
+        };
+        if !self.fast_path {
+            record_query_metrics("exists", T::table_name(), start.elapsed());
+        }
+        Ok(exists)
     }
 
     /// Executes the query and returns a stream of results.
@@ -916,22 +3791,9 @@ where
         self.ensure_safe_filters()?;
 
         let mut sql = String::with_capacity(128);
-        sql.push_str("SELECT * FROM ");
-        sql.push_str(T::table_name());
-
         let mut where_binds: SmallVec<[BindValue; 8]> =
             SmallVec::with_capacity(self.estimate_bind_count());
-        self.render_where_clause_into(&mut sql, &mut where_binds, 1);
-
-        if let Some(limit) = self.limit {
-            use std::fmt::Write;
-            let _ = write!(sql, " LIMIT {}", limit);
-        }
-
-        if let Some(offset) = self.offset {
-            use std::fmt::Write;
-            let _ = write!(sql, " OFFSET {}", offset);
-        }
+        self.render_select_into(&mut sql, &mut where_binds);
 
         #[cfg(debug_assertions)]
         if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
@@ -977,35 +3839,38 @@ where
         chrono::NaiveDate: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
         sqlx::types::Json<serde_json::Value>: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
     {
-        self.ensure_safe_filters()?;
-        if self.filters.is_empty() && !self.allow_unsafe && !self.unsafe_fast {
-            return Err(sqlx::Error::Protocol(
-                "Refusing bulk update without filters".to_string(),
-            ));
-        }
-        let obj = values.as_object().ok_or_else(|| {
-            sqlx::Error::Protocol("Bulk update requires a JSON object".to_string())
-        })?;
-
-        let mut sql = String::with_capacity(256);
-        use std::fmt::Write;
-        let _ = write!(sql, "UPDATE {} SET ", T::table_name());
+        self.ensure_bulk_allowed("update")?;
+        let set = UpdateSet::from_json(&values)?;
+        self.execute_update(set).await
+    }
 
-        let mut i = 1;
-        let mut first = true;
-        for k in obj.keys() {
-            if !first {
-                sql.push_str(", ");
-            }
-            let p = DB::placeholder(i);
-            let _ = write!(sql, "{} = {}", DB::quote_identifier(k), p);
-            i += 1;
-            first = false;
-        }
+    /// Executes a bulk update built from column assignments, such as atomic
+    /// counters (`views = views + ?`) or the current time.
+    ///
+    /// The same guard as [`update`](Self::update) applies: without filters the
+    /// update is refused unless `allow_unsafe` is used.
+    ///
+    /// ```ignore
+    /// Account::find_in_pool(&pool)
+    ///     .filter_eq("id", from_id)
+    ///     .update_with(|u| u.decrement("balance", 25).set_now("updated_at"))
+    ///     .await?;
+    /// ```
+    #[tracing::instrument(skip(self, build), fields(table = T::table_name()))]
+    pub async fn update_with(
+        mut self,
+        build: impl FnOnce(UpdateSet) -> UpdateSet,
+    ) -> Result<u64, sqlx::Error> {
+        self.ensure_bulk_allowed("update")?;
+        self.execute_update(build(UpdateSet::new())).await
+    }
 
-        let mut where_binds: SmallVec<[BindValue; 8]> =
-            SmallVec::with_capacity(self.estimate_bind_count());
-        self.render_where_clause_into(&mut sql, &mut where_binds, obj.len() + 1);
+    async fn execute_update(&mut self, set: UpdateSet) -> Result<u64, sqlx::Error> {
+        self.ensure_known_set_columns(&set)?;
+        let set = self.touch_updated_at(set);
+        let mut binds: SmallVec<[BindValue; 8]> =
+            SmallVec::with_capacity(set.assignments.len() + self.estimate_bind_count());
+        let sql = self.render_update_sql(&set, &mut binds)?;
 
         if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
             let filters = self.format_filters_for_log();
@@ -1016,27 +3881,8 @@ where
                 "premix query"
             );
         }
-        let mut query = apply_persistent_query(sqlx::query::<DB>(&sql), self.prepared);
-        for val in obj.values() {
-            match val {
-                serde_json::Value::String(s) => query = query.bind(s.clone()),
-                serde_json::Value::Number(n) => {
-                    if let Some(v) = n.as_i64() {
-                        query = query.bind(v);
-                    } else if let Some(v) = n.as_f64() {
-                        query = query.bind(v);
-                    }
-                }
-                serde_json::Value::Bool(b) => query = query.bind(*b),
-                serde_json::Value::Null => query = query.bind(Option::<String>::None),
-                serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
-                    query = query.bind(sqlx::types::Json(val.clone()));
-                }
-            }
-        }
-        for bind in where_binds {
-            query = bind_value_query(query, bind);
-        }
+        let base = apply_persistent_query(sqlx::query::<DB>(&sql), self.prepared);
+        let query = binds.into_iter().fold(base, bind_value_query);
 
         let start = Instant::now();
         let result = match &mut self.executor {
@@ -1075,31 +3921,11 @@ where
     /// Executes a bulk delete based on the current filters.
     #[tracing::instrument(skip(self), fields(table = T::table_name()))]
     pub async fn delete(mut self) -> Result<u64, sqlx::Error> {
-        self.ensure_safe_filters()?;
-        if self.filters.is_empty() && !self.allow_unsafe && !self.unsafe_fast {
-            return Err(sqlx::Error::Protocol(
-                "Refusing bulk delete without filters".to_string(),
-            ));
-        }
-
-        let mut sql = String::with_capacity(128);
-        use std::fmt::Write;
-
-        if T::has_soft_delete() {
-            let _ = write!(
-                sql,
-                "UPDATE {} SET {} = {}",
-                T::table_name(),
-                DB::quote_identifier("deleted_at"),
-                DB::current_timestamp_fn()
-            );
-        } else {
-            let _ = write!(sql, "DELETE FROM {}", T::table_name());
-        }
+        self.ensure_bulk_allowed("delete")?;
 
         let mut where_binds: SmallVec<[BindValue; 8]> =
             SmallVec::with_capacity(self.estimate_bind_count());
-        self.render_where_clause_into(&mut sql, &mut where_binds, 1);
+        let sql = self.render_delete_sql(&mut where_binds);
 
         if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
             let filters = self.format_filters_for_log();
@@ -1135,6 +3961,219 @@ where
     pub async fn delete_all(self) -> Result<u64, sqlx::Error> {
         self.delete().await
     }
+
+    /// Executes a bulk update and returns the updated rows.
+    ///
+    /// Uses `UPDATE ... RETURNING *` on Postgres and SQLite. On MySQL the matching
+    /// primary keys are locked with `SELECT ... FOR UPDATE`, updated, and read back inside
+    /// one transaction (a savepoint when the executor is already in one).
+    /// Relations are not eager-loaded. The guard of [`update`](Self::update) applies.
+    #[tracing::instrument(skip(self, values), fields(table = T::table_name()))]
+    pub async fn update_returning(
+        mut self,
+        values: serde_json::Value,
+    ) -> Result<Vec<T>, sqlx::Error>
+    where
+        i32: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        f64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        bool: for<'r> sqlx::Decode<'r, DB>,
+        uuid::Uuid: for<'r> sqlx::Decode<'r, DB>,
+        chrono::DateTime<chrono::Utc>: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDateTime: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDate: for<'r> sqlx::Decode<'r, DB>,
+    {
+        self.ensure_bulk_allowed("update")?;
+        let set = UpdateSet::from_json(&values)?;
+        self.execute_returning(Some(set)).await
+    }
+
+    /// Executes a bulk delete (or soft delete) and returns the affected rows.
+    ///
+    /// Soft-deleted rows are returned with `deleted_at` set. See
+    /// [`update_returning`](Self::update_returning) for how each database is handled.
+    #[tracing::instrument(skip(self), fields(table = T::table_name()))]
+    pub async fn delete_returning(mut self) -> Result<Vec<T>, sqlx::Error>
+    where
+        i32: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        f64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        bool: for<'r> sqlx::Decode<'r, DB>,
+        uuid::Uuid: for<'r> sqlx::Decode<'r, DB>,
+        chrono::DateTime<chrono::Utc>: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDateTime: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDate: for<'r> sqlx::Decode<'r, DB>,
+    {
+        self.ensure_bulk_allowed("delete")?;
+        self.execute_returning(None).await
+    }
+
+    /// Runs an update (`Some(set)`) or delete (`None`) that yields the affected rows.
+    async fn execute_returning(&mut self, set: Option<UpdateSet>) -> Result<Vec<T>, sqlx::Error>
+    where
+        i32: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        f64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        bool: for<'r> sqlx::Decode<'r, DB>,
+        uuid::Uuid: for<'r> sqlx::Decode<'r, DB>,
+        chrono::DateTime<chrono::Utc>: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDateTime: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDate: for<'r> sqlx::Decode<'r, DB>,
+    {
+        let operation = if set.is_some() {
+            "bulk_update"
+        } else {
+            "bulk_delete"
+        };
+        if let Some(set) = &set {
+            self.ensure_known_set_columns(set)?;
+        }
+        let set = set.map(|set| self.touch_updated_at(set));
+
+        if !DB::supports_update_returning() {
+            // Lock the matching keys first, then modify and read them back by key.
+            self.columns = T::primary_key_columns()
+                .iter()
+                .map(|column| ColumnRef::Static(column))
+                .collect();
+            self.lock = Some((LockMode::Update, LockWait::Wait));
+            let mut binds: SmallVec<[BindValue; 8]> =
+                SmallVec::with_capacity(self.estimate_bind_count());
+            let mut sql = String::with_capacity(128);
+            self.render_select_into(&mut sql, &mut binds);
+            return match &mut self.executor {
+                Executor::Pool(pool) => {
+                    let mut tx = pool.begin().await?;
+                    let rows = Self::modify_locked_ids(&mut tx, &sql, binds, set).await?;
+                    tx.commit().await?;
+                    Ok(rows)
+                }
+                Executor::Conn(conn) => {
+                    let mut tx = sqlx::Connection::begin(&mut **conn).await?;
+                    let rows = Self::modify_locked_ids(&mut tx, &sql, binds, set).await?;
+                    tx.commit().await?;
+                    Ok(rows)
+                }
+            };
+        }
+
+        let mut binds: SmallVec<[BindValue; 8]> =
+            SmallVec::with_capacity(self.estimate_bind_count());
+        let mut sql = match &set {
+            Some(set) => self.render_update_sql(set, &mut binds)?,
+            None => self.render_delete_sql(&mut binds),
+        };
+        sql.push_str(" RETURNING *");
+
+        if !self.fast_path && tracing::enabled!(tracing::Level::DEBUG) {
+            let filters = self.format_filters_for_log();
+            tracing::debug!(
+                operation = operation,
+                sql = %sql,
+                filters = %filters,
+                "premix query"
+            );
+        }
+        let start = Instant::now();
+        let rows = match &mut self.executor {
+            Executor::Pool(pool) => {
+                let base = apply_persistent_query_as(sqlx::query_as::<DB, T>(&sql), self.prepared);
+                let query = binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_all(*pool).await?
+            }
+            Executor::Conn(conn) => {
+                let base = apply_persistent_query_as(sqlx::query_as::<DB, T>(&sql), self.prepared);
+                let query = binds.into_iter().fold(base, bind_value_query_as);
+                query.fetch_all(&mut **conn).await?
+            }
+        };
+        if !self.fast_path {
+            record_query_metrics(operation, T::table_name(), start.elapsed());
+        }
+        Ok(rows)
+    }
+
+    /// Fallback for databases without `RETURNING`: runs the primary-key `SELECT ... FOR UPDATE`
+    /// in `id_sql`, then updates or deletes those rows by key and returns the affected rows.
+    async fn modify_locked_ids(
+        conn: &mut DB::Connection,
+        id_sql: &str,
+        binds: SmallVec<[BindValue; 8]>,
+        set: Option<UpdateSet>,
+    ) -> Result<Vec<T>, sqlx::Error>
+    where
+        i32: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+        i64: for<'r> sqlx::Decode<'r, DB>,
+        f64: for<'r> sqlx::Decode<'r, DB>,
+        String: for<'r> sqlx::Decode<'r, DB>,
+        bool: for<'r> sqlx::Decode<'r, DB>,
+        uuid::Uuid: for<'r> sqlx::Decode<'r, DB>,
+        chrono::DateTime<chrono::Utc>: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDateTime: for<'r> sqlx::Decode<'r, DB>,
+        chrono::NaiveDate: for<'r> sqlx::Decode<'r, DB>,
+    {
+        let query = binds
+            .into_iter()
+            .fold(sqlx::query::<DB>(id_sql), bind_value_query);
+        let key_columns = T::primary_key_columns();
+        let ids = query
+            .fetch_all(&mut *conn)
+            .await?
+            .iter()
+            .map(|row| crate::pagination::read_cursor_values::<DB>(row, key_columns))
+            .collect::<Result<Vec<_>, _>>()?;
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        fn by_ids<T, DB>(
+            conn: &mut DB::Connection,
+            ids: Vec<SmallVec<[BindValue; 2]>>,
+        ) -> QueryBuilder<'_, T, DB>
+        where
+            DB: SqlDialect,
+            T: Model<DB>,
+        {
+            // The caller's set already carries any `updated_at` touch.
+            let query = QueryBuilder::new(Executor::Conn(conn));
+            let mut query = match T::primary_key_columns() {
+                [column] => query.filter_in(*column, ids.into_iter().flatten()),
+                columns => query.any_of(|group| {
+                    ids.into_iter().fold(group, |group, key| {
+                        group.all_of(|all| {
+                            columns
+                                .iter()
+                                .zip(key)
+                                .fold(all, |all, (column, value)| all.eq(*column, value))
+                        })
+                    })
+                }),
+            }
+            .with_deleted()
+            .without_timestamps();
+            query.includes.clear();
+            query
+        }
+        let by_ids = by_ids::<T, DB>;
+        match set {
+            Some(set) => {
+                by_ids(&mut *conn, ids.clone()).execute_update(set).await?;
+                by_ids(conn, ids).all().await
+            }
+            None if T::has_soft_delete() => {
+                by_ids(&mut *conn, ids.clone()).delete().await?;
+                by_ids(conn, ids).all().await
+            }
+            None => {
+                let rows = by_ids(&mut *conn, ids.clone()).all().await?;
+                by_ids(conn, ids).delete().await?;
+                Ok(rows)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1145,15 +4184,23 @@ mod tests {
     struct DummyModel;
 
     impl Model<Sqlite> for DummyModel {
+        type Id = i32;
+
         fn table_name() -> &'static str {
             "users"
         }
+        fn primary_key_value(&self) -> i32 {
+            0
+        }
         fn create_table_sql() -> String {
             String::new()
         }
         fn list_columns() -> Vec<String> {
             vec!["id".to_string()]
         }
+        fn column_names() -> &'static [&'static str] {
+            &["id"]
+        }
         async fn save<'a, E>(&'a mut self, _e: E) -> Result<(), sqlx::Error>
         where
             E: crate::executor::IntoExecutor<'a, DB = Sqlite>,
@@ -1205,7 +4252,7 @@ mod tests {
 
         // The column name should be quoted
         assert!(sql.contains("`id; DROP TABLE users; --` = ?"));
-        assert!(sql.contains("SELECT * FROM users WHERE"));
+        assert!(sql.contains("SELECT * FROM `users` WHERE"));
     }
 
     #[tokio::test]
@@ -1222,6 +4269,632 @@ mod tests {
         assert!(sql.contains("`name; DROP TABLE users; --` = ?"));
     }
 
+    #[tokio::test]
+    async fn test_order_by_renders_quoted_columns() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .filter_eq("id", 1)
+            .order_by_desc("created_at")
+            .nulls_last()
+            .order_by("id")
+            .limit(10)
+            .to_sql();
+
+        assert!(
+            sql.contains("WHERE `id` = ? ORDER BY `created_at` DESC NULLS LAST, `id` ASC LIMIT 10")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_count_and_exists_sql() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let qb = DummyModel::find_in_pool(&pool)
+            .filter_gt("id", 1)
+            .order_by("id")
+            .limit(5);
+
+        assert_eq!(
+            qb.to_count_sql(),
+            "SELECT COUNT(*) FROM `users` WHERE `id` > ?"
+        );
+        assert_eq!(
+            qb.to_exists_sql(),
+            "SELECT EXISTS(SELECT 1 FROM `users` WHERE `id` > ?)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_renders_projection() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .select(&["id"])
+            .filter_eq("id", 1)
+            .to_sql();
+        assert_eq!(sql, "SELECT `id` FROM `users` WHERE `id` = ?");
+    }
+
+    #[tokio::test]
+    async fn test_select_rejects_unknown_column() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let err = DummyModel::find_in_pool(&pool)
+            .select(&["id", "password"])
+            .fetch_as::<(i64,)>()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown column 'password'"));
+    }
+
+    #[tokio::test]
+    async fn test_filter_and_order_columns_are_checked() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let err = DummyModel::find_in_pool(&pool)
+            .filter_eq("ID", 1)
+            .ensure_safe_filters()
+            .unwrap_err();
+        match crate::map_sqlx_error(err) {
+            crate::PremixError::UnknownColumn {
+                table,
+                column,
+                suggestion,
+            } => {
+                assert_eq!(table, "users");
+                assert_eq!(column, "ID");
+                assert_eq!(suggestion.as_deref(), Some("id"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let err = DummyModel::find_in_pool(&pool)
+            .any_of(|f| f.eq("id", 1).is_null("archived_at"))
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .ends_with("Unknown column 'archived_at' on table users")
+        );
+
+        let err = DummyModel::find_in_pool(&pool)
+            .order_by_desc("created")
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown column 'created'"));
+
+        DummyModel::find_in_pool(&pool)
+            .filter_gt("score_total", 10)
+            .order_by("score_total")
+            .allow_unknown_columns()
+            .ensure_safe_filters()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_group_by_having_sql() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .filter_gt("id", 0)
+            .group_by("id")
+            .count_as("total")
+            .avg_as("id", "mean")
+            .having_gte(Aggregate::Count, "*", 2)
+            .order_by_desc("id")
+            .to_sql();
+        assert_eq!(
+            sql,
+            "SELECT `id`, COUNT(*) AS `total`, CAST(AVG(`id`) AS REAL) AS `mean` FROM `users` \
+             WHERE `id` > ? GROUP BY `id` HAVING COUNT(*) >= ? ORDER BY `id` DESC"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_nested_filter_groups_sql() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .filter_gt("id", 0)
+            .any_of(|f| f.eq("id", 1).all_of(|f| f.gte("id", 10).lt("id", 20)))
+            .not(|f| f.in_list("id", [15, 16]))
+            .any_of(|f| f)
+            .to_sql();
+        assert_eq!(
+            sql,
+            "SELECT * FROM `users` WHERE `id` > ? AND (`id` = ? OR (`id` >= ? AND `id` < ?)) \
+             AND NOT (`id` IN (?, ?)) AND 1=0"
+        );
+    }
+
+    #[test]
+    fn test_filter_group_log_masks_sensitive_values() {
+        let group = FilterGroup::default()
+            .eq("email", "a@b.c")
+            .any_of(|f| f.eq("password", "secret").in_list("password", ["x"]));
+        let expr = FilterExpr::Group {
+            kind: GroupKind::Any,
+            filters: group.filters,
+        };
+        let mut rendered = String::new();
+        expr.log_into(&mut rendered, &["password"]);
+        assert_eq!(
+            rendered,
+            "(email = a@b.c OR (password = *** OR password IN (***)))"
+        );
+        assert_eq!(expr.bind_count(), 3);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_nested_filter_groups_postgres_placeholders() {
+        let expr = FilterExpr::Group {
+            kind: GroupKind::Not,
+            filters: FilterGroup::default()
+                .eq("a", 1)
+                .any_of(|f| f.in_list("b", [2, 3]).eq("c", 4))
+                .filters,
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        let mut idx = 2;
+        expr.render_into::<sqlx::Postgres>(&mut sql, &mut binds, &mut idx, None);
+        assert_eq!(sql, r#"NOT ("a" = $2 AND ("b" IN ($3, $4) OR "c" = $5))"#);
+        assert_eq!(idx, 6);
+        assert_eq!(binds.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_subquery_filters_sql() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .filter_gt("id", 1)
+            .filter_in_query(
+                "id",
+                DummyModel::find_in_pool(&pool)
+                    .select(&["id"])
+                    .filter_lt("id", 9),
+            )
+            .filter_not_exists(
+                DummyModel::find_in_pool(&pool)
+                    .correlate("id", "id")
+                    .filter_eq("id", 5),
+            )
+            .to_sql();
+        assert_eq!(
+            sql,
+            "SELECT * FROM `users` WHERE `id` > ? AND `id` IN (SELECT `id` FROM `users` AS `sq` WHERE `id` < ?) \
+             AND NOT EXISTS (SELECT 1 FROM `users` AS `sq` WHERE `sq`.`id` = `users`.`id` AND `id` = ?)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subquery_shape_and_correlation_are_checked() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let err = DummyModel::find_in_pool(&pool)
+            .filter_in_query("id", DummyModel::find_in_pool(&pool))
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(err.to_string().contains("exactly one column"));
+
+        let err = DummyModel::find_in_pool(&pool)
+            .correlate("id", "id")
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(err.to_string().contains("correlate()"));
+
+        let qb = DummyModel::find_in_pool(&pool)
+            .filter_exists(DummyModel::find_in_pool(&pool).filter("1=1"));
+        assert!(qb.ensure_safe_filters().is_err());
+    }
+
+    #[test]
+    fn test_escape_like_escapes_wildcards() {
+        assert_eq!(escape_like("50%_off!"), "50!%!_off!!");
+        assert_eq!(escape_like("plain"), "plain");
+    }
+
+    #[tokio::test]
+    async fn test_range_and_pattern_filters_sql() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .filter_between("id", 1, 9)
+            .filter_not_in("id", [3, 4])
+            .filter_ilike("id", "A%")
+            .filter_contains("id", "5%")
+            .to_sql();
+        assert_eq!(
+            sql,
+            "SELECT * FROM `users` WHERE `id` BETWEEN ? AND ? AND `id` NOT IN (?, ?) \
+             AND LOWER(`id`) LIKE LOWER(?) AND `id` LIKE ? ESCAPE '!'"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_ilike_and_between_postgres() {
+        let expr = FilterExpr::Group {
+            kind: GroupKind::All,
+            filters: FilterGroup::default()
+                .between("age", 18, 65)
+                .ilike("name", "a%")
+                .filters,
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        let mut idx = 1;
+        expr.render_into::<sqlx::Postgres>(&mut sql, &mut binds, &mut idx, None);
+        assert_eq!(sql, r#"("age" BETWEEN $1 AND $2 AND "name" ILIKE $3)"#);
+        assert_eq!(binds.len(), expr.bind_count());
+    }
+
+    #[tokio::test]
+    async fn test_json_filters_bind_path_and_value() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let qb = DummyModel::find_in_pool(&pool)
+            .filter_json_path("id", "$.settings.theme", "dark")
+            .json_contains("id", serde_json::json!("rust"));
+        assert_eq!(
+            qb.to_sql(),
+            format!(
+                "SELECT * FROM `users` WHERE json_extract(`id`, ?) = ? AND {}",
+                Sqlite::render_json_contains("`id`", "?")
+            )
+        );
+        assert_eq!(qb.estimate_bind_count(), 3);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_json_filters_postgres() {
+        assert_eq!(
+            sqlx::Postgres::json_path_arg("$.settings.tags[0]"),
+            r#"{"settings","tags","0"}"#
+        );
+        let expr = FilterExpr::JsonPath {
+            column: "data".into(),
+            path: "$.theme".to_string(),
+            value: BindValue::from("dark"),
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        let mut idx = 1;
+        expr.render_into::<sqlx::Postgres>(&mut sql, &mut binds, &mut idx, None);
+        assert_eq!(sql, r#""data" #>> CAST($1 AS TEXT[]) = CAST($2 AS TEXT)"#);
+        assert!(matches!(&binds[0], BindValue::String(path) if path == r#"{"theme"}"#));
+    }
+
+    #[tokio::test]
+    async fn test_fulltext_match_and_rank_sql() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let qb = DummyModel::find_in_pool(&pool)
+            .filter_match(&["id"], "say \"hi\"")
+            .order_by_rank()
+            .order_by("id");
+        assert_eq!(
+            qb.to_sql(),
+            "SELECT * FROM `users` WHERE `users`.`id` IN (SELECT rowid FROM users_fts WHERE users_fts \
+             MATCH ?) ORDER BY (SELECT bm25(users_fts) FROM users_fts WHERE users_fts MATCH ? \
+             AND users_fts.rowid = `users`.`id`) ASC, `id` ASC"
+        );
+        assert_eq!(
+            <Sqlite as SqlDialect>::fulltext_query_arg(&["title", "body"], "say \"hi\""),
+            r#"{title body} : ("say" """hi""")"#
+        );
+
+        let err = DummyModel::find_in_pool(&pool)
+            .order_by_rank()
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(err.to_string().contains("filter_match"));
+
+        let expr = FilterExpr::FullText {
+            table: "posts",
+            primary_key: "post_id",
+            rowid_key: false,
+            columns: smallvec!["title".into()],
+            query: "rust".to_string(),
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        expr.render_into::<Sqlite>(&mut sql, &mut binds, &mut 1, None);
+        assert_eq!(
+            sql,
+            "`posts`.`post_id` IN (SELECT rowid FROM posts_fts WHERE posts_fts MATCH ?)"
+        );
+        let err = check_fulltext_keys::<Sqlite>(&[expr]).unwrap_err();
+        assert!(err.to_string().contains("integer primary key"));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_fulltext_postgres() {
+        let expr = FilterExpr::FullText {
+            table: "posts",
+            primary_key: "id",
+            rowid_key: true,
+            columns: smallvec!["title".into(), "body".into()],
+            query: "rust orm".to_string(),
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        let mut idx = 1;
+        expr.render_into::<sqlx::Postgres>(&mut sql, &mut binds, &mut idx, None);
+        assert_eq!(
+            sql,
+            r#"to_tsvector('simple', COALESCE("title", '') || ' ' || COALESCE("body", '')) @@ plainto_tsquery('simple', $1)"#
+        );
+        assert!(matches!(&binds[0], BindValue::String(q) if q == "rust orm"));
+    }
+
+    #[tokio::test]
+    async fn test_update_with_sql() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let qb = DummyModel::find_in_pool(&pool).filter_eq("id", 1);
+        let sql = qb
+            .to_update_with_sql(|u| {
+                u.increment("views", 1)
+                    .decrement("stock", 2)
+                    .set_column("previous", "current")
+                    .set_null("note")
+                    .set_now("updated_at")
+                    .set("name", "x")
+            })
+            .unwrap();
+        assert_eq!(
+            sql,
+            "UPDATE `users` SET `views` = `views` + ?, `stock` = `stock` - ?, \
+             `previous` = `current`, `note` = NULL, `updated_at` = ?, \
+             `name` = ? WHERE `id` = ?"
+        );
+        let err = qb.to_update_with_sql(|u| u).unwrap_err();
+        assert!(err.to_string().contains("at least one column"));
+    }
+
+    #[tokio::test]
+    async fn test_typed_column_conditions() {
+        use crate::column::Column;
+
+        const ID: Column<i64> = Column::new("id");
+        const NAME: Column<String> = Column::new("name");
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .filter_by(ID.gt(1))
+            .filter_by(NAME.starts_with("a_"))
+            .any_of(|f| f.filter_by(ID.in_list([2, 3])).filter_by(NAME.is_null()))
+            .order_by(ID)
+            .to_sql();
+        assert_eq!(
+            sql,
+            "SELECT * FROM `users` WHERE `id` > ? AND `name` LIKE ? ESCAPE '!' \
+             AND (`id` IN (?, ?) OR `name` IS NULL) ORDER BY `id` ASC"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_filter_sql_binds_placeholders() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let qb = DummyModel::find_in_pool(&pool)
+            .filter_eq("id", 1)
+            .filter_sql("id > ? OR name = '?'", [2]);
+        assert_eq!(
+            qb.to_sql(),
+            "SELECT * FROM `users` WHERE `id` = ? AND (id > ? OR name = '?')"
+        );
+        assert!(qb.ensure_safe_filters().is_ok());
+
+        let err = DummyModel::find_in_pool(&pool)
+            .filter_sql("id BETWEEN ? AND ?", [1])
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(err.to_string().contains("2 placeholders but 1 bind values"));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_filter_sql_postgres_renumbers() {
+        let expr = FilterExpr::Sql {
+            sql: "age > ? AND lower(name) = lower(?) AND note <> 'why?'".to_string(),
+            values: smallvec![BindValue::from(18), BindValue::from("ann")],
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        let mut idx = 3;
+        expr.render_into::<sqlx::Postgres>(&mut sql, &mut binds, &mut idx, None);
+        assert_eq!(
+            sql,
+            "(age > $3 AND lower(name) = lower($4) AND note <> 'why?')"
+        );
+        assert_eq!(idx, 5);
+        assert_eq!(binds.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_row_lock_requires_transaction() {
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let qb = DummyModel::find_in_pool(&pool)
+            .filter_eq("id", 1)
+            .for_update();
+        // SQLite has no row locks, so nothing is appended.
+        assert_eq!(qb.to_sql(), "SELECT * FROM `users` WHERE `id` = ?");
+        let err = qb.ensure_safe_filters().unwrap_err();
+        assert!(err.to_string().contains("transaction"));
+
+        let mut conn = pool.acquire().await.unwrap();
+        assert!(
+            DummyModel::find_in_tx(&mut conn)
+                .skip_locked()
+                .ensure_safe_filters()
+                .is_ok()
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_row_lock_postgres() {
+        type Pg = sqlx::Postgres;
+        assert_eq!(
+            <Pg as SqlDialect>::render_row_lock(LockMode::Update, LockWait::Wait),
+            "FOR UPDATE"
+        );
+        assert_eq!(
+            <Pg as SqlDialect>::render_row_lock(LockMode::Update, LockWait::SkipLocked),
+            "FOR UPDATE SKIP LOCKED"
+        );
+        assert_eq!(
+            <Pg as SqlDialect>::render_row_lock(LockMode::Share, LockWait::NoWait),
+            "FOR SHARE NOWAIT"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_sql_qualifies_columns() {
+        const POSTS: Relation = Relation::with_join("posts", "posts", "user_id", "id");
+        let pool = sqlx::Pool::<Sqlite>::connect_lazy("sqlite::memory:").unwrap();
+        let sql = DummyModel::find_in_pool(&pool)
+            .join(POSTS)
+            .filter_eq(("posts", "title"), "hello")
+            .filter_gt("id", 1)
+            .order_by("id")
+            .distinct()
+            .to_sql();
+        assert_eq!(
+            sql,
+            "SELECT DISTINCT `users`.* FROM `users` INNER JOIN `posts` ON `posts`.`user_id` = `users`.`id` \
+             WHERE `posts`.`title` = ? AND `users`.`id` > ? ORDER BY `users`.`id` ASC"
+        );
+
+        let sql = DummyModel::find_in_pool(&pool)
+            .left_join(POSTS)
+            .to_count_sql();
+        assert_eq!(
+            sql,
+            "SELECT COUNT(*) FROM (SELECT DISTINCT `users`.`id` FROM `users` \
+             LEFT JOIN `posts` ON `posts`.`user_id` = `users`.`id`) AS premix_count"
+        );
+
+        let sql = DummyModel::find_in_pool(&pool)
+            .join(POSTS)
+            .distinct()
+            .to_count_sql();
+        assert_eq!(
+            sql,
+            "SELECT COUNT(*) FROM (SELECT DISTINCT `users`.* FROM `users` \
+             INNER JOIN `posts` ON `posts`.`user_id` = `users`.`id`) AS premix_count"
+        );
+
+        struct Comment;
+        impl crate::ModelSchema for Comment {
+            const TABLE_NAME: &'static str = "comments";
+            const PRIMARY_KEY: &'static str = "id";
+            const COLUMNS: &'static [&'static str] = &["id", "user_id", "deleted_at"];
+            const SOFT_DELETE: bool = true;
+
+            fn schema() -> crate::schema::SchemaTable {
+                crate::schema::SchemaTable {
+                    name: "comments".to_string(),
+                    columns: Vec::new(),
+                    indexes: Vec::new(),
+                    foreign_keys: Vec::new(),
+                    fulltext: Vec::new(),
+                    primary_key: Vec::new(),
+                    create_sql: None,
+                }
+            }
+        }
+        const COMMENTS: Relation =
+            Relation::with_model_join::<Comment>("comments", "user_id", "id");
+        let sql = DummyModel::find_in_pool(&pool)
+            .left_join(COMMENTS)
+            .to_count_sql();
+        assert_eq!(
+            sql,
+            "SELECT COUNT(*) FROM (SELECT DISTINCT `users`.`id` FROM `users` \
+             LEFT JOIN `comments` ON `comments`.`user_id` = `users`.`id` \
+             AND `comments`.deleted_at IS NULL) AS premix_count"
+        );
+
+        // Qualifiers must name this query's table or a joined one.
+        let err = DummyModel::find_in_pool(&pool)
+            .filter_eq(("posts", "title"), "hello")
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(err.to_string().contains("must be qualified with users"));
+        let err = DummyModel::find_in_pool(&pool)
+            .filter_eq(("users", "title"), "hello")
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unknown column 'title' on table users")
+        );
+
+        // DummyModel declares no relations, so the join is rejected before execution.
+        let err = DummyModel::find_in_pool(&pool)
+            .join(POSTS)
+            .ensure_safe_filters()
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot be joined"));
+        let err = DummyModel::find_in_pool(&pool)
+            .join(POSTS)
+            .to_update_sql(&serde_json::json!({ "id": 1 }))
+            .unwrap_err();
+        assert!(err.to_string().contains("not supported for update"));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_subquery_binds_continue_postgres_numbering() {
+        let sub = Subquery {
+            table: "orders",
+            columns: smallvec!["user_id".into()],
+            filters: vec![FilterExpr::Compare {
+                column: "total".into(),
+                op: FilterOp::Gt,
+                values: smallvec![BindValue::from(100)],
+            }],
+            group_by: SmallVec::new(),
+            having: SmallVec::new(),
+            soft_delete: true,
+            sensitive_fields: &[],
+            known_columns: &[],
+            outer: "users",
+        };
+        let expr = FilterExpr::Subquery {
+            column: Some("id".into()),
+            negated: false,
+            query: Box::new(sub),
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        let mut idx = 2;
+        expr.render_into::<sqlx::Postgres>(&mut sql, &mut binds, &mut idx, None);
+        assert_eq!(
+            sql,
+            r#""id" IN (SELECT "user_id" FROM "orders" WHERE "total" > $2 AND deleted_at IS NULL)"#
+        );
+        assert_eq!(idx, 3);
+    }
+
+    #[test]
+    fn test_keyset_row_value_and_expanded_forms() {
+        let expr = FilterExpr::Keyset {
+            columns: smallvec!["created_at".into(), "id".into()],
+            op: FilterOp::Gt,
+            values: smallvec![BindValue::from("2026-01-01"), BindValue::from(7)],
+        };
+        let mut sql = String::new();
+        let mut binds = SmallVec::new();
+        let mut idx = 1;
+        expr.render_into::<Sqlite>(&mut sql, &mut binds, &mut idx, None);
+        assert_eq!(sql, "(`created_at`, `id`) > (?, ?)");
+        assert_eq!(binds.len(), 2);
+
+        #[cfg(feature = "mysql")]
+        {
+            let mut sql = String::new();
+            let mut binds = SmallVec::new();
+            let mut idx = 1;
+            expr.render_into::<sqlx::MySql>(&mut sql, &mut binds, &mut idx, None);
+            assert_eq!(sql, "(`created_at` > ? OR (`created_at` = ? AND `id` > ?))");
+            assert_eq!(binds.len(), expr.bind_count());
+        }
+    }
+
     #[tokio::test]
     async fn test_stream_api() {
         use sqlx::Connection;
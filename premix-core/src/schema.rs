@@ -59,6 +59,11 @@ pub struct SchemaTable {
     pub indexes: Vec<SchemaIndex>,
     /// The foreign keys in the table.
     pub foreign_keys: Vec<SchemaForeignKey>,
+    /// Columns covered by the table's full-text index (`#[premix(fulltext)]`).
+    pub fulltext: Vec<String>,
+    /// The primary key columns in key order, as introspected. Empty means the
+    /// columns marked `primary_key`, in column order.
+    pub primary_key: Vec<String>,
     /// The original CREATE TABLE SQL (if available).
     pub create_sql: Option<String>,
 }
@@ -69,35 +74,92 @@ impl SchemaTable {
         self.columns.iter().find(|c| c.name == name)
     }
 
-    /// Generates a `CREATE TABLE` SQL statement for this table.
+    /// Returns the primary key columns in key order.
+    pub fn primary_key_columns(&self) -> Vec<&str> {
+        if !self.primary_key.is_empty() {
+            return self.primary_key.iter().map(String::as_str).collect();
+        }
+        self.columns
+            .iter()
+            .filter(|c| c.primary_key)
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    /// Generates a `CREATE TABLE` SQL statement for this table, with names in
+    /// double quotes (valid on SQLite and Postgres).
     pub fn to_create_sql(&self) -> String {
+        self.create_sql_quoted(quote_ansi)
+    }
+
+    /// Generates a `CREATE TABLE` SQL statement with names quoted for `DB`.
+    pub fn to_create_sql_for<DB: crate::dialect::SqlDialect>(&self) -> String {
+        self.create_sql_quoted(DB::quote_identifier)
+    }
+
+    fn create_sql_quoted(&self, quote: fn(&str) -> String) -> String {
         if let Some(sql) = &self.create_sql {
             return sql.clone();
         }
 
+        let key = self.primary_key_columns();
         let mut cols = Vec::new();
         for col in &self.columns {
-            if col.primary_key {
-                cols.push(format!("{} INTEGER PRIMARY KEY", col.name));
+            if col.primary_key && key.len() == 1 {
+                // Integer keys become rowid aliases; other keys keep their own type.
+                let sql_type =
+                    if matches!(normalize_sql_type(&col.sql_type).as_str(), "" | "integer") {
+                        "INTEGER"
+                    } else {
+                        col.sql_type.as_str()
+                    };
+                cols.push(format!("{} {} PRIMARY KEY", quote(&col.name), sql_type));
                 continue;
             }
-            let mut def = format!("{} {}", col.name, col.sql_type);
-            if !col.nullable {
+            let mut def = format!("{} {}", quote(&col.name), col.sql_type);
+            if !col.nullable || col.primary_key {
                 def.push_str(" NOT NULL");
             }
             cols.push(def);
         }
+        if key.len() > 1 {
+            let key: Vec<String> = key.into_iter().map(quote).collect();
+            cols.push(format!("PRIMARY KEY ({})", key.join(", ")));
+        }
 
         format!(
             "CREATE TABLE IF NOT EXISTS {} ({})",
-            self.name,
+            quote(&self.name),
             cols.join(", ")
         )
     }
 }
 
+/// Quotes an identifier with standard SQL double quotes.
+fn quote_ansi(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Quotes each name in `names` and joins them with commas.
+fn quote_list<S: AsRef<str>>(names: &[S], quote: fn(&str) -> String) -> String {
+    names
+        .iter()
+        .map(|name| quote(name.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// A trait for models that can provide their own schema metadata.
 pub trait ModelSchema {
+    /// The table this model maps to, usable in constant expressions.
+    const TABLE_NAME: &'static str;
+    /// The primary key column, usable in constant expressions.
+    const PRIMARY_KEY: &'static str = "id";
+    /// The model's columns, used to check columns qualified with this table in joins.
+    const COLUMNS: &'static [&'static str] = &[];
+    /// Whether the model is soft-deleted (has a `deleted_at` column).
+    const SOFT_DELETE: bool = false;
+
     /// Returns the schema metadata for this model.
     fn schema() -> SchemaTable;
 }
@@ -160,6 +222,17 @@ pub struct ColumnPrimaryKeyDiff {
     pub actual_primary_key: bool,
 }
 
+/// Represents a table whose primary key is made of different columns than expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimaryKeyDiff {
+    /// The table whose key differs.
+    pub table: String,
+    /// The key columns the model expects.
+    pub expected: Vec<String>,
+    /// The key columns actually in the database.
+    pub actual: Vec<String>,
+}
+
 /// Represents the differences between two database schemas.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct SchemaDiff {
@@ -175,8 +248,12 @@ pub struct SchemaDiff {
     pub type_mismatches: Vec<ColumnTypeDiff>,
     /// Columns with different nullability than expected.
     pub nullability_mismatches: Vec<ColumnNullabilityDiff>,
-    /// Columns with different Primary Key status than expected.
+    /// Columns with different Primary Key status than expected. These detail the
+    /// entries in `primary_key_changes` and are not counted again in the summary.
     pub primary_key_mismatches: Vec<ColumnPrimaryKeyDiff>,
+    /// Tables whose whole primary key differs, including composite keys with a
+    /// missing or extra column.
+    pub primary_key_changes: Vec<PrimaryKeyDiff>,
     /// Indexes missing in the actual database.
     pub missing_indexes: Vec<(String, SchemaIndex)>,
     /// Indexes present in the database but not in the models.
@@ -185,6 +262,10 @@ pub struct SchemaDiff {
     pub missing_foreign_keys: Vec<(String, SchemaForeignKey)>,
     /// Foreign keys present in the database but not in the models.
     pub extra_foreign_keys: Vec<(String, SchemaForeignKey)>,
+    /// Full-text indexes (table, columns) missing or out of date in the actual database.
+    pub missing_fulltext: Vec<(String, Vec<String>)>,
+    /// Full-text indexes (table, columns) in the database that no longer match the models.
+    pub extra_fulltext: Vec<(String, Vec<String>)>,
 }
 
 impl SchemaDiff {
@@ -197,10 +278,13 @@ impl SchemaDiff {
             && self.type_mismatches.is_empty()
             && self.nullability_mismatches.is_empty()
             && self.primary_key_mismatches.is_empty()
+            && self.primary_key_changes.is_empty()
             && self.missing_indexes.is_empty()
             && self.extra_indexes.is_empty()
             && self.missing_foreign_keys.is_empty()
             && self.extra_foreign_keys.is_empty()
+            && self.missing_fulltext.is_empty()
+            && self.extra_fulltext.is_empty()
     }
 }
 
@@ -222,8 +306,8 @@ pub fn format_schema_diff_summary(diff: &SchemaDiff) -> String {
         diff.nullability_mismatches.len()
     ));
     lines.push(format!(
-        "  primary key mismatches: {}",
-        diff.primary_key_mismatches.len()
+        "  primary key changes: {}",
+        diff.primary_key_changes.len()
     ));
     lines.push(format!("  missing indexes: {}", diff.missing_indexes.len()));
     lines.push(format!("  extra indexes: {}", diff.extra_indexes.len()));
@@ -235,6 +319,14 @@ pub fn format_schema_diff_summary(diff: &SchemaDiff) -> String {
         "  extra foreign keys: {}",
         diff.extra_foreign_keys.len()
     ));
+    lines.push(format!(
+        "  missing full-text indexes: {}",
+        diff.missing_fulltext.len()
+    ));
+    lines.push(format!(
+        "  extra full-text indexes: {}",
+        diff.extra_fulltext.len()
+    ));
 
     if !diff.missing_tables.is_empty() {
         lines.push(format!(
@@ -260,9 +352,27 @@ pub async fn introspect_sqlite_schema(pool: &SqlitePool) -> Result<Vec<SchemaTab
     )
     .fetch_all(pool)
     .await?;
+    let virtual_tables: BTreeSet<String> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type='table' AND sql LIKE 'CREATE VIRTUAL TABLE%'",
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
 
     let mut tables = Vec::new();
     for name in table_names {
+        // FTS5 tables and their shadow tables belong to the base table's full-text index.
+        let is_fts_table = virtual_tables.iter().any(|vt| {
+            name == *vt
+                || ["data", "idx", "content", "docsize", "config"]
+                    .iter()
+                    .any(|suffix| name == format!("{}_{}", vt, suffix))
+        });
+        if is_fts_table {
+            continue;
+        }
+
         let pragma_sql = format!("PRAGMA table_info({})", name);
         let rows: Vec<(i64, String, String, i64, Option<String>, i64)> =
             sqlx::query_as(&pragma_sql).fetch_all(pool).await?;
@@ -271,6 +381,14 @@ pub async fn introspect_sqlite_schema(pool: &SqlitePool) -> Result<Vec<SchemaTab
             continue;
         }
 
+        let mut key: Vec<(i64, String)> = rows
+            .iter()
+            .filter(|row| row.5 > 0)
+            .map(|row| (row.5, row.1.clone()))
+            .collect();
+        key.sort_unstable();
+        let primary_key = key.into_iter().map(|(_, col)| col).collect();
+
         let columns = rows
             .into_iter()
             .map(|(_cid, col_name, col_type, notnull, _default, pk)| {
@@ -286,12 +404,23 @@ pub async fn introspect_sqlite_schema(pool: &SqlitePool) -> Result<Vec<SchemaTab
 
         let indexes = introspect_sqlite_indexes(pool, &name).await?;
         let foreign_keys = introspect_sqlite_foreign_keys(pool, &name).await?;
+        let fts_name = fulltext_name(&name);
+        let fulltext = if virtual_tables.contains(&fts_name) {
+            let fts_sql = format!("PRAGMA table_info({})", fts_name);
+            let rows: Vec<(i64, String, String, i64, Option<String>, i64)> =
+                sqlx::query_as(&fts_sql).fetch_all(pool).await?;
+            rows.into_iter().map(|(_cid, col, ..)| col).collect()
+        } else {
+            Vec::new()
+        };
 
         tables.push(SchemaTable {
             name,
             columns,
             indexes,
             foreign_keys,
+            fulltext,
+            primary_key,
             create_sql: None,
         });
     }
@@ -311,12 +440,16 @@ pub async fn introspect_postgres_schema(pool: &PgPool) -> Result<Vec<SchemaTable
     let mut tables = Vec::new();
     for name in table_names {
         let pk_cols: Vec<String> = sqlx::query_scalar(
-            "SELECT a.attname FROM pg_index i JOIN pg_attribute a ON a.attrelid=i.indrelid AND a.attnum = ANY(i.indkey) WHERE i.indrelid=$1::regclass AND i.indisprimary",
+            "SELECT a.attname FROM pg_index i
+             JOIN LATERAL unnest(i.indkey) WITH ORDINALITY AS k(attnum, n) ON true
+             JOIN pg_attribute a ON a.attrelid = i.indrelid AND a.attnum = k.attnum
+             WHERE i.indrelid=$1::regclass AND i.indisprimary
+             ORDER BY k.n",
         )
         .bind(&name)
         .fetch_all(pool)
         .await?;
-        let pk_set: BTreeSet<String> = pk_cols.into_iter().collect();
+        let pk_set: BTreeSet<String> = pk_cols.iter().cloned().collect();
 
         let rows: Vec<(String, String, String, String)> = sqlx::query_as(
             "SELECT column_name, data_type, udt_name, is_nullable FROM information_schema.columns WHERE table_schema='public' AND table_name=$1 ORDER BY ordinal_position",
@@ -352,12 +485,24 @@ pub async fn introspect_postgres_schema(pool: &PgPool) -> Result<Vec<SchemaTable
 
         let indexes = introspect_postgres_indexes(pool, &name).await?;
         let foreign_keys = introspect_postgres_foreign_keys(pool, &name).await?;
+        let fulltext_def: Option<String> = sqlx::query_scalar(
+            "SELECT indexdef FROM pg_indexes WHERE schemaname='public' AND tablename=$1 AND indexname=$2",
+        )
+        .bind(&name)
+        .bind(fulltext_name(&name))
+        .fetch_optional(pool)
+        .await?;
+        let fulltext = fulltext_def
+            .map(|def| parse_tsvector_columns(&def))
+            .unwrap_or_default();
 
         tables.push(SchemaTable {
             name,
             columns,
             indexes,
             foreign_keys,
+            fulltext,
+            primary_key: pk_cols,
             create_sql: None,
         });
     }
@@ -391,7 +536,7 @@ pub async fn introspect_mysql_schema(pool: &MySqlPool) -> Result<Vec<SchemaTable
         .bind(&name)
         .fetch_all(pool)
         .await?;
-        let pk_set: BTreeSet<String> = pk_cols.into_iter().collect();
+        let pk_set: BTreeSet<String> = pk_cols.iter().cloned().collect();
 
         let rows: Vec<(String, String, String)> = sqlx::query_as(
             "SELECT column_name, column_type, is_nullable
@@ -422,12 +567,29 @@ pub async fn introspect_mysql_schema(pool: &MySqlPool) -> Result<Vec<SchemaTable
 
         let indexes = introspect_mysql_indexes(pool, &name).await?;
         let foreign_keys = introspect_mysql_foreign_keys(pool, &name).await?;
+        let fulltext_columns: Option<String> = sqlx::query_scalar(
+            "SELECT GROUP_CONCAT(column_name ORDER BY seq_in_index)
+             FROM information_schema.statistics
+             WHERE table_schema = DATABASE() AND table_name = ? AND index_name = ?",
+        )
+        .bind(&name)
+        .bind(fulltext_name(&name))
+        .fetch_one(pool)
+        .await?;
+        let fulltext = fulltext_columns
+            .unwrap_or_default()
+            .split(',')
+            .filter(|col| !col.is_empty())
+            .map(|col| col.to_string())
+            .collect();
 
         tables.push(SchemaTable {
             name,
             columns,
             indexes,
             foreign_keys,
+            fulltext,
+            primary_key: pk_cols,
             create_sql: None,
         });
     }
@@ -552,6 +714,17 @@ pub fn diff_schema(expected: &[SchemaTable], actual: &[SchemaTable]) -> SchemaDi
             }
         }
 
+        // Key order matters for composite keys (it is the index's column order).
+        let expected_key = expected_table.primary_key_columns();
+        let actual_key = actual_table.primary_key_columns();
+        if expected_key != actual_key {
+            diff.primary_key_changes.push(PrimaryKeyDiff {
+                table: (*name).to_string(),
+                expected: expected_key.into_iter().map(str::to_string).collect(),
+                actual: actual_key.into_iter().map(str::to_string).collect(),
+            });
+        }
+
         let expected_indexes = index_map(&expected_table.indexes);
         let actual_indexes = index_map(&actual_table.indexes);
         for key in expected_indexes.keys() {
@@ -589,6 +762,17 @@ pub fn diff_schema(expected: &[SchemaTable], actual: &[SchemaTable]) -> SchemaDi
                 }
             }
         }
+
+        if expected_table.fulltext != actual_table.fulltext {
+            if !actual_table.fulltext.is_empty() {
+                diff.extra_fulltext
+                    .push(((*name).to_string(), actual_table.fulltext.clone()));
+            }
+            if !expected_table.fulltext.is_empty() {
+                diff.missing_fulltext
+                    .push(((*name).to_string(), expected_table.fulltext.clone()));
+            }
+        }
     }
 
     diff.missing_tables.sort();
@@ -599,6 +783,7 @@ pub fn diff_schema(expected: &[SchemaTable], actual: &[SchemaTable]) -> SchemaDi
 
 /// Generates SQLite migration SQL based on the provided schema differences.
 pub fn sqlite_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<String> {
+    let quote = quote_ansi;
     let expected_map: BTreeMap<String, &SchemaTable> =
         expected.iter().map(|t| (t.name.clone(), t)).collect();
     let mut statements = Vec::new();
@@ -609,6 +794,9 @@ pub fn sqlite_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<
             for index in &schema.indexes {
                 statements.push(sqlite_create_index_sql(&schema.name, index));
             }
+            if !schema.fulltext.is_empty() {
+                statements.extend(sqlite_create_fulltext_sql(schema, &schema.fulltext));
+            }
         } else {
             statements.push(format!("-- Missing schema for table {}", table));
         }
@@ -662,7 +850,9 @@ pub fn sqlite_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<
 
             let mut stmt = format!(
                 "ALTER TABLE {} ADD COLUMN {} {}",
-                table, col.name, col.sql_type
+                quote(&table),
+                quote(&col.name),
+                col.sql_type
             );
             if !col.nullable {
                 stmt.push_str(" NOT NULL");
@@ -683,18 +873,20 @@ pub fn sqlite_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<
             mismatch.table, mismatch.column, mismatch.expected_nullable, mismatch.actual_nullable
         ));
     }
-    for mismatch in &diff.primary_key_mismatches {
-        statements.push(format!(
-            "-- TODO: column primary key mismatch {}.{} (expected pk {}, actual pk {})",
-            mismatch.table,
-            mismatch.column,
-            mismatch.expected_primary_key,
-            mismatch.actual_primary_key
-        ));
+    for change in &diff.primary_key_changes {
+        statements.push(primary_key_change_todo(change));
     }
     for (table, index) in &diff.missing_indexes {
         statements.push(sqlite_create_index_sql(table, index));
     }
+    for (table, _) in &diff.extra_fulltext {
+        statements.extend(sqlite_drop_fulltext_sql(table));
+    }
+    for (table, columns) in &diff.missing_fulltext {
+        if let Some(schema) = expected_map.get(table) {
+            statements.extend(sqlite_create_fulltext_sql(schema, columns));
+        }
+    }
     for (table, index) in &diff.extra_indexes {
         statements.push(format!(
             "-- TODO: extra index {}.{} ({})",
@@ -728,6 +920,16 @@ pub fn sqlite_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<
     statements
 }
 
+/// A primary key can only be changed by rebuilding the table, so it is left to the user.
+fn primary_key_change_todo(change: &PrimaryKeyDiff) -> String {
+    format!(
+        "-- TODO: primary key mismatch on {} (expected PRIMARY KEY ({}), actual PRIMARY KEY ({}))",
+        change.table,
+        change.expected.join(", "),
+        change.actual.join(", ")
+    )
+}
+
 fn normalize_sql_type(sql_type: &str) -> String {
     let t = sql_type.trim().to_lowercase();
     if t.is_empty() {
@@ -759,6 +961,7 @@ fn normalize_sql_type(sql_type: &str) -> String {
 #[cfg(feature = "postgres")]
 /// Generates PostgreSQL migration SQL for a given schema difference.
 pub fn postgres_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<String> {
+    let quote = <sqlx::Postgres as crate::dialect::SqlDialect>::quote_identifier;
     let expected_map: BTreeMap<String, &SchemaTable> =
         expected.iter().map(|t| (t.name.clone(), t)).collect();
     let mut statements = Vec::new();
@@ -769,6 +972,9 @@ pub fn postgres_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Ve
             for index in &schema.indexes {
                 statements.push(postgres_create_index_sql(&schema.name, index));
             }
+            if !schema.fulltext.is_empty() {
+                statements.extend(postgres_create_fulltext_sql(schema, &schema.fulltext));
+            }
         } else {
             statements.push(format!("-- Missing schema for table {}", table));
         }
@@ -820,7 +1026,9 @@ pub fn postgres_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Ve
 
             let mut stmt = format!(
                 "ALTER TABLE {} ADD COLUMN {} {}",
-                table, col.name, col.sql_type
+                quote(&table),
+                quote(&col.name),
+                col.sql_type
             );
             if !col.nullable {
                 stmt.push_str(" NOT NULL");
@@ -841,18 +1049,20 @@ pub fn postgres_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Ve
             mismatch.table, mismatch.column, mismatch.expected_nullable, mismatch.actual_nullable
         ));
     }
-    for mismatch in &diff.primary_key_mismatches {
-        statements.push(format!(
-            "-- TODO: column primary key mismatch {}.{} (expected pk {}, actual pk {})",
-            mismatch.table,
-            mismatch.column,
-            mismatch.expected_primary_key,
-            mismatch.actual_primary_key
-        ));
+    for change in &diff.primary_key_changes {
+        statements.push(primary_key_change_todo(change));
     }
     for (table, index) in &diff.missing_indexes {
         statements.push(postgres_create_index_sql(table, index));
     }
+    for (table, _) in &diff.extra_fulltext {
+        statements.extend(postgres_drop_fulltext_sql(table));
+    }
+    for (table, columns) in &diff.missing_fulltext {
+        if let Some(schema) = expected_map.get(table) {
+            statements.extend(postgres_create_fulltext_sql(schema, columns));
+        }
+    }
     for (table, index) in &diff.extra_indexes {
         statements.push(format!(
             "-- TODO: extra index {}.{} ({})",
@@ -890,16 +1100,20 @@ pub fn postgres_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Ve
 #[cfg(feature = "mysql")]
 /// Generates MySQL migration SQL for a given schema difference.
 pub fn mysql_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<String> {
+    let quote = <sqlx::MySql as crate::dialect::SqlDialect>::quote_identifier;
     let expected_map: BTreeMap<String, &SchemaTable> =
         expected.iter().map(|t| (t.name.clone(), t)).collect();
     let mut statements = Vec::new();
 
     for table in &diff.missing_tables {
         if let Some(schema) = expected_map.get(table) {
-            statements.push(schema.to_create_sql());
+            statements.push(schema.to_create_sql_for::<sqlx::MySql>());
             for index in &schema.indexes {
                 statements.push(mysql_create_index_sql(&schema.name, index));
             }
+            if !schema.fulltext.is_empty() {
+                statements.extend(mysql_create_fulltext_sql(schema, &schema.fulltext));
+            }
         } else {
             statements.push(format!("-- Missing schema for table {}", table));
         }
@@ -950,7 +1164,9 @@ pub fn mysql_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<S
 
             let mut stmt = format!(
                 "ALTER TABLE {} ADD COLUMN {} {}",
-                table, col.name, col.sql_type
+                quote(&table),
+                quote(&col.name),
+                col.sql_type
             );
             if !col.nullable {
                 stmt.push_str(" NOT NULL");
@@ -971,18 +1187,20 @@ pub fn mysql_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<S
             mismatch.table, mismatch.column, mismatch.expected_nullable, mismatch.actual_nullable
         ));
     }
-    for mismatch in &diff.primary_key_mismatches {
-        statements.push(format!(
-            "-- TODO: column primary key mismatch {}.{} (expected pk {}, actual pk {})",
-            mismatch.table,
-            mismatch.column,
-            mismatch.expected_primary_key,
-            mismatch.actual_primary_key
-        ));
+    for change in &diff.primary_key_changes {
+        statements.push(primary_key_change_todo(change));
     }
     for (table, index) in &diff.missing_indexes {
         statements.push(mysql_create_index_sql(table, index));
     }
+    for (table, _) in &diff.extra_fulltext {
+        statements.extend(mysql_drop_fulltext_sql(table));
+    }
+    for (table, columns) in &diff.missing_fulltext {
+        if let Some(schema) = expected_map.get(table) {
+            statements.extend(mysql_create_fulltext_sql(schema, columns));
+        }
+    }
     for (table, index) in &diff.extra_indexes {
         statements.push(format!(
             "-- TODO: extra index {}.{} ({})",
@@ -1017,6 +1235,120 @@ pub fn mysql_migration_sql(expected: &[SchemaTable], diff: &SchemaDiff) -> Vec<S
     statements
 }
 
+/// Name of a table's full-text index: the FTS5 table on SQLite, the index on
+/// Postgres and MySQL.
+pub fn fulltext_name(table: &str) -> String {
+    format!("{}_fts", table)
+}
+
+/// Builds an external-content FTS5 table plus the triggers that keep it in sync,
+/// then indexes the rows already in the table.
+fn sqlite_create_fulltext_sql(table: &SchemaTable, columns: &[String]) -> Vec<String> {
+    let fts = fulltext_name(&table.name);
+    let pk = table
+        .columns
+        .iter()
+        .find(|c| c.primary_key)
+        .map_or("id", |c| c.name.as_str());
+    let cols = quote_list(columns, quote_ansi);
+    let quoted_pk = quote_ansi(pk);
+    let new_values: Vec<String> = columns
+        .iter()
+        .map(|c| format!("new.{}", quote_ansi(c)))
+        .collect();
+    let old_values: Vec<String> = columns
+        .iter()
+        .map(|c| format!("old.{}", quote_ansi(c)))
+        .collect();
+    let insert_new = format!(
+        "INSERT INTO {fts}(rowid, {cols}) VALUES (new.{quoted_pk}, {});",
+        new_values.join(", ")
+    );
+    let delete_old = format!(
+        "INSERT INTO {fts}({fts}, rowid, {cols}) VALUES ('delete', old.{quoted_pk}, {});",
+        old_values.join(", ")
+    );
+    let content = table.name.replace('\'', "''");
+    let content_rowid = pk.replace('\'', "''");
+    let table = quote_ansi(&table.name);
+    vec![
+        format!(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS {fts} USING fts5({cols}, content='{content}', content_rowid='{content_rowid}')"
+        ),
+        format!(
+            "CREATE TRIGGER IF NOT EXISTS {fts}_ai AFTER INSERT ON {table} BEGIN {insert_new} END"
+        ),
+        format!(
+            "CREATE TRIGGER IF NOT EXISTS {fts}_ad AFTER DELETE ON {table} BEGIN {delete_old} END"
+        ),
+        format!(
+            "CREATE TRIGGER IF NOT EXISTS {fts}_au AFTER UPDATE ON {table} BEGIN {delete_old} {insert_new} END"
+        ),
+        format!("INSERT INTO {fts}({fts}) VALUES ('rebuild')"),
+    ]
+}
+
+fn sqlite_drop_fulltext_sql(table: &str) -> Vec<String> {
+    let fts = fulltext_name(table);
+    vec![
+        format!("DROP TRIGGER IF EXISTS {}_ai", fts),
+        format!("DROP TRIGGER IF EXISTS {}_ad", fts),
+        format!("DROP TRIGGER IF EXISTS {}_au", fts),
+        format!("DROP TABLE IF EXISTS {}", fts),
+    ]
+}
+
+#[cfg(feature = "postgres")]
+fn postgres_create_fulltext_sql(table: &SchemaTable, columns: &[String]) -> Vec<String> {
+    let quote = <sqlx::Postgres as crate::dialect::SqlDialect>::quote_identifier;
+    vec![format!(
+        "CREATE INDEX IF NOT EXISTS {} ON {} USING GIN ({})",
+        quote(&fulltext_name(&table.name)),
+        quote(&table.name),
+        crate::dialect::postgres_tsvector(&columns.iter().map(|c| quote(c)).collect::<Vec<_>>())
+    )]
+}
+
+#[cfg(feature = "postgres")]
+fn postgres_drop_fulltext_sql(table: &str) -> Vec<String> {
+    vec![format!(
+        "DROP INDEX IF EXISTS {}",
+        <sqlx::Postgres as crate::dialect::SqlDialect>::quote_identifier(&fulltext_name(table))
+    )]
+}
+
+/// Extracts the column names from a `to_tsvector(... COALESCE(col, '') ...)` index definition.
+#[cfg(feature = "postgres")]
+fn parse_tsvector_columns(index_def: &str) -> Vec<String> {
+    index_def
+        .split("COALESCE(")
+        .skip(1)
+        .filter_map(|rest| rest.split(',').next())
+        .map(|col| col.trim().trim_matches('"').to_string())
+        .collect()
+}
+
+#[cfg(feature = "mysql")]
+fn mysql_create_fulltext_sql(table: &SchemaTable, columns: &[String]) -> Vec<String> {
+    let quote = <sqlx::MySql as crate::dialect::SqlDialect>::quote_identifier;
+    vec![format!(
+        "CREATE FULLTEXT INDEX {} ON {} ({})",
+        quote(&fulltext_name(&table.name)),
+        quote(&table.name),
+        quote_list(columns, quote)
+    )]
+}
+
+#[cfg(feature = "mysql")]
+fn mysql_drop_fulltext_sql(table: &str) -> Vec<String> {
+    let quote = <sqlx::MySql as crate::dialect::SqlDialect>::quote_identifier;
+    vec![format!(
+        "DROP INDEX {} ON {}",
+        quote(&fulltext_name(table)),
+        quote(table)
+    )]
+}
+
 fn index_key(index: &SchemaIndex) -> (String, String, bool) {
     let name = index.name.clone();
     let cols = index.columns.join(",");
@@ -1088,6 +1420,7 @@ async fn introspect_sqlite_foreign_keys(
 }
 
 fn sqlite_create_index_sql(table: &str, index: &SchemaIndex) -> String {
+    let quote = quote_ansi;
     let unique = if index.unique { "UNIQUE " } else { "" };
     let name = if index.name.is_empty() {
         format!("idx_{}_{}", table, index.columns.join("_"))
@@ -1097,14 +1430,15 @@ fn sqlite_create_index_sql(table: &str, index: &SchemaIndex) -> String {
     format!(
         "CREATE {}INDEX IF NOT EXISTS {} ON {} ({})",
         unique,
-        name,
-        table,
-        index.columns.join(", ")
+        quote(&name),
+        quote(table),
+        quote_list(&index.columns, quote)
     )
 }
 
 #[cfg(feature = "postgres")]
 fn postgres_create_index_sql(table: &str, index: &SchemaIndex) -> String {
+    let quote = <sqlx::Postgres as crate::dialect::SqlDialect>::quote_identifier;
     let unique = if index.unique { "UNIQUE " } else { "" };
     let name = if index.name.is_empty() {
         format!("idx_{}_{}", table, index.columns.join("_"))
@@ -1114,14 +1448,15 @@ fn postgres_create_index_sql(table: &str, index: &SchemaIndex) -> String {
     format!(
         "CREATE {}INDEX IF NOT EXISTS {} ON {} ({})",
         unique,
-        name,
-        table,
-        index.columns.join(", ")
+        quote(&name),
+        quote(table),
+        quote_list(&index.columns, quote)
     )
 }
 
 #[cfg(feature = "mysql")]
 fn mysql_create_index_sql(table: &str, index: &SchemaIndex) -> String {
+    let quote = <sqlx::MySql as crate::dialect::SqlDialect>::quote_identifier;
     let unique = if index.unique { "UNIQUE " } else { "" };
     let name = if index.name.is_empty() {
         format!("idx_{}_{}", table, index.columns.join("_"))
@@ -1131,9 +1466,9 @@ fn mysql_create_index_sql(table: &str, index: &SchemaIndex) -> String {
     format!(
         "CREATE {}INDEX {} ON {} ({})",
         unique,
-        name,
-        table,
-        index.columns.join(", ")
+        quote(&name),
+        quote(table),
+        quote_list(&index.columns, quote)
     )
 }
 
@@ -1210,6 +1545,7 @@ async fn introspect_mysql_indexes(
         "SELECT index_name, non_unique, GROUP_CONCAT(column_name ORDER BY seq_in_index) AS columns
          FROM information_schema.statistics
          WHERE table_schema = DATABASE() AND table_name = ? AND index_name != 'PRIMARY'
+           AND index_type != 'FULLTEXT'
          GROUP BY index_name, non_unique
          ORDER BY index_name",
     )
@@ -1306,6 +1642,8 @@ mod tests {
             ],
             indexes: Vec::new(),
             foreign_keys: Vec::new(),
+            fulltext: Vec::new(),
+            primary_key: Vec::new(),
             create_sql: None,
         }];
 
@@ -1347,6 +1685,8 @@ mod tests {
             ],
             indexes: Vec::new(),
             foreign_keys: Vec::new(),
+            fulltext: Vec::new(),
+            primary_key: Vec::new(),
             create_sql: None,
         }];
 
@@ -1360,8 +1700,91 @@ mod tests {
         let sql = sqlite_migration_sql(&expected, &diff);
         assert!(
             sql.iter()
-                .any(|stmt| stmt.contains("ALTER TABLE users ADD COLUMN status"))
+                .any(|stmt| stmt.contains(r#"ALTER TABLE "users" ADD COLUMN "status""#))
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn sqlite_composite_primary_key_round_trips() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let key_column = |name: &str| SchemaColumn {
+            name: name.to_string(),
+            sql_type: "INTEGER".to_string(),
+            nullable: false,
+            primary_key: true,
+        };
+        let expected = vec![SchemaTable {
+            name: "memberships".to_string(),
+            columns: vec![
+                key_column("user_id"),
+                key_column("group_id"),
+                SchemaColumn {
+                    name: "role".to_string(),
+                    sql_type: "TEXT".to_string(),
+                    nullable: true,
+                    primary_key: false,
+                },
+            ],
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+            fulltext: Vec::new(),
+            primary_key: Vec::new(),
+            create_sql: None,
+        }];
+        assert_eq!(
+            expected[0].to_create_sql(),
+            r#"CREATE TABLE IF NOT EXISTS "memberships" ("user_id" INTEGER NOT NULL, "group_id" INTEGER NOT NULL, "role" TEXT, PRIMARY KEY ("user_id", "group_id"))"#
+        );
+
+        sqlx::query(&expected[0].to_create_sql())
+            .execute(&pool)
+            .await
+            .unwrap();
+        let actual = introspect_sqlite_schema(&pool).await.unwrap();
+        assert!(diff_schema(&expected, &actual).is_empty());
+
+        sqlx::query("DROP TABLE memberships")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE memberships (user_id INTEGER NOT NULL, group_id INTEGER NOT NULL, \
+             role TEXT, PRIMARY KEY (group_id, user_id))",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let actual = introspect_sqlite_schema(&pool).await.unwrap();
+        assert_eq!(actual[0].primary_key_columns(), ["group_id", "user_id"]);
+        let diff = diff_schema(&expected, &actual);
+        assert_eq!(diff.primary_key_changes.len(), 1);
+        assert_eq!(diff.primary_key_changes[0].actual, ["group_id", "user_id"]);
+
+        sqlx::query("DROP TABLE memberships")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE memberships (user_id INTEGER PRIMARY KEY, role TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let actual = introspect_sqlite_schema(&pool).await.unwrap();
+        let diff = diff_schema(&expected, &actual);
+        assert!(diff.primary_key_mismatches.is_empty());
+        assert!(format_schema_diff_summary(&diff).contains("primary key changes: 1"));
+        assert_eq!(
+            diff.primary_key_changes,
+            vec![PrimaryKeyDiff {
+                table: "memberships".to_string(),
+                expected: vec!["user_id".to_string(), "group_id".to_string()],
+                actual: vec!["user_id".to_string()],
+            }]
         );
+        let sql = sqlite_migration_sql(&expected, &diff);
+        assert!(sql.iter().any(|stmt| stmt.contains(
+            "primary key mismatch on memberships (expected PRIMARY KEY (user_id, group_id), actual PRIMARY KEY (user_id))"
+        )));
     }
 
     #[cfg(feature = "sqlite")]
@@ -1395,6 +1818,8 @@ mod tests {
                 unique: false,
             }],
             foreign_keys: Vec::new(),
+            fulltext: Vec::new(),
+            primary_key: Vec::new(),
             create_sql: None,
         }];
 
@@ -1403,10 +1828,87 @@ mod tests {
         assert_eq!(diff.missing_indexes.len(), 1);
 
         let sql = sqlite_migration_sql(&expected, &diff);
-        assert!(
-            sql.iter()
-                .any(|stmt| stmt.contains("CREATE INDEX IF NOT EXISTS idx_users_name"))
+        assert!(sql.iter().any(|stmt| {
+            stmt.contains(r#"CREATE INDEX IF NOT EXISTS "idx_users_name" ON "users" ("name")"#)
+        }));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn sqlite_fulltext_migration_round_trips() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE posts (id INTEGER PRIMARY KEY, title TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO posts (title) VALUES ('existing row')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let expected = vec![SchemaTable {
+            name: "posts".to_string(),
+            columns: vec![
+                SchemaColumn {
+                    name: "id".to_string(),
+                    sql_type: "INTEGER".to_string(),
+                    nullable: false,
+                    primary_key: true,
+                },
+                SchemaColumn {
+                    name: "title".to_string(),
+                    sql_type: "TEXT".to_string(),
+                    nullable: false,
+                    primary_key: false,
+                },
+            ],
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+            fulltext: vec!["title".to_string()],
+            primary_key: Vec::new(),
+            create_sql: None,
+        }];
+
+        let actual = introspect_sqlite_schema(&pool).await.unwrap();
+        let diff = diff_schema(&expected, &actual);
+        assert_eq!(diff.missing_fulltext.len(), 1);
+        for stmt in sqlite_migration_sql(&expected, &diff) {
+            sqlx::raw_sql(&stmt).execute(&pool).await.unwrap();
+        }
+
+        // The FTS5 shadow tables are not reported as extra tables.
+        let actual = introspect_sqlite_schema(&pool).await.unwrap();
+        assert!(diff_schema(&expected, &actual).is_empty());
+
+        let hits: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM posts_fts WHERE posts_fts MATCH 'existing'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(hits, 1);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_fulltext_index_matches_query_expression() {
+        let table = SchemaTable {
+            name: "posts".to_string(),
+            columns: Vec::new(),
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+            fulltext: vec!["title".to_string(), "body".to_string()],
+            primary_key: Vec::new(),
+            create_sql: None,
+        };
+        let sql = postgres_create_fulltext_sql(&table, &table.fulltext);
+        assert_eq!(
+            sql[0],
+            "CREATE INDEX IF NOT EXISTS posts_fts ON posts USING GIN (to_tsvector('simple', \
+             COALESCE(title, '') || ' ' || COALESCE(body, '')))"
         );
+        let def = "CREATE INDEX posts_fts ON public.posts USING gin (to_tsvector('simple'::regconfig, \
+                   ((COALESCE(title, ''::text) || ' '::text) || COALESCE(body, ''::text))))";
+        assert_eq!(parse_tsvector_columns(def), ["title", "body"]);
     }
 
     #[cfg(feature = "postgres")]
@@ -1482,6 +1984,8 @@ mod tests {
                     ref_table: "schema_users".to_string(),
                     ref_column: "id".to_string(),
                 }],
+                fulltext: Vec::new(),
+                primary_key: Vec::new(),
                 create_sql: None,
             },
             SchemaTable {
@@ -1502,6 +2006,8 @@ mod tests {
                 ],
                 indexes: Vec::new(),
                 foreign_keys: Vec::new(),
+                fulltext: Vec::new(),
+                primary_key: Vec::new(),
                 create_sql: None,
             },
         ];
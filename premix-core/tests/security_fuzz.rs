@@ -17,10 +17,16 @@ impl ModelHooks for DummyModel {}
 impl ModelValidation for DummyModel {}
 
 impl Model<Sqlite> for DummyModel {
+    type Id = i32;
+
     fn table_name() -> &'static str {
         "dummy_models"
     }
 
+    fn primary_key_value(&self) -> i32 {
+        self.id
+    }
+
     fn create_table_sql() -> String {
         "CREATE TABLE dummy_models (id INTEGER PRIMARY KEY, name TEXT)".to_string()
     }
@@ -1,10 +1,8 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{
-    Attribute, Data, DeriveInput, Field, Fields, Ident, LitStr, Token, parse_macro_input,
-    punctuated::Punctuated,
-};
+use syn::{Attribute, Data, DeriveInput, Field, Fields, Ident, LitStr, Token, parse_macro_input};
 
+mod naming;
 mod relations;
 mod static_query;
 
@@ -83,15 +81,146 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
 fn derive_model_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let impl_block = generate_generic_impl(input)?;
     let rel_block = relations::impl_relations(input)?;
+    let scope_block = generate_scopes(input)?;
     Ok(quote! {
         #impl_block
         #rel_block
+        #scope_block
     })
 }
 
+/// Generates the `<Model>Scopes` extension trait for `#[premix(scope(name = "sql"))]`.
+///
+/// `QueryBuilder` lives in premix-core, so the scope methods are added through a
+/// trait implemented for `QueryBuilder<Model, DB>`; it is in scope wherever the
+/// model is, and must be imported alongside it elsewhere.
+fn generate_scopes(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut scopes: Vec<(Ident, LitStr)> = Vec::new();
+    for attr in &input.attrs {
+        if !attr.path().is_ident("premix") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("scope") {
+                meta.parse_nested_meta(|nested| {
+                    let name = nested
+                        .path
+                        .get_ident()
+                        .cloned()
+                        .ok_or_else(|| nested.error("scope name must be an identifier"))?;
+                    let sql: LitStr = nested.value()?.parse()?;
+                    if sql.value().trim().is_empty() {
+                        return Err(syn::Error::new_spanned(&sql, "scope SQL must not be empty"));
+                    }
+                    if premix_core::__private::has_sql_placeholder(&sql.value()) {
+                        return Err(syn::Error::new_spanned(
+                            &sql,
+                            "scope SQL cannot take bind placeholders; use filter_sql with values instead",
+                        ));
+                    }
+                    if scopes.iter().any(|(existing, _)| *existing == name) {
+                        return Err(syn::Error::new_spanned(&name, "duplicate scope name"));
+                    }
+                    scopes.push((name, sql));
+                    Ok(())
+                })
+            } else {
+                skip_premix_meta(&meta)
+            }
+        })?;
+    }
+    if scopes.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let struct_name = &input.ident;
+    let vis = &input.vis;
+    let trait_name = Ident::new(&format!("{}Scopes", struct_name), struct_name.span());
+    let trait_doc = format!(
+        "Named query scopes declared with `#[premix(scope(...))]` on [`{}`].",
+        struct_name
+    );
+    let names: Vec<_> = scopes.iter().map(|(name, _)| name).collect();
+    let sqls: Vec<_> = scopes.iter().map(|(_, sql)| sql).collect();
+    let method_docs: Vec<_> = scopes
+        .iter()
+        .map(|(_, sql)| format!("Filters by `{}`.", sql.value()))
+        .collect();
+
+    Ok(quote! {
+        #[doc = #trait_doc]
+        #vis trait #trait_name: Sized {
+            #(
+                #[doc = #method_docs]
+                fn #names(self) -> Self;
+            )*
+        }
+
+        impl<'a, DB> #trait_name for premix_orm::QueryBuilder<'a, #struct_name, DB>
+        where
+            DB: premix_orm::SqlDialect,
+            #struct_name: premix_orm::Model<DB>,
+        {
+            #(
+                fn #names(self) -> Self {
+                    self.filter_sql(#sqls, ::std::iter::empty::<premix_orm::BindValue>())
+                }
+            )*
+        }
+    })
+}
+
+/// The model's table: `#[premix(table = "...")]` when given, otherwise the
+/// crate's naming strategy applied to the struct name.
+fn model_table_name(input: &DeriveInput) -> syn::Result<String> {
+    let mut table: Option<LitStr> = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("premix") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table") {
+                let value: LitStr = meta.value()?.parse()?;
+                let name = value.value();
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+                {
+                    return Err(syn::Error::new_spanned(
+                        &value,
+                        "table name may only contain letters, digits, '_' and '.'",
+                    ));
+                }
+                table = Some(value);
+                Ok(())
+            } else {
+                skip_premix_meta(&meta)
+            }
+        })?;
+    }
+    match table {
+        Some(table) => Ok(table.value()),
+        None => Ok(naming::for_current_crate()?.table_name(&input.ident.to_string())),
+    }
+}
+
+/// Consumes a `#[premix(...)]` entry handled elsewhere (`flag`,
+/// `key = value`, or `name(...)`).
+fn skip_premix_meta(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        content.parse::<proc_macro2::TokenStream>()?;
+    } else if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    }
+    Ok(())
+}
+
 fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let struct_name = &input.ident;
-    let table_name = struct_name.to_string().to_lowercase() + "s";
+    let table_name = model_table_name(input)?;
     let custom_hooks = has_premix_flag(&input.attrs, "custom_hooks");
     let custom_validation = has_premix_flag(&input.attrs, "custom_validation");
 
@@ -128,25 +257,73 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
         .collect();
     let field_types: Vec<_> = db_fields.iter().map(|f| &f.ty).collect();
     let _field_indices: Vec<_> = (0..db_fields.len()).collect();
-    let field_names: Vec<_> = field_idents.iter().map(|id| id.to_string()).collect();
+    // SQL column names: the field name unless `#[premix(column = "...")]` renames it.
+    let field_names: Vec<_> = db_fields
+        .iter()
+        .map(|f| field_column_name(f))
+        .collect::<syn::Result<_>>()?;
+    for (i, name) in field_names.iter().enumerate() {
+        if field_names[..i].contains(name) {
+            return Err(syn::Error::new_spanned(
+                db_fields[i],
+                format!("duplicate column name '{}'", name),
+            ));
+        }
+    }
+    let mut pk_indices = Vec::new();
+    for pk_field in primary_key_fields(input)? {
+        let index = db_fields
+            .iter()
+            .position(|f| ::std::ptr::eq(*f, pk_field))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(pk_field, "a primary key field cannot be ignored")
+            })?;
+        pk_indices.push(index);
+    }
+    let pk_index = pk_indices[0];
+    let pk_ident = field_idents[pk_index];
+    let pk_ty = field_types[pk_index];
+    let pk_column = &field_names[pk_index];
+    let pk_count = pk_indices.len();
+    let pk_idents: Vec<_> = pk_indices.iter().map(|&i| field_idents[i]).collect();
+    let pk_tys: Vec<_> = pk_indices.iter().map(|&i| field_types[i]).collect();
+    let pk_columns: Vec<_> = pk_indices.iter().map(|&i| &field_names[i]).collect();
+    // A composite key is the tuple of its fields, in declaration order.
+    let (id_ty, pk_value) = if pk_count == 1 {
+        (
+            quote! { #pk_ty },
+            quote! { ::std::clone::Clone::clone(&self.#pk_ident) },
+        )
+    } else {
+        (
+            quote! { ( #( #pk_tys ),* ) },
+            quote! { ( #( ::std::clone::Clone::clone(&self.#pk_idents) ),* ) },
+        )
+    };
+    let pk_unset = if pk_count == 1 {
+        quote! { premix_orm::PrimaryKey::is_unset(&self.#pk_ident) }
+    } else {
+        quote! { premix_orm::PrimaryKey::is_unset(&#pk_value) }
+    };
+    let id_binds = if pk_count == 1 {
+        quote! { .bind(id) }
+    } else {
+        let positions = (0..pk_count).map(syn::Index::from);
+        quote! { #( .bind(id.#positions) )* }
+    };
+    let field_is_pk: Vec<bool> = (0..db_fields.len())
+        .map(|i| pk_indices.contains(&i))
+        .collect();
     let field_names_no_id: Vec<_> = field_names
         .iter()
-        .filter(|name| *name != "id")
-        .cloned()
+        .enumerate()
+        .filter(|(i, _)| !pk_indices.contains(i))
+        .map(|(_, name)| name.clone())
         .collect();
     let field_names_no_id_len = field_names_no_id.len();
-    // all_columns_joined and no_id_columns_joined removed as part of Zero-Overhead optimization (replaced by concat!)
-
-    // Prepare head/tail for concat! (to avoid trailing commas and handle separators)
-    let all_cols_head = field_names.first().cloned().unwrap_or_default();
-    let all_cols_tail: Vec<_> = field_names.iter().skip(1).cloned().collect();
-
-    let no_id_cols_head = field_names_no_id.first().cloned().unwrap_or_default();
-    let no_id_cols_tail: Vec<_> = field_names_no_id.iter().skip(1).cloned().collect();
-
     let field_idents_len = field_idents.len();
     let field_nullables: Vec<_> = db_fields.iter().map(|f| is_option_type(&f.ty)).collect();
-    let field_primary_keys: Vec<_> = field_names.iter().map(|n| n == "id").collect();
+    let field_primary_keys = field_is_pk.clone();
     let field_sql_types: Vec<_> = db_fields
         .iter()
         .map(|field| {
@@ -163,13 +340,16 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
         .collect();
     let sensitive_field_literals: Vec<LitStr> = db_fields
         .iter()
-        .filter(|f| is_sensitive(f))
-        .map(|f| {
-            LitStr::new(
-                &f.ident.as_ref().unwrap().to_string(),
-                f.ident.as_ref().unwrap().span(),
-            )
-        })
+        .zip(field_names.iter())
+        .filter(|(f, _)| is_sensitive(f))
+        .map(|(f, name)| LitStr::new(name, f.ident.as_ref().unwrap().span()))
+        .collect();
+
+    let fulltext_field_literals: Vec<LitStr> = db_fields
+        .iter()
+        .zip(field_names.iter())
+        .filter(|(f, _)| is_fulltext(f))
+        .map(|(f, name)| LitStr::new(name, f.ident.as_ref().unwrap().span()))
         .collect();
 
     let relation_meta = relations::collect_relation_metadata(input)?;
@@ -214,12 +394,101 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             }
         })
         .collect();
+    // Integer keys auto-increment; other key types are supplied by the caller.
+    let pk_column_def = match sql_type_for_field(&pk_ident.to_string(), pk_ty) {
+        "INTEGER" => quote! { <DB as premix_orm::SqlDialect>::auto_increment_pk().to_string() },
+        "BIGINT" => {
+            quote! { <DB as premix_orm::SqlDialect>::auto_increment_bigint_pk().to_string() }
+        }
+        _ => {
+            let sql_type = &field_sql_type_exprs[pk_index];
+            quote! { format!("{} PRIMARY KEY", #sql_type) }
+        }
+    };
+    let create_table_body = if pk_count == 1 {
+        quote! {
+            let mut cols = vec![format!(
+                "{} {}",
+                <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column),
+                #pk_column_def
+            )];
+            #(
+                if !#field_is_pk {
+                    let sql_type = #field_sql_type_exprs;
+                    cols.push(format!(
+                        "{} {}",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(#field_names),
+                        sql_type
+                    ));
+                }
+            )*
+        }
+    } else {
+        quote! {
+            let mut cols = Vec::new();
+            #(
+                let sql_type = #field_sql_type_exprs;
+                let column = <DB as premix_orm::SqlDialect>::quote_identifier(#field_names);
+                if #field_is_pk {
+                    cols.push(format!("{} {} NOT NULL", column, sql_type));
+                } else {
+                    cols.push(format!("{} {}", column, sql_type));
+                }
+            )*
+            cols.push(format!(
+                "PRIMARY KEY ({})",
+                premix_orm::build_column_list::<DB>(&[#(#pk_columns),*])
+            ));
+        }
+    };
     let has_version = field_names.contains(&"version".to_string());
     let has_soft_delete = field_names.contains(&"deleted_at".to_string());
 
+    // Managed timestamps: inserts set both, updates bump `updated_at`.
+    let created_at = timestamp_field(&db_fields, "created_at")?;
+    let updated_at = timestamp_field(&db_fields, "updated_at")?;
+    let stamp = |field: &Option<(usize, proc_macro2::TokenStream)>| {
+        field.as_ref().map(|(i, value)| {
+            let ident = field_idents[*i];
+            if field_nullables[*i] {
+                quote! { self.#ident = ::std::option::Option::Some(#value); }
+            } else {
+                quote! { self.#ident = #value; }
+            }
+        })
+    };
+    let (stamp_created, stamp_updated) = (stamp(&created_at), stamp(&updated_at));
+    let stamp_update = stamp_updated.as_ref().map(|stamp_updated| {
+        quote! {
+            let now = premix_orm::chrono::Utc::now();
+            #stamp_updated
+        }
+    });
+    let stamp_insert = (created_at.is_some() || updated_at.is_some()).then(|| {
+        quote! {
+            let now = premix_orm::chrono::Utc::now();
+            #stamp_created
+            #stamp_updated
+        }
+    });
+    let timestamp_tys: Vec<_> = created_at
+        .iter()
+        .chain(&updated_at)
+        .map(|(i, _)| field_types[*i])
+        .collect();
+    let touch_updated_at_impl = updated_at.as_ref().map(|(i, value)| {
+        let column = &field_names[*i];
+        quote! {
+            fn touch_updated_at() -> ::std::option::Option<(&'static str, premix_orm::BindValue)> {
+                let now = premix_orm::chrono::Utc::now();
+                ::std::option::Option::Some((#column, premix_orm::BindValue::from(#value)))
+            }
+        }
+    });
+
     let save_update_block = if has_version {
         quote! {
-            if self.id != 0 {
+            if !#pk_unset {
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -229,21 +498,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
-                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(2 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
+                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #pk_count + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 64);
                     use ::std::fmt::Write;
                     let _ = write!(
                         sql,
-                        "UPDATE {} SET {}, version = version + 1 WHERE id = {} AND version = {}",
-                        table_name,
+                        "UPDATE {} SET {}, version = version + 1 WHERE {} AND version = {}",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
                         set_clause,
-                        id_p,
+                        key_condition,
                         ver_p
                     );
                     sql
@@ -258,23 +527,29 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id)
+                    #( .bind(&self.#pk_idents) )*
                     .bind(&self.version);
 
                 let result = executor.execute(query).await?;
                 if <DB as premix_orm::SqlDialect>::rows_affected(&result) == 0 {
                     static EXISTS_SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                     let exists_sql = EXISTS_SQL.get_or_init(|| {
-                        let exists_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                        let exists_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                         let mut exists_sql = String::with_capacity(table_name.len() + 32);
                         use ::std::fmt::Write;
-                        let _ = write!(exists_sql, "SELECT id FROM {} WHERE id = {}", table_name, exists_p);
+                        let _ = write!(
+                            exists_sql,
+                            "SELECT {} FROM {} WHERE {}",
+                            <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column),
+                            <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
+                            exists_condition
+                        );
                         exists_sql
                     });
                     let exists_query =
-                        premix_orm::sqlx::query_as::<DB, (i32,)>(exists_sql)
+                        premix_orm::sqlx::query_as::<DB, (#pk_ty,)>(exists_sql)
                             .persistent(true)
-                            .bind(&self.id);
+                            #( .bind(&self.#pk_idents) )*;
                     let exists = executor.fetch_optional(exists_query).await?;
                     if exists.is_some() {
                         return Err(premix_orm::sqlx::Error::Protocol(
@@ -290,7 +565,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
         }
     } else {
         quote! {
-            if self.id != 0 {
+            if !#pk_unset {
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -300,15 +575,15 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 32);
                     use ::std::fmt::Write;
-                    let _ = write!(sql, "UPDATE {} SET {} WHERE id = {}", table_name, set_clause, id_p);
+                    let _ = write!(sql, "UPDATE {} SET {} WHERE {}", <DB as premix_orm::SqlDialect>::quote_identifier(table_name), set_clause, key_condition);
                     sql
                 });
 
@@ -321,7 +596,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id);
+                    #( .bind(&self.#pk_idents) )*;
 
                 let result = executor.execute(query).await?;
                 if <DB as premix_orm::SqlDialect>::rows_affected(&result) > 0 {
@@ -334,7 +609,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
     let save_fast_update_block = if has_version {
         quote! {
-            if self.id != 0 {
+            if !#pk_unset {
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -344,21 +619,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
-                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(2 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
+                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #pk_count + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 64);
                     use ::std::fmt::Write;
                     let _ = write!(
                         sql,
-                        "UPDATE {} SET {}, version = version + 1 WHERE id = {} AND version = {}",
-                        table_name,
+                        "UPDATE {} SET {}, version = version + 1 WHERE {} AND version = {}",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
                         set_clause,
-                        id_p,
+                        key_condition,
                         ver_p
                     );
                     sql
@@ -366,23 +641,29 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id)
+                    #( .bind(&self.#pk_idents) )*
                     .bind(&self.version);
 
                 let result = executor.execute(query).await?;
                 if <DB as premix_orm::SqlDialect>::rows_affected(&result) == 0 {
                     static EXISTS_SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                     let exists_sql = EXISTS_SQL.get_or_init(|| {
-                        let exists_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                        let exists_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                         let mut exists_sql = String::with_capacity(table_name.len() + 32);
                         use ::std::fmt::Write;
-                        let _ = write!(exists_sql, "SELECT id FROM {} WHERE id = {}", table_name, exists_p);
+                        let _ = write!(
+                            exists_sql,
+                            "SELECT {} FROM {} WHERE {}",
+                            <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column),
+                            <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
+                            exists_condition
+                        );
                         exists_sql
                     });
                     let exists_query =
-                        premix_orm::sqlx::query_as::<DB, (i32,)>(exists_sql)
+                        premix_orm::sqlx::query_as::<DB, (#pk_ty,)>(exists_sql)
                             .persistent(true)
-                            .bind(&self.id);
+                            #( .bind(&self.#pk_idents) )*;
                     let exists = executor.fetch_optional(exists_query).await?;
                     if exists.is_some() {
                         return Err(premix_orm::sqlx::Error::Protocol(
@@ -397,7 +678,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
         }
     } else {
         quote! {
-            if self.id != 0 {
+            if !#pk_unset {
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -407,21 +688,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 32);
                     use ::std::fmt::Write;
-                    let _ = write!(sql, "UPDATE {} SET {} WHERE id = {}", table_name, set_clause, id_p);
+                    let _ = write!(sql, "UPDATE {} SET {} WHERE {}", <DB as premix_orm::SqlDialect>::quote_identifier(table_name), set_clause, key_condition);
                     sql
                 });
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id);
+                    #( .bind(&self.#pk_idents) )*;
 
                 let result = executor.execute(query).await?;
                 if <DB as premix_orm::SqlDialect>::rows_affected(&result) > 0 {
@@ -444,6 +725,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             {
                 async move {
                 let mut executor = executor.into_executor();
+                #stamp_update
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -453,21 +735,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
-                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(2 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
+                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #pk_count + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 64);
                     use ::std::fmt::Write;
                     let _ = write!(
                         sql,
-                        "UPDATE {} SET {}, version = version + 1 WHERE id = {} AND version = {}",
-                        table_name,
+                        "UPDATE {} SET {}, version = version + 1 WHERE {} AND version = {}",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
                         set_clause,
-                        id_p,
+                        key_condition,
                         ver_p
                     );
                     sql
@@ -482,7 +764,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id)
+                    #( .bind(&self.#pk_idents) )*
                     .bind(&self.version);
 
                 let result = executor.execute(query).await?;
@@ -490,16 +772,22 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 if <DB as premix_orm::SqlDialect>::rows_affected(&result) == 0 {
                     static EXISTS_SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                     let exists_sql = EXISTS_SQL.get_or_init(|| {
-                        let exists_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                        let exists_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                         let mut exists_sql = String::with_capacity(table_name.len() + 32);
                         use ::std::fmt::Write;
-                        let _ = write!(exists_sql, "SELECT id FROM {} WHERE id = {}", table_name, exists_p);
+                        let _ = write!(
+                            exists_sql,
+                            "SELECT {} FROM {} WHERE {}",
+                            <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column),
+                            <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
+                            exists_condition
+                        );
                         exists_sql
                     });
                     let exists_query =
-                        premix_orm::sqlx::query_as::<DB, (i32,)>(exists_sql)
+                        premix_orm::sqlx::query_as::<DB, (#pk_ty,)>(exists_sql)
                             .persistent(true)
-                            .bind(&self.id);
+                            #( .bind(&self.#pk_idents) )*;
                     let exists = executor.fetch_optional(exists_query).await?;
 
                     if exists.is_none() {
@@ -525,6 +813,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             {
                 async move {
                 let mut executor = executor.into_executor();
+                #stamp_update
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -534,21 +823,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
-                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(2 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
+                    let ver_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #pk_count + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 64);
                     use ::std::fmt::Write;
                     let _ = write!(
                         sql,
-                        "UPDATE {} SET {}, version = version + 1 WHERE id = {} AND version = {}",
-                        table_name,
+                        "UPDATE {} SET {}, version = version + 1 WHERE {} AND version = {}",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
                         set_clause,
-                        id_p,
+                        key_condition,
                         ver_p
                     );
                     sql
@@ -556,7 +845,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id)
+                    #( .bind(&self.#pk_idents) )*
                     .bind(&self.version);
 
                 let result = executor.execute(query).await?;
@@ -564,16 +853,22 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 if <DB as premix_orm::SqlDialect>::rows_affected(&result) == 0 {
                     static EXISTS_SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                     let exists_sql = EXISTS_SQL.get_or_init(|| {
-                        let exists_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                        let exists_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                         let mut exists_sql = String::with_capacity(table_name.len() + 32);
                         use ::std::fmt::Write;
-                        let _ = write!(exists_sql, "SELECT id FROM {} WHERE id = {}", table_name, exists_p);
+                        let _ = write!(
+                            exists_sql,
+                            "SELECT {} FROM {} WHERE {}",
+                            <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column),
+                            <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
+                            exists_condition
+                        );
                         exists_sql
                     });
                     let exists_query =
-                        premix_orm::sqlx::query_as::<DB, (i32,)>(exists_sql)
+                        premix_orm::sqlx::query_as::<DB, (#pk_ty,)>(exists_sql)
                             .persistent(true)
-                            .bind(&self.id);
+                            #( .bind(&self.#pk_idents) )*;
                     let exists = executor.fetch_optional(exists_query).await?;
 
                     if exists.is_none() {
@@ -612,6 +907,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             {
                 async move {
                 let mut executor = executor.into_executor();
+                #stamp_update
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -621,15 +917,15 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 32);
                     use ::std::fmt::Write;
-                    let _ = write!(sql, "UPDATE {} SET {} WHERE id = {}", table_name, set_clause, id_p);
+                    let _ = write!(sql, "UPDATE {} SET {} WHERE {}", <DB as premix_orm::SqlDialect>::quote_identifier(table_name), set_clause, key_condition);
                     sql
                 });
 
@@ -642,7 +938,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id);
+                    #( .bind(&self.#pk_idents) )*;
 
                 let result = executor.execute(query).await?;
 
@@ -665,6 +961,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             {
                 async move {
                 let mut executor = executor.into_executor();
+                #stamp_update
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
@@ -674,21 +971,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         if i > 1 {
                             set_clause.push_str(", ");
                         }
-                        set_clause.push_str(#field_names);
+                        set_clause.push_str(&<DB as premix_orm::SqlDialect>::quote_identifier(#field_names));
                         set_clause.push_str(" = ");
                         set_clause.push_str(&<DB as premix_orm::SqlDialect>::placeholder(i));
                         i += 1;
                     )*
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1 + #field_idents_len);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1 + #field_idents_len);
                     let mut sql = String::with_capacity(set_clause.len() + table_name.len() + 32);
                     use ::std::fmt::Write;
-                    let _ = write!(sql, "UPDATE {} SET {} WHERE id = {}", table_name, set_clause, id_p);
+                    let _ = write!(sql, "UPDATE {} SET {} WHERE {}", <DB as premix_orm::SqlDialect>::quote_identifier(table_name), set_clause, key_condition);
                     sql
                 });
 
                 let mut query = premix_orm::sqlx::query::<DB>(sql).persistent(true)
                     #( .bind(&self.#field_idents) )*
-                    .bind(&self.id);
+                    #( .bind(&self.#pk_idents) )*;
 
                 let result = executor.execute(query).await?;
 
@@ -728,15 +1025,15 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                     let mut sql = String::with_capacity(table_name.len() + 64);
                     use ::std::fmt::Write;
                     let _ = write!(
                         sql,
-                        "UPDATE {} SET deleted_at = {} WHERE id = {}",
-                        table_name,
+                        "UPDATE {} SET deleted_at = {} WHERE {}",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
                         <DB as premix_orm::SqlDialect>::current_timestamp_fn(),
-                        id_p
+                        key_condition
                     );
                     sql
                 });
@@ -748,7 +1045,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                     "premix query"
                 );
 
-                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true).bind(&self.id);
+                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true)#( .bind(&self.#pk_idents) )*;
                 executor.execute(query).await?;
 
                 self.deleted_at = Some("DELETED".to_string());
@@ -768,20 +1065,20 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                     let mut sql = String::with_capacity(table_name.len() + 64);
                     use ::std::fmt::Write;
                     let _ = write!(
                         sql,
-                        "UPDATE {} SET deleted_at = {} WHERE id = {}",
-                        table_name,
+                        "UPDATE {} SET deleted_at = {} WHERE {}",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(table_name),
                         <DB as premix_orm::SqlDialect>::current_timestamp_fn(),
-                        id_p
+                        key_condition
                     );
                     sql
                 });
 
-                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true).bind(&self.id);
+                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true)#( .bind(&self.#pk_idents) )*;
                 executor.execute(query).await?;
 
                 self.deleted_at = Some("DELETED".to_string());
@@ -815,10 +1112,10 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                     let mut sql = String::with_capacity(table_name.len() + 24);
                     use ::std::fmt::Write;
-                    let _ = write!(sql, "DELETE FROM {} WHERE id = {}", table_name, id_p);
+                    let _ = write!(sql, "DELETE FROM {} WHERE {}", <DB as premix_orm::SqlDialect>::quote_identifier(table_name), key_condition);
                     sql
                 });
 
@@ -829,7 +1126,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                     "premix query"
                 );
 
-                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true).bind(&self.id);
+                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true)#( .bind(&self.#pk_idents) )*;
                 executor.execute(query).await?;
 
                 Ok(())
@@ -848,14 +1145,14 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 let table_name = Self::table_name();
                 static SQL: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
                 let sql = SQL.get_or_init(|| {
-                    let id_p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                    let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
                     let mut sql = String::with_capacity(table_name.len() + 24);
                     use ::std::fmt::Write;
-                    let _ = write!(sql, "DELETE FROM {} WHERE id = {}", table_name, id_p);
+                    let _ = write!(sql, "DELETE FROM {} WHERE {}", <DB as premix_orm::SqlDialect>::quote_identifier(table_name), key_condition);
                     sql
                 });
 
-                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true).bind(&self.id);
+                let query = premix_orm::sqlx::query::<DB>(sql).persistent(true)#( .bind(&self.#pk_idents) )*;
                 executor.execute(query).await?;
 
                 Ok(())
@@ -875,6 +1172,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
         }
     };
 
+    let naming = naming::for_current_crate()?;
     let mut related_model_bounds = Vec::new();
     for field in all_fields {
         for attr in &field.attrs {
@@ -885,7 +1183,11 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             } else if attr.path().is_ident("belongs_to")
                 && let Ok(related_ident) = attr.parse_args::<syn::Ident>()
             {
-                related_model_bounds.push(quote! { #related_ident: premix_orm::Model<DB> + Clone });
+                // Pin the parent's key type so eager loading can compare it with ours.
+                let fk = naming.foreign_key(&related_ident.to_string());
+                let fk_ty = relations::field_type(Some(all_fields), &fk);
+                related_model_bounds
+                    .push(quote! { #related_ident: premix_orm::Model<DB, Id = #fk_ty> + Clone });
             }
         }
     }
@@ -917,6 +1219,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
         })
         .collect();
 
+    let typed_col_consts: Vec<_> = field_names
+        .iter()
+        .zip(field_idents.iter())
+        .zip(field_types.iter())
+        .map(|((name, ident), ty)| {
+            let const_name = syn::Ident::new(&ident.to_string().to_uppercase(), ident.span());
+            let value_ty = option_inner_type(ty).unwrap_or(ty);
+            let doc = format!("Typed handle for the `{}` column.", name);
+            quote! {
+                #[doc = #doc]
+                pub const #const_name: premix_orm::Column<#value_ty> = premix_orm::Column::new(#name);
+            }
+        })
+        .collect();
+
     let columns_mod_ident = syn::Ident::new(
         &format!("columns_{}", struct_name.to_string().to_lowercase()),
         struct_name.span(),
@@ -930,6 +1247,11 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
              #( #col_consts )*
         }
 
+        #[allow(dead_code)]
+        impl #struct_name {
+            #( #typed_col_consts )*
+        }
+
         impl<'r, R> premix_orm::sqlx::FromRow<'r, R> for #struct_name
         where
             R: premix_orm::sqlx::Row,
@@ -965,27 +1287,45 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             String: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB> + for<'r> premix_orm::sqlx::Decode<'r, DB>,
             bool: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB> + for<'r> premix_orm::sqlx::Decode<'r, DB>,
             Option<String>: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB> + for<'r> premix_orm::sqlx::Decode<'r, DB>,
+            #( #pk_tys: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB> + for<'r> premix_orm::sqlx::Decode<'r, DB>, )*
+            #( #timestamp_tys: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB> + for<'r> premix_orm::sqlx::Decode<'r, DB>, )*
             #( #related_model_bounds, )*
         {
+            type Id = #id_ty;
+
             fn table_name() -> &'static str {
                 #table_name
             }
 
+            fn primary_key() -> &'static str {
+                #pk_column
+            }
+
+            fn primary_key_columns() -> &'static [&'static str] {
+                &[ #( #pk_columns ),* ]
+            }
+
+            fn primary_key_value(&self) -> Self::Id {
+                #pk_value
+            }
+
             fn create_table_sql() -> String {
-                let mut cols = vec!["id ".to_string() + <DB as premix_orm::SqlDialect>::auto_increment_pk()];
-                #(
-                    if #field_names != "id" {
-                        let sql_type = #field_sql_type_exprs;
-                        cols.push(format!("{} {}", #field_names, sql_type));
-                    }
-                )*
-                format!("CREATE TABLE IF NOT EXISTS {} ({})", #table_name, cols.join(", "))
+                #create_table_body
+                format!(
+                    "CREATE TABLE IF NOT EXISTS {} ({})",
+                    <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                    cols.join(", ")
+                )
             }
 
             fn list_columns() -> ::std::vec::Vec<::std::string::String> {
                 vec![ #( #field_names.to_string() ),* ]
             }
 
+            fn column_names() -> &'static [&'static str] {
+                <Self as premix_orm::ModelSchema>::COLUMNS
+            }
+
             fn sensitive_fields() -> &'static [&'static str] {
                 &[ #( #sensitive_field_literals ),* ]
             }
@@ -998,6 +1338,8 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 &[ #( #eager_relation_names ),* ]
             }
 
+            #touch_updated_at_impl
+
             fn from_row_fast(row: &<DB as premix_orm::sqlx::Database>::Row) -> Result<Self, premix_orm::sqlx::Error>
             where
                 usize: premix_orm::sqlx::ColumnIndex<<DB as premix_orm::sqlx::Database>::Row>,
@@ -1028,26 +1370,28 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 use premix_orm::ModelHooks;
                 self.before_save().await?;
 
+                #stamp_update
                 #save_update_block
+                #stamp_insert
 
-                // CONSTANT column lists to avoid runtime joining/allocation
-                // We use head/tail pattern to insert ", " separator without trailing comma
-                const ALL_COLUMNS_LIST: &str = concat!(#all_cols_head, #( ", ", #all_cols_tail ),*);
-                const NO_ID_COLUMNS_LIST: &str = concat!(#no_id_cols_head, #( ", ", #no_id_cols_tail ),*);
+                // Quoted per dialect once, when each statement is first built.
+                const ALL_COLUMNS: &[&str] = &[ #( #field_names ),* ];
+                const NO_ID_COLUMNS: &[&str] = &[ #( #field_names_no_id ),* ];
 
                 let supports_returning = <DB as premix_orm::SqlDialect>::supports_returning();
                 if supports_returning {
-                    let sql = if self.id == 0 {
+                    let sql = if #pk_unset {
                         static INSERT_NO_ID_RETURNING_SQL: std::sync::OnceLock<String> =
                             std::sync::OnceLock::new();
                         INSERT_NO_ID_RETURNING_SQL.get_or_init(|| {
                             let placeholders =
                                 premix_orm::cached_placeholders::<DB>(#field_names_no_id_len);
                             format!(
-                                "INSERT INTO {} ({}) VALUES ({}) RETURNING id",
-                                #table_name,
-                                NO_ID_COLUMNS_LIST,
-                                placeholders
+                                "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(NO_ID_COLUMNS),
+                                placeholders,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column)
                             )
                         })
                     } else {
@@ -1057,10 +1401,11 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                             let placeholders =
                                 premix_orm::cached_placeholders::<DB>(#field_idents_len);
                             format!(
-                                "INSERT INTO {} ({}) VALUES ({}) RETURNING id",
-                                #table_name,
-                                ALL_COLUMNS_LIST,
-                                placeholders
+                                "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(ALL_COLUMNS),
+                                placeholders,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column)
                             )
                         })
                     };
@@ -1072,21 +1417,21 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                         "premix query"
                     );
 
-                    let mut query = premix_orm::sqlx::query_as::<DB, (i32,)>(sql.as_str())
+                    let mut query = premix_orm::sqlx::query_as::<DB, (#pk_ty,)>(sql.as_str())
                         .persistent(true);
                     #(
-                        if #field_names != "id" {
+                        if !#field_is_pk {
+                            query = query.bind(&self.#field_idents);
+                        } else if !#pk_unset {
                             query = query.bind(&self.#field_idents);
-                        } else if self.id != 0 {
-                            query = query.bind(&self.id);
                         }
                     )*
 
                     if let Some((id,)) = executor.fetch_optional(query).await? {
-                        self.id = id;
+                        self.#pk_ident = id;
                     }
                 } else {
-                    let sql = if self.id == 0 {
+                    let sql = if #pk_unset {
                         static INSERT_NO_ID_SQL: std::sync::OnceLock<String> =
                             std::sync::OnceLock::new();
                         INSERT_NO_ID_SQL.get_or_init(|| {
@@ -1094,8 +1439,8 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                                 premix_orm::cached_placeholders::<DB>(#field_names_no_id_len);
                             format!(
                                 "INSERT INTO {} ({}) VALUES ({})",
-                                #table_name,
-                                NO_ID_COLUMNS_LIST,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(NO_ID_COLUMNS),
                                 placeholders
                             )
                         })
@@ -1107,8 +1452,8 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                                 premix_orm::cached_placeholders::<DB>(#field_idents_len);
                             format!(
                                 "INSERT INTO {} ({}) VALUES ({})",
-                                #table_name,
-                                ALL_COLUMNS_LIST,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(ALL_COLUMNS),
                                 placeholders
                             )
                         })
@@ -1123,17 +1468,19 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                     let mut query = premix_orm::sqlx::query::<DB>(sql.as_str()).persistent(true);
                     #(
-                        if #field_names != "id" {
+                        if !#field_is_pk {
+                            query = query.bind(&self.#field_idents);
+                        } else if !#pk_unset {
                             query = query.bind(&self.#field_idents);
-                        } else if self.id != 0 {
-                            query = query.bind(&self.id);
                         }
                     )*
 
                     let result = executor.execute(query).await?;
                     let last_id = <DB as premix_orm::SqlDialect>::last_insert_id(&result);
-                    if last_id > 0 {
-                        self.id = last_id as i32;
+                    if let Some(id) = <#pk_ty as premix_orm::PrimaryKey>::from_generated_id(last_id)
+                        .filter(|_| last_id > 0 && #pk_unset)
+                    {
+                        self.#pk_ident = id;
                     }
                 }
 
@@ -1153,26 +1500,28 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 async move {
                 let mut executor = executor.into_executor();
 
+                #stamp_update
                 #save_fast_update_block
+                #stamp_insert
 
-                // CONSTANT column lists to avoid runtime joining/allocation
-                // We use head/tail pattern to insert ", " separator without trailing comma
-                const ALL_COLUMNS_LIST: &str = concat!(#all_cols_head, #( ", ", #all_cols_tail ),*);
-                const NO_ID_COLUMNS_LIST: &str = concat!(#no_id_cols_head, #( ", ", #no_id_cols_tail ),*);
+                // Quoted per dialect once, when each statement is first built.
+                const ALL_COLUMNS: &[&str] = &[ #( #field_names ),* ];
+                const NO_ID_COLUMNS: &[&str] = &[ #( #field_names_no_id ),* ];
 
                 let supports_returning = <DB as premix_orm::SqlDialect>::supports_returning();
                 if supports_returning {
-                    let sql = if self.id == 0 {
+                    let sql = if #pk_unset {
                         static INSERT_NO_ID_RETURNING_SQL: std::sync::OnceLock<String> =
                             std::sync::OnceLock::new();
                         INSERT_NO_ID_RETURNING_SQL.get_or_init(|| {
                             let placeholders =
                                 premix_orm::cached_placeholders::<DB>(#field_names_no_id_len);
                             format!(
-                                "INSERT INTO {} ({}) VALUES ({}) RETURNING id",
-                                #table_name,
-                                NO_ID_COLUMNS_LIST,
-                                placeholders
+                                "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(NO_ID_COLUMNS),
+                                placeholders,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column)
                             )
                         })
                     } else {
@@ -1182,29 +1531,30 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                             let placeholders =
                                 premix_orm::cached_placeholders::<DB>(#field_idents_len);
                             format!(
-                                "INSERT INTO {} ({}) VALUES ({}) RETURNING id",
-                                #table_name,
-                                ALL_COLUMNS_LIST,
-                                placeholders
+                                "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(ALL_COLUMNS),
+                                placeholders,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#pk_column)
                             )
                         })
                     };
 
-                    let mut query = premix_orm::sqlx::query_as::<DB, (i32,)>(sql.as_str())
+                    let mut query = premix_orm::sqlx::query_as::<DB, (#pk_ty,)>(sql.as_str())
                         .persistent(true);
                     #(
-                        if #field_names != "id" {
+                        if !#field_is_pk {
+                            query = query.bind(&self.#field_idents);
+                        } else if !#pk_unset {
                             query = query.bind(&self.#field_idents);
-                        } else if self.id != 0 {
-                            query = query.bind(&self.id);
                         }
                     )*
 
                     if let Some((id,)) = executor.fetch_optional(query).await? {
-                        self.id = id;
+                        self.#pk_ident = id;
                     }
                 } else {
-                    let sql = if self.id == 0 {
+                    let sql = if #pk_unset {
                         static INSERT_NO_ID_SQL: std::sync::OnceLock<String> =
                             std::sync::OnceLock::new();
                         INSERT_NO_ID_SQL.get_or_init(|| {
@@ -1212,8 +1562,8 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                                 premix_orm::cached_placeholders::<DB>(#field_names_no_id_len);
                             format!(
                                 "INSERT INTO {} ({}) VALUES ({})",
-                                #table_name,
-                                NO_ID_COLUMNS_LIST,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(NO_ID_COLUMNS),
                                 placeholders
                             )
                         })
@@ -1225,8 +1575,8 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                                 premix_orm::cached_placeholders::<DB>(#field_idents_len);
                             format!(
                                 "INSERT INTO {} ({}) VALUES ({})",
-                                #table_name,
-                                ALL_COLUMNS_LIST,
+                                <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                                premix_orm::build_column_list::<DB>(ALL_COLUMNS),
                                 placeholders
                             )
                         })
@@ -1234,17 +1584,19 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
                     let mut query = premix_orm::sqlx::query::<DB>(sql.as_str()).persistent(true);
                     #(
-                        if #field_names != "id" {
+                        if !#field_is_pk {
+                            query = query.bind(&self.#field_idents);
+                        } else if !#pk_unset {
                             query = query.bind(&self.#field_idents);
-                        } else if self.id != 0 {
-                            query = query.bind(&self.id);
                         }
                     )*
 
                     let result = executor.execute(query).await?;
                     let last_id = <DB as premix_orm::SqlDialect>::last_insert_id(&result);
-                    if last_id > 0 {
-                        self.id = last_id as i32;
+                    if let Some(id) = <#pk_ty as premix_orm::PrimaryKey>::from_generated_id(last_id)
+                        .filter(|_| last_id > 0 && #pk_unset)
+                    {
+                        self.#pk_ident = id;
                     }
                 }
 
@@ -1261,38 +1613,41 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             {
                 async move {
                 let mut executor = executor.into_executor();
+                #stamp_insert
 
-                // CONSTANT column lists to avoid runtime joining/allocation
-                // We use head/tail pattern to insert ", " separator without trailing comma
-                const ALL_COLUMNS_LIST: &str = concat!(#all_cols_head, #( ", ", #all_cols_tail ),*);
-                const NO_ID_COLUMNS_LIST: &str = concat!(#no_id_cols_head, #( ", ", #no_id_cols_tail ),*);
+                // Quoted per dialect once, when each statement is first built.
+                const ALL_COLUMNS: &[&str] = &[ #( #field_names ),* ];
+                const NO_ID_COLUMNS: &[&str] = &[ #( #field_names_no_id ),* ];
 
-                let column_list: &str = if self.id == 0 { NO_ID_COLUMNS_LIST } else { ALL_COLUMNS_LIST };
+                let column_list =
+                    premix_orm::build_column_list::<DB>(if #pk_unset { NO_ID_COLUMNS } else { ALL_COLUMNS });
 
                 // We still need to calculate placeholders at runtime because they depend on the count and DB dialect
-                let count = if self.id == 0 { #field_names_no_id_len } else { #field_idents_len };
+                let count = if #pk_unset { #field_names_no_id_len } else { #field_idents_len };
                 let placeholders = premix_orm::cached_placeholders::<DB>(count);
 
                 let sql = format!(
                     "INSERT INTO {} ({}) VALUES ({})",
-                    #table_name,
+                    <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
                     column_list,
                     placeholders
                 );
 
                 let mut query = premix_orm::sqlx::query::<DB>(&sql).persistent(true);
                 #(
-                    if #field_names != "id" {
+                    if !#field_is_pk {
+                        query = query.bind(&self.#field_idents);
+                    } else if !#pk_unset {
                         query = query.bind(&self.#field_idents);
-                    } else if self.id != 0 {
-                        query = query.bind(&self.id);
                     }
                 )*
 
                 let result = executor.execute(query).await?;
                 let last_id = <DB as premix_orm::SqlDialect>::last_insert_id(&result);
-                if last_id > 0 {
-                    self.id = last_id as i32;
+                if let Some(id) = <#pk_ty as premix_orm::PrimaryKey>::from_generated_id(last_id)
+                    .filter(|_| last_id > 0 && #pk_unset)
+                {
+                    self.#pk_ident = id;
                 }
 
                 Ok(())
@@ -1304,7 +1659,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 
             fn find_by_id<'a, E>(
                 executor: E,
-                id: i32,
+                id: Self::Id,
             ) -> impl ::std::future::Future<Output = ::std::result::Result<::std::option::Option<Self>, premix_orm::sqlx::Error>>
             + Send
             where
@@ -1312,13 +1667,17 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
             {
                 async move {
                 let mut executor = executor.into_executor();
-                let p = <DB as premix_orm::SqlDialect>::placeholder(1);
+                let key_condition = premix_orm::build_key_condition::<DB>(&[#(#pk_columns),*], 1);
 
                 // Optimization: Pre-calculate the base SQL string
                 let sql = if Self::has_soft_delete() {
-                    format!("SELECT * FROM {} WHERE id = {} AND deleted_at IS NULL LIMIT 1", #table_name, p)
+                    format!(
+                        "SELECT * FROM {} WHERE {} AND deleted_at IS NULL LIMIT 1",
+                        <DB as premix_orm::SqlDialect>::quote_identifier(#table_name),
+                        key_condition
+                    )
                 } else {
-                    format!("SELECT * FROM {} WHERE id = {} LIMIT 1", #table_name, p)
+                    format!("SELECT * FROM {} WHERE {} LIMIT 1", <DB as premix_orm::SqlDialect>::quote_identifier(#table_name), key_condition)
                 };
 
                 premix_orm::tracing::debug!(
@@ -1329,7 +1688,7 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 );
                 let query = premix_orm::sqlx::query_as::<DB, Self>(&sql)
                     .persistent(true)
-                    .bind(id);
+                    #id_binds;
                 executor.fetch_optional(query).await
                 }
             }
@@ -1351,6 +1710,11 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
         #validation_impl
 
         impl premix_orm::ModelSchema for #struct_name {
+            const TABLE_NAME: &'static str = #table_name;
+            const PRIMARY_KEY: &'static str = #pk_column;
+            const COLUMNS: &'static [&'static str] = &[ #( #field_names ),* ];
+            const SOFT_DELETE: bool = #has_soft_delete;
+
             fn schema() -> premix_orm::schema::SchemaTable {
                 let columns = vec![
                     #(
@@ -1373,6 +1737,8 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                     columns,
                     indexes,
                     foreign_keys,
+                    fulltext: vec![#(#fulltext_field_literals.to_string()),*],
+                    primary_key: Vec::new(),
                     create_sql: None,
                 }
             }
@@ -1381,15 +1747,143 @@ fn generate_generic_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
 }
 
 fn has_premix_field_flag(field: &Field, flag: &str) -> bool {
+    has_premix_flag(&field.attrs, flag)
+}
+
+/// The SQL column for a field: `#[premix(column = "...")]` when given, otherwise
+/// the field name.
+fn field_column_name(field: &Field) -> syn::Result<String> {
+    let ident = field
+        .ident
+        .as_ref()
+        .ok_or_else(|| syn::Error::new_spanned(field, "Field must have an ident"))?;
+    let mut column: Option<LitStr> = None;
     for attr in &field.attrs {
-        if attr.path().is_ident("premix")
-            && let Ok(meta) = attr.parse_args::<syn::Ident>()
-            && meta == flag
+        if !attr.path().is_ident("premix") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("column") {
+                let value: LitStr = meta.value()?.parse()?;
+                let name = value.value();
+                let mut chars = name.chars();
+                let valid = chars
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !valid {
+                    return Err(syn::Error::new_spanned(
+                        &value,
+                        "column name may only contain letters, digits and '_'",
+                    ));
+                }
+                column = Some(value);
+                Ok(())
+            } else {
+                skip_premix_meta(&meta)
+            }
+        })?;
+    }
+    let Some(column) = column else {
+        return Ok(ident.to_string());
+    };
+    // `version` and `deleted_at` drive optimistic locking and soft delete by name.
+    const RESERVED: [&str; 2] = ["version", "deleted_at"];
+    if RESERVED.contains(&ident.to_string().as_str()) || RESERVED.contains(&column.value().as_str())
+    {
+        return Err(syn::Error::new_spanned(
+            &column,
+            "`version` and `deleted_at` cannot be renamed",
+        ));
+    }
+    Ok(column.value())
+}
+
+/// The primary key fields: those marked `#[premix(primary_key)]` (several make a
+/// composite key), or the `id` field when none are marked.
+fn primary_key_fields(input: &DeriveInput) -> syn::Result<Vec<&Field>> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "Premix Model only supports structs",
+        ));
+    };
+    let marked: Vec<&Field> = data
+        .fields
+        .iter()
+        .filter(|f| has_premix_field_flag(f, "primary_key"))
+        .collect();
+    if !marked.is_empty() {
+        return Ok(marked);
+    }
+    data.fields
+        .iter()
+        .filter(|f| !is_ignored(f))
+        .find(|f| f.ident.as_ref().is_some_and(|ident| ident == "id"))
+        .map(|field| vec![field])
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                &input.ident,
+                "Premix Model needs an `id` field or a `#[premix(primary_key)]` field",
+            )
+        })
+}
+
+/// The `created_at`/`updated_at` field kept current by the derive: the field
+/// marked `#[premix(<flag>)]`, or else a timestamp field named `<flag>`.
+/// Returns its index and the current time (`now`) as its value type.
+fn timestamp_field(
+    fields: &[&Field],
+    flag: &str,
+) -> syn::Result<Option<(usize, proc_macro2::TokenStream)>> {
+    let marked: Vec<usize> = (0..fields.len())
+        .filter(|&i| has_premix_field_flag(fields[i], flag))
+        .collect();
+    match marked[..] {
+        [] => Ok(fields
+            .iter()
+            .position(|f| f.ident.as_ref().is_some_and(|ident| ident == flag))
+            .and_then(|i| timestamp_value(&fields[i].ty).map(|value| (i, value)))),
+        [index] => timestamp_value(&fields[index].ty)
+            .map(|value| Some((index, value)))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &fields[index].ty,
+                    format!(
+                        "#[premix({})] needs a DateTime<Utc> or NaiveDateTime field",
+                        flag
+                    ),
+                )
+            }),
+        [_, second, ..] => Err(syn::Error::new_spanned(
+            fields[second],
+            format!("only one field can be marked #[premix({})]", flag),
+        )),
+    }
+}
+
+/// `now` converted to a `DateTime<Utc>` or `NaiveDateTime` field's type
+/// (ignoring an `Option` wrapper), or `None` for any other type.
+fn timestamp_value(ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+    let syn::Type::Path(path) = option_inner_type(ty).unwrap_or(ty) else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident == "NaiveDateTime" {
+        return Some(quote! { now.naive_utc() });
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(syn::Type::Path(tz))
+            if segment.ident == "DateTime"
+                && tz.path.segments.last().is_some_and(|s| s.ident == "Utc") =>
         {
-            return true;
+            Some(quote! { now })
         }
+        _ => None,
     }
-    false
 }
 
 fn is_ignored(field: &Field) -> bool {
@@ -1400,6 +1894,10 @@ fn is_sensitive(field: &Field) -> bool {
     has_premix_field_flag(field, "sensitive")
 }
 
+fn is_fulltext(field: &Field) -> bool {
+    has_premix_field_flag(field, "fulltext")
+}
+
 struct IndexSpec {
     name: String,
     columns: Vec<String>,
@@ -1423,11 +1921,7 @@ fn collect_schema_specs(
         if is_ignored(field) {
             continue;
         }
-        let field_name = field
-            .ident
-            .as_ref()
-            .ok_or_else(|| syn::Error::new_spanned(field, "Field must have an ident"))?
-            .to_string();
+        let field_name = field_column_name(field)?;
 
         for attr in &field.attrs {
             if !attr.path().is_ident("premix") {
@@ -1482,6 +1976,8 @@ fn collect_schema_specs(
                         ref_table,
                         ref_column,
                     });
+                } else {
+                    skip_premix_meta(&meta)?;
                 }
                 Ok(())
             })?;
@@ -1491,6 +1987,24 @@ fn collect_schema_specs(
     Ok((indexes, foreign_keys))
 }
 
+/// Returns `T` for an `Option<T>` field type.
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn is_option_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(path) = ty {
         if let Some(seg) = path.path.segments.last() {
@@ -1501,17 +2015,18 @@ fn is_option_type(ty: &syn::Type) -> bool {
 }
 
 fn has_premix_flag(attrs: &[Attribute], flag: &str) -> bool {
+    let mut found = false;
     for attr in attrs {
         if attr.path().is_ident("premix") {
-            let args = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated);
-            if let Ok(args) = args {
-                if args.iter().any(|ident| ident == flag) {
-                    return true;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(flag) {
+                    found = true;
                 }
-            }
+                skip_premix_meta(&meta)
+            });
         }
     }
-    false
+    found
 }
 
 fn type_name_for_field(ty: &syn::Type) -> Option<String> {
@@ -1705,6 +2220,65 @@ mod tests {
         assert!(tokens.contains("account_id"));
     }
 
+    #[test]
+    fn generate_generic_impl_includes_fulltext_columns() {
+        let input: DeriveInput = parse_quote! {
+            struct Article {
+                id: i32,
+                #[premix(fulltext)]
+                title: String,
+                #[premix(fulltext)]
+                body: String,
+                views: i32,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(
+            tokens.contains(r#"fulltext : vec ! ["title" . to_string () , "body" . to_string ()]"#)
+        );
+    }
+
+    #[test]
+    fn generate_scopes_builds_extension_trait() {
+        let input: DeriveInput = parse_quote! {
+            #[premix(custom_hooks, scope(active = "status = 'active'", adults = "age >= 18"))]
+            pub struct User {
+                id: i32,
+                status: String,
+                age: i32,
+            }
+        };
+        assert!(has_premix_flag(&input.attrs, "custom_hooks"));
+        let tokens = generate_scopes(&input).unwrap().to_string();
+        assert!(tokens.contains("pub trait UserScopes"));
+        assert!(tokens.contains("fn active (self) -> Self"));
+        assert!(tokens.contains(r#"self . filter_sql ("age >= 18""#));
+
+        let duplicate: DeriveInput = parse_quote! {
+            #[premix(scope(active = "a = 1", active = "a = 2"))]
+            struct User { id: i32 }
+        };
+        assert!(generate_scopes(&duplicate).is_err());
+    }
+
+    #[test]
+    fn generate_scopes_rejects_placeholders() {
+        for sql in ["age >= ?", "age >= $1"] {
+            let input: DeriveInput = parse_quote! {
+                #[premix(scope(adults = #sql))]
+                struct User { id: i32 }
+            };
+            let err = generate_scopes(&input).unwrap_err();
+            assert!(err.to_string().contains("bind placeholders"));
+        }
+
+        let quoted: DeriveInput = parse_quote! {
+            #[premix(scope(asked = "title LIKE '%?'"))]
+            struct Post { id: i32 }
+        };
+        assert!(generate_scopes(&quoted).is_ok());
+    }
+
     #[test]
     fn generate_generic_impl_includes_sensitive_fields() {
         let input: DeriveInput = parse_quote! {
@@ -1863,6 +2437,9 @@ mod tests {
         let tokens = generate_generic_impl(&input).unwrap().to_string();
         assert!(tokens.contains("\"user_id\""));
         assert!(tokens.contains("\"is_active\""));
+        assert!(tokens.contains(
+            "const COLUMNS : & 'static [& 'static str] = & [\"id\" , \"user_id\" , \"is_active\"]"
+        ));
     }
 
     #[test]
@@ -1878,4 +2455,205 @@ mod tests {
         assert!(tokens.contains("pub const ID : & str = \"id\""));
         assert!(tokens.contains("pub const NAME : & str = \"name\""));
     }
+
+    #[test]
+    fn generate_generic_impl_creates_typed_columns() {
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                id: i32,
+                nickname: Option<String>,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(tokens.contains(
+            "pub const ID : premix_orm :: Column < i32 > = premix_orm :: Column :: new (\"id\")"
+        ));
+        assert!(tokens.contains("pub const NICKNAME : premix_orm :: Column < String >"));
+    }
+
+    #[test]
+    fn generate_generic_impl_honors_table_attribute() {
+        let input: DeriveInput = parse_quote! {
+            #[premix(table = "people")]
+            struct Person {
+                id: i32,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("const TABLE_NAME : & 'static str = \"people\""));
+        assert!(!tokens.contains("persons"));
+    }
+
+    #[test]
+    fn generate_generic_impl_maps_renamed_columns() {
+        let input: DeriveInput = parse_quote! {
+            struct Account {
+                id: i32,
+                #[premix(column = "userName", sensitive)]
+                user_name: String,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("pub const USER_NAME : & str = \"userName\""));
+        assert!(tokens.contains("user_name : row . try_get (\"userName\") ?"));
+        assert!(tokens.contains("name : \"userName\" . to_string ()"));
+        assert!(tokens.contains("& [\"userName\"]"));
+        assert!(!tokens.contains("\"user_name\""));
+    }
+
+    #[test]
+    fn generate_generic_impl_rejects_bad_column_renames() {
+        for input in [
+            parse_quote! {
+                struct Account {
+                    #[premix(column = "the version")]
+                    version: i32,
+                }
+            },
+            parse_quote! {
+                struct Account {
+                    id: i32,
+                    #[premix(column = "user name")]
+                    name: String,
+                }
+            },
+            parse_quote! {
+                struct Account {
+                    id: i32,
+                    name: String,
+                    #[premix(column = "name")]
+                    alias: String,
+                }
+            },
+        ] {
+            let input: DeriveInput = input;
+            assert!(generate_generic_impl(&input).is_err());
+        }
+    }
+
+    #[test]
+    fn generate_generic_impl_uses_custom_primary_key() {
+        let input: DeriveInput = parse_quote! {
+            struct Session {
+                #[premix(primary_key, column = "token")]
+                key: String,
+                user_id: i32,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("type Id = String"));
+        assert!(tokens.contains("const PRIMARY_KEY : & 'static str = \"token\""));
+        assert!(tokens.contains("self . key"));
+        assert!(!tokens.contains("self . id"));
+    }
+
+    #[test]
+    fn generate_generic_impl_rejects_bad_primary_keys() {
+        for input in [
+            parse_quote! {
+                struct Session {
+                    token: String,
+                }
+            },
+            parse_quote! {
+                struct Session {
+                    id: i32,
+                    #[premix(primary_key, ignore)]
+                    token: String,
+                }
+            },
+        ] {
+            let input: DeriveInput = input;
+            assert!(generate_generic_impl(&input).is_err());
+        }
+    }
+
+    #[test]
+    fn generate_generic_impl_supports_composite_keys() {
+        let input: DeriveInput = parse_quote! {
+            struct Membership {
+                #[premix(primary_key)]
+                user_id: i32,
+                #[premix(primary_key)]
+                group_id: i64,
+                role: String,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("type Id = (i32 , i64)"));
+        assert!(tokens.contains("& [\"user_id\" , \"group_id\"]"));
+        assert!(tokens.contains(
+            "\"PRIMARY KEY ({})\" , premix_orm :: build_column_list :: < DB > (& [\"user_id\" , \"group_id\"])"
+        ));
+        assert!(tokens.contains(". bind (id . 0) . bind (id . 1)"));
+    }
+
+    #[test]
+    fn generate_generic_impl_manages_timestamps() {
+        let input: DeriveInput = parse_quote! {
+            struct Post {
+                id: i32,
+                created_at: DateTime<Utc>,
+                #[premix(updated_at, column = "modified")]
+                touched: Option<NaiveDateTime>,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("self . created_at = now ;"));
+        assert!(tokens.contains(
+            "self . touched = :: std :: option :: Option :: Some (now . naive_utc ()) ;"
+        ));
+        assert!(tokens.contains("fn touch_updated_at ()"));
+        assert!(tokens.contains(
+            "Some ((\"modified\" , premix_orm :: BindValue :: from (now . naive_utc ())))"
+        ));
+
+        let input: DeriveInput = parse_quote! {
+            struct Post {
+                id: i32,
+                created_at: String,
+                updated_at: DateTime<Local>,
+            }
+        };
+        let tokens = generate_generic_impl(&input).unwrap().to_string();
+        assert!(!tokens.contains("chrono :: Utc :: now"));
+        assert!(!tokens.contains("fn touch_updated_at"));
+    }
+
+    #[test]
+    fn generate_generic_impl_rejects_bad_timestamps() {
+        for input in [
+            parse_quote! {
+                struct Post {
+                    id: i32,
+                    #[premix(created_at)]
+                    born: i64,
+                }
+            },
+            parse_quote! {
+                struct Post {
+                    id: i32,
+                    #[premix(updated_at)]
+                    first: NaiveDateTime,
+                    #[premix(updated_at)]
+                    second: NaiveDateTime,
+                }
+            },
+        ] {
+            let input: DeriveInput = input;
+            assert!(generate_generic_impl(&input).is_err());
+        }
+    }
+
+    #[test]
+    fn generate_generic_impl_rejects_invalid_table_name() {
+        let input: DeriveInput = parse_quote! {
+            #[premix(table = "people; DROP")]
+            struct Person {
+                id: i32,
+            }
+        };
+        let err = generate_generic_impl(&input).unwrap_err();
+        assert!(err.to_string().contains("table name may only contain"));
+    }
 }
@@ -0,0 +1,16 @@
+//! The table naming strategy for the crate being compiled; the rules themselves
+//! live in `premix_core::naming`, shared with the CLI.
+
+use premix_core::NamingStrategy;
+use std::path::Path;
+
+/// The strategy configured in the compiling crate's `[package.metadata.premix]`,
+/// or `Legacy` when none is set.
+pub(crate) fn for_current_crate() -> syn::Result<NamingStrategy> {
+    let Ok(dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+        return Ok(NamingStrategy::Legacy);
+    };
+    let manifest = std::fs::read_to_string(Path::new(&dir).join("Cargo.toml")).unwrap_or_default();
+    NamingStrategy::from_manifest(&manifest)
+        .map_err(|message| syn::Error::new(proc_macro2::Span::call_site(), message))
+}
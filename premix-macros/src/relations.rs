@@ -1,7 +1,9 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
-use syn::{DeriveInput, Token};
+use syn::{DeriveInput, Field, Token};
+
+use premix_core::NamingStrategy;
 
 struct RelationArgs {
     model: Ident,
@@ -39,14 +41,28 @@ pub fn impl_relations(input: &DeriveInput) -> syn::Result<TokenStream> {
     let mut methods = TokenStream::new();
     let mut relation_consts = TokenStream::new();
     let reserved_names = belongs_to_method_names(&input.attrs);
+    let naming = crate::naming::for_current_crate()?;
+    let fields = named_fields(input);
 
     for attr in &input.attrs {
         if attr.path().is_ident("has_many") {
             let args = attr.parse_args::<RelationArgs>()?;
-            methods.extend(generate_has_many(struct_name, &args.model));
+            let pk_field = has_many_key(input, attr)?;
+            methods.extend(generate_has_many(
+                struct_name,
+                &args.model,
+                naming,
+                pk_field,
+            ));
         } else if attr.path().is_ident("belongs_to") {
             let args = attr.parse_args::<RelationArgs>()?;
-            methods.extend(generate_belongs_to(struct_name, &args.model));
+            let fk_ty = field_type(fields, &naming.foreign_key(&args.model.to_string()));
+            methods.extend(generate_belongs_to(
+                struct_name,
+                &args.model,
+                naming,
+                &fk_ty,
+            ));
         }
     }
 
@@ -57,23 +73,36 @@ pub fn impl_relations(input: &DeriveInput) -> syn::Result<TokenStream> {
             let field_name = field.ident.as_ref().unwrap();
             for attr in &field.attrs {
                 if attr.path().is_ident("has_many") {
+                    let args = attr.parse_args::<RelationArgs>()?;
                     let relation_name = field_name.to_string();
+                    let pk_column = crate::field_column_name(has_many_key(input, attr)?)?;
+                    // posts.user_id = users.id
+                    let child = &args.model;
+                    let fk = naming.foreign_key(&struct_name.to_string());
                     relation_consts.extend(quote! {
                         #[allow(non_upper_case_globals)]
                         pub const #field_name: premix_orm::Relation =
-                            premix_orm::Relation::new(#relation_name);
+                            premix_orm::Relation::with_model_join::<#child>(#relation_name, #fk, #pk_column);
                     });
                 } else if attr.path().is_ident("belongs_to") {
+                    let args = attr.parse_args::<RelationArgs>()?;
                     let relation_name = field_name.to_string();
                     let const_ident = if reserved_names.contains(&relation_name) {
                         format_ident!("{}_rel", field_name)
                     } else {
                         field_name.clone()
                     };
+                    // users.id = posts.user_id
+                    let parent = &args.model;
+                    let fk = naming.foreign_key(&parent.to_string());
                     relation_consts.extend(quote! {
                         #[allow(non_upper_case_globals)]
                         pub const #const_ident: premix_orm::Relation =
-                            premix_orm::Relation::new(#relation_name);
+                            premix_orm::Relation::with_model_join::<#parent>(
+                                #relation_name,
+                                <#parent as premix_orm::ModelSchema>::PRIMARY_KEY,
+                                #fk,
+                            );
                     });
                 }
             }
@@ -92,6 +121,17 @@ pub fn impl_relations(input: &DeriveInput) -> syn::Result<TokenStream> {
     }
 }
 
+/// The key a `has_many` relation matches children against, which must be a single column.
+fn has_many_key<'a>(input: &'a DeriveInput, attr: &syn::Attribute) -> syn::Result<&'a Field> {
+    match crate::primary_key_fields(input)?.as_slice() {
+        [field] => Ok(field),
+        _ => Err(syn::Error::new_spanned(
+            attr,
+            "has_many needs a single-column primary key",
+        )),
+    }
+}
+
 fn belongs_to_method_names(attrs: &[syn::Attribute]) -> Vec<String> {
     let mut names = Vec::new();
     for attr in attrs {
@@ -105,10 +145,39 @@ fn belongs_to_method_names(attrs: &[syn::Attribute]) -> Vec<String> {
     names
 }
 
-fn generate_has_many(parent: &Ident, child: &Ident) -> TokenStream {
+/// The named fields of a struct, or none for other inputs.
+fn named_fields(input: &DeriveInput) -> Option<&syn::punctuated::Punctuated<Field, Token![,]>> {
+    match &input.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => Some(&fields.named),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The type of the field called `name`, or `i32` when there is no such field.
+pub fn field_type(
+    fields: Option<&syn::punctuated::Punctuated<Field, Token![,]>>,
+    name: &str,
+) -> syn::Type {
+    fields
+        .into_iter()
+        .flatten()
+        .find(|f| f.ident.as_ref().is_some_and(|ident| ident == name))
+        .map_or_else(|| syn::parse_quote!(i32), |f| f.ty.clone())
+}
+
+fn generate_has_many(
+    parent: &Ident,
+    child: &Ident,
+    naming: NamingStrategy,
+    pk_field: &Field,
+) -> TokenStream {
     let method_name = format_ident!("{}s_lazy", child.to_string().to_lowercase());
-    let child_table = format!("{}s", child.to_string().to_lowercase());
-    let fk = format!("{}_id", parent.to_string().to_lowercase());
+    let fk = naming.foreign_key(&parent.to_string());
+    let pk_ident = &pk_field.ident;
+    let pk_ty = &pk_field.ty;
 
     quote! {
         pub async fn #method_name<'e, E, DB>(&self, executor: E) -> Result<Vec<#child>, premix_orm::sqlx::Error>
@@ -119,26 +188,37 @@ fn generate_has_many(parent: &Ident, child: &Ident) -> TokenStream {
             #child: premix_orm::Model<DB>,
             for<'q> <DB as premix_orm::sqlx::Database>::Arguments<'q>: premix_orm::sqlx::IntoArguments<'q, DB>,
             for<'c> &'c mut <DB as premix_orm::sqlx::Database>::Connection: premix_orm::sqlx::Executor<'c, Database = DB>,
-            i32: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB>,
+            #pk_ty: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB>,
         {
             let mut executor = executor.into_executor();
             static SQL: std::sync::OnceLock<String> = std::sync::OnceLock::new();
             let sql = SQL.get_or_init(|| {
                 let p = <DB as premix_orm::SqlDialect>::placeholder(1);
-                format!("SELECT * FROM {} WHERE {} = {}", #child_table, #fk, p)
+                format!(
+                    "SELECT * FROM {} WHERE {} = {}",
+                    <DB as premix_orm::SqlDialect>::quote_identifier(
+                        <#child as premix_orm::ModelSchema>::TABLE_NAME
+                    ),
+                    <DB as premix_orm::SqlDialect>::quote_identifier(#fk),
+                    p
+                )
             });
             let query = premix_orm::sqlx::query_as::<DB, #child>(sql.as_str())
                 .persistent(true)
-                .bind(self.id);
+                .bind(::std::clone::Clone::clone(&self.#pk_ident));
             executor.fetch_all(query).await
         }
     }
 }
 
-fn generate_belongs_to(child: &Ident, parent: &Ident) -> TokenStream {
+fn generate_belongs_to(
+    child: &Ident,
+    parent: &Ident,
+    naming: NamingStrategy,
+    fk_ty: &syn::Type,
+) -> TokenStream {
     let method_name = format_ident!("{}", parent.to_string().to_lowercase());
-    let parent_table = format!("{}s", parent.to_string().to_lowercase());
-    let fk = format_ident!("{}_id", parent.to_string().to_lowercase());
+    let fk = format_ident!("{}", naming.foreign_key(&parent.to_string()));
 
     quote! {
         pub async fn #method_name<'e, E, DB>(&self, executor: E) -> Result<Option<#parent>, premix_orm::sqlx::Error>
@@ -149,17 +229,26 @@ fn generate_belongs_to(child: &Ident, parent: &Ident) -> TokenStream {
             #parent: premix_orm::Model<DB>,
             for<'q> <DB as premix_orm::sqlx::Database>::Arguments<'q>: premix_orm::sqlx::IntoArguments<'q, DB>,
             for<'c> &'c mut <DB as premix_orm::sqlx::Database>::Connection: premix_orm::sqlx::Executor<'c, Database = DB>,
-            i32: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB>,
+            #fk_ty: premix_orm::sqlx::Type<DB> + for<'q> premix_orm::sqlx::Encode<'q, DB>,
         {
             let mut executor = executor.into_executor();
             static SQL: std::sync::OnceLock<String> = std::sync::OnceLock::new();
             let sql = SQL.get_or_init(|| {
                 let p = <DB as premix_orm::SqlDialect>::placeholder(1);
-                format!("SELECT * FROM {} WHERE id = {}", #parent_table, p)
+                format!(
+                    "SELECT * FROM {} WHERE {} = {}",
+                    <DB as premix_orm::SqlDialect>::quote_identifier(
+                        <#parent as premix_orm::ModelSchema>::TABLE_NAME
+                    ),
+                    <DB as premix_orm::SqlDialect>::quote_identifier(
+                        <#parent as premix_orm::ModelSchema>::PRIMARY_KEY
+                    ),
+                    p
+                )
             });
             let query = premix_orm::sqlx::query_as::<DB, #parent>(sql.as_str())
                 .persistent(true)
-                .bind(self.#fk);
+                .bind(::std::clone::Clone::clone(&self.#fk));
             executor.fetch_optional(query).await
         }
     }
@@ -169,6 +258,7 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
     let parent_struct = &input.ident;
     let mut arms = TokenStream::new();
     let mut relation_names: Vec<String> = Vec::new();
+    let naming = crate::naming::for_current_crate()?;
 
     if let syn::Data::Struct(data) = &input.data
         && let syn::Fields::Named(fields) = &data.fields
@@ -181,24 +271,29 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                     let args = attr.parse_args::<RelationArgs>()?;
                     let child_model = args.model;
                     let relation_name = field_name.as_ref().unwrap().to_string();
+                    let pk_field = has_many_key(input, attr)?;
+                    let pk_ident = &pk_field.ident;
+                    let pk_ty = &pk_field.ty;
                     relation_names.push(relation_name.clone());
 
-                    let child_table = format!("{}s", child_model.to_string().to_lowercase());
-                    let parent_fk_str = format!("{}_id", parent_struct.to_string().to_lowercase());
+                    let parent_fk_str = naming.foreign_key(&parent_struct.to_string());
                     let parent_fk_ident = format_ident!("{}", parent_fk_str);
 
                     arms.extend(quote! {
                             #relation_name => {
-                                let mut ids: Vec<i32> = models.iter().map(|m| m.id).collect();
+                                let mut ids: Vec<#pk_ty> = models
+                                    .iter()
+                                    .map(|m| ::std::clone::Clone::clone(&m.#pk_ident))
+                                    .collect();
                                 if ids.is_empty() { return Ok(()); }
                                 ids.sort_unstable();
                                 ids.dedup();
 
                                 const GROUP_THRESHOLD: usize = 128;
                                 let use_hash_map = ids.len() > GROUP_THRESHOLD;
-                                let mut grouped: Vec<(i32, Vec<#child_model>)> =
+                                let mut grouped: Vec<(#pk_ty, Vec<#child_model>)> =
                                     Vec::with_capacity(models.len());
-                                let mut grouped_map: Option<::std::collections::HashMap<i32, Vec<#child_model>>> = None;
+                                let mut grouped_map: Option<::std::collections::HashMap<#pk_ty, Vec<#child_model>>> = None;
                                 let mut children_all: Vec<#child_model> = Vec::new();
 
                                 const CHUNK_SIZE: usize = 500;
@@ -206,21 +301,23 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                                     let params = premix_orm::cached_placeholders::<DB>(chunk.len());
                                     let sql = format!(
                                         "SELECT * FROM {} WHERE {} IN ({})",
-                                        #child_table,
-                                        #parent_fk_str,
+                                        <DB as premix_orm::SqlDialect>::quote_identifier(
+                                            <#child_model as premix_orm::ModelSchema>::TABLE_NAME
+                                        ),
+                                        <DB as premix_orm::SqlDialect>::quote_identifier(#parent_fk_str),
                                         params
                                     );
                                     let mut query = premix_orm::sqlx::query_as::<DB, #child_model>(&sql)
                                         .persistent(true);
                                     for id in chunk {
-                                        query = query.bind(*id);
+                                        query = query.bind(::std::clone::Clone::clone(id));
                                     }
                                     let children = executor.fetch_all(query).await?;
                                     if use_hash_map {
                                         let map = grouped_map
                                             .get_or_insert_with(|| ::std::collections::HashMap::with_capacity(ids.len()));
                                         for child in children {
-                                            let fk = child.#parent_fk_ident;
+                                            let fk = ::std::clone::Clone::clone(&child.#parent_fk_ident);
                                             map.entry(fk).or_insert_with(Vec::new).push(child);
                                         }
                                     } else {
@@ -229,9 +326,9 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                                 }
 
                                 if !use_hash_map {
-                                    children_all.sort_by_key(|child| child.#parent_fk_ident);
+                                    children_all.sort_by(|a, b| a.#parent_fk_ident.cmp(&b.#parent_fk_ident));
                                     for child in children_all {
-                                        let fk = child.#parent_fk_ident;
+                                        let fk = ::std::clone::Clone::clone(&child.#parent_fk_ident);
                                         if let Some(last) = grouped.last_mut() {
                                             if last.0 == fk {
                                                 last.1.push(child);
@@ -245,7 +342,7 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                                 for model in models.iter_mut() {
                                     if use_hash_map {
                                         if let Some(map) = grouped_map.as_mut() {
-                                            if let Some(children) = map.remove(&model.id) {
+                                            if let Some(children) = map.remove(&model.#pk_ident) {
                                                 model.#field_name = Some(children);
                                             } else {
                                                 model.#field_name = Some(Vec::new());
@@ -254,8 +351,8 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                                             model.#field_name = Some(Vec::new());
                                         }
                                     } else {
-                                        // Binary search for model.id in sorted grouped Vec
-                                        if let Ok(idx) = grouped.binary_search_by_key(&model.id, |item| item.0) {
+                                        // Binary search for the model's key in sorted grouped Vec
+                                        if let Ok(idx) = grouped.binary_search_by(|item| item.0.cmp(&model.#pk_ident)) {
                                             // Move Vec directly instead of cloning
                                             let children = std::mem::take(&mut grouped[idx].1);
                                             model.#field_name = Some(children);
@@ -271,43 +368,52 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                     let parent_model = args.model;
                     let relation_name = field_name.as_ref().unwrap().to_string();
                     relation_names.push(relation_name.clone());
-                    let parent_table = format!("{}s", parent_model.to_string().to_lowercase());
-                    let fk_str = format!("{}_id", parent_model.to_string().to_lowercase());
+                    let fk_str = naming.foreign_key(&parent_model.to_string());
                     let fk_ident = format_ident!("{}", fk_str);
+                    let fk_ty = field_type(Some(&fields.named), &fk_str);
 
                     arms.extend(quote! {
                             #relation_name => {
-                                let mut ids: Vec<i32> = models.iter().map(|m| m.#fk_ident).collect();
+                                let mut ids: Vec<#fk_ty> = models
+                                    .iter()
+                                    .map(|m| ::std::clone::Clone::clone(&m.#fk_ident))
+                                    .collect();
                                 if ids.is_empty() { return Ok(()); }
                                 ids.sort_unstable();
                                 ids.dedup();
 
                                 const GROUP_THRESHOLD: usize = 128;
                                 let use_hash_map = ids.len() > GROUP_THRESHOLD;
-                                let mut grouped: Vec<(i32, Option<#parent_model>)> =
+                                let mut grouped: Vec<(#fk_ty, Option<#parent_model>)> =
                                     Vec::with_capacity(ids.len());
-                                let mut grouped_map: Option<::std::collections::HashMap<i32, Option<#parent_model>>> = None;
+                                let mut grouped_map: Option<::std::collections::HashMap<#fk_ty, Option<#parent_model>>> = None;
                                 let mut parents_all: Vec<#parent_model> = Vec::new();
 
                                 const CHUNK_SIZE: usize = 500;
                                 for chunk in ids.chunks(CHUNK_SIZE) {
                                     let params = premix_orm::cached_placeholders::<DB>(chunk.len());
                                     let sql = format!(
-                                        "SELECT * FROM {} WHERE id IN ({})",
-                                        #parent_table,
+                                        "SELECT * FROM {} WHERE {} IN ({})",
+                                        <DB as premix_orm::SqlDialect>::quote_identifier(
+                                            <#parent_model as premix_orm::ModelSchema>::TABLE_NAME
+                                        ),
+                                        <DB as premix_orm::SqlDialect>::quote_identifier(
+                                            <#parent_model as premix_orm::ModelSchema>::PRIMARY_KEY
+                                        ),
                                         params
                                     );
                                     let mut query = premix_orm::sqlx::query_as::<DB, #parent_model>(&sql)
                                         .persistent(true);
                                     for id in chunk {
-                                        query = query.bind(*id);
+                                        query = query.bind(::std::clone::Clone::clone(id));
                                     }
                                     let parents = executor.fetch_all(query).await?;
                                     if use_hash_map {
                                         let map = grouped_map
                                             .get_or_insert_with(|| ::std::collections::HashMap::with_capacity(ids.len()));
                                         for parent in parents {
-                                            map.entry(parent.id).or_insert(Some(parent));
+                                            let key = premix_orm::Model::<DB>::primary_key_value(&parent);
+                                            map.entry(key).or_insert(Some(parent));
                                         }
                                     } else {
                                         parents_all.extend(parents);
@@ -315,14 +421,15 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                                 }
 
                                 if !use_hash_map {
-                                    parents_all.sort_by_key(|parent| parent.id);
+                                    parents_all.sort_by_key(|parent| premix_orm::Model::<DB>::primary_key_value(parent));
                                     for parent in parents_all {
+                                        let key = premix_orm::Model::<DB>::primary_key_value(&parent);
                                         if let Some(last) = grouped.last() {
-                                            if last.0 == parent.id {
+                                            if last.0 == key {
                                                 continue;
                                             }
                                         }
-                                        grouped.push((parent.id, Some(parent)));
+                                        grouped.push((key, Some(parent)));
                                     }
                                 }
 
@@ -339,7 +446,7 @@ pub fn generate_eager_load_body(input: &DeriveInput) -> syn::Result<TokenStream>
                                         }
                                     } else {
                                         // Binary search for model's foreign key in sorted grouped Vec
-                                        if let Ok(idx) = grouped.binary_search_by_key(&model.#fk_ident, |item| item.0) {
+                                        if let Ok(idx) = grouped.binary_search_by(|item| item.0.cmp(&model.#fk_ident)) {
                                             // Move value instead of cloning
                                             model.#field_name = grouped[idx].1.take();
                                         } else {
@@ -459,7 +566,8 @@ mod tests {
         let tokens = generate_eager_load_body(&input).unwrap().to_string();
         assert!(tokens.contains("user"));
         assert!(tokens.contains("user_id"));
-        assert!(tokens.contains("WHERE id IN"));
+        assert!(tokens.contains("WHERE {} IN"));
+        assert!(tokens.contains("< User as premix_orm :: ModelSchema > :: PRIMARY_KEY"));
     }
 
     #[test]
@@ -500,5 +608,21 @@ mod tests {
         let tokens = impl_relations(&input).unwrap().to_string();
         assert!(tokens.contains("user"));
         assert!(tokens.contains("Relation"));
+        assert!(tokens.contains(
+            r#"with_model_join :: < User > ("user" , < User as premix_orm :: ModelSchema > :: PRIMARY_KEY , "user_id" ,)"#
+        ));
+    }
+
+    #[test]
+    fn impl_relations_has_many_const_carries_join_keys() {
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                id: i32,
+                #[has_many(Post)]
+                posts: Option<Vec<Post>>,
+            }
+        };
+        let tokens = impl_relations(&input).unwrap().to_string();
+        assert!(tokens.contains(r#"with_model_join :: < Post > ("posts" , "user_id" , "id")"#));
     }
 }
@@ -203,31 +203,47 @@ impl Parse for StaticQueryInput {
 /// Generate the compile-time query code
 pub fn generate_static_query(input: StaticQueryInput) -> TokenStream {
     let model = input.model.clone();
-    let table_name = format!("{}s", model.to_string().to_lowercase());
 
     match input.operation {
-        QueryOperation::Select => generate_select_query(&model, &table_name, &input, false),
+        QueryOperation::Select => generate_select_query(&model, &input, false),
         QueryOperation::Find => {
             let mut find_input = input;
             if find_input.limit.is_none() {
                 find_input.limit = Some(1);
             }
-            generate_select_query(&model, &table_name, &find_input, true)
+            generate_select_query(&model, &find_input, true)
         }
-        QueryOperation::Insert => generate_insert_query(&model, &table_name, &input),
-        QueryOperation::Update => generate_update_query(&model, &table_name, &input),
-        QueryOperation::Delete => generate_delete_query(&model, &table_name, &input),
+        QueryOperation::Insert => generate_insert_query(&model, &input),
+        QueryOperation::Update => generate_update_query(&model, &input),
+        QueryOperation::Delete => generate_delete_query(&model, &input),
+    }
+}
+
+/// Defines `__PREMIX_SQL` as `head` + the model's table + `tail`.
+///
+/// The table comes from the model's `ModelSchema::TABLE_NAME` (it may be renamed
+/// with `#[premix(table = "...")]`, which this macro cannot see), and is joined
+/// in a constant so the SQL is still fixed at compile time.
+fn sql_const(model: &Ident, head: &str, tail: &str) -> TokenStream {
+    quote! {
+        const __PREMIX_PARTS: &[&str] = &[
+            #head,
+            <#model as ::premix_orm::ModelSchema>::TABLE_NAME,
+            #tail,
+        ];
+        const __PREMIX_BYTES: [u8; ::premix_orm::__private::concat_len(__PREMIX_PARTS)] =
+            ::premix_orm::__private::concat_bytes(__PREMIX_PARTS);
+        const __PREMIX_SQL: &str = ::premix_orm::__private::str_from_utf8(&__PREMIX_BYTES);
     }
 }
 
 fn generate_select_query(
     model: &Ident,
-    table_name: &str,
     input: &StaticQueryInput,
     force_limit_one: bool,
 ) -> TokenStream {
-    // Build SQL string at compile time
-    let mut sql = format!("SELECT * FROM {}", table_name);
+    // Build SQL string at compile time (everything after the table name)
+    let mut sql = String::new();
 
     // Collect bind values
     let mut bind_exprs: Vec<&Expr> = Vec::new();
@@ -268,10 +284,11 @@ fn generate_select_query(
     });
 
     // Output: sqlx::query_as with static SQL string
+    let sql_const = sql_const(model, "SELECT * FROM ", &sql);
     quote! {
         {
             // SQL generated at compile time - Zero Overhead!
-            const __PREMIX_SQL: &str = #sql;
+            #sql_const
             ::premix_orm::sqlx::query_as::<_, #model>(__PREMIX_SQL)
                 .persistent(true)
                 #(#binds)*
@@ -279,25 +296,23 @@ fn generate_select_query(
     }
 }
 
-fn generate_insert_query(model: &Ident, table_name: &str, input: &StaticQueryInput) -> TokenStream {
+fn generate_insert_query(model: &Ident, input: &StaticQueryInput) -> TokenStream {
     let mut cols = Vec::new();
     let mut vals = Vec::new();
     let mut bind_exprs: Vec<&Expr> = Vec::new();
-    let mut placeholder_index = 1;
 
-    for assignment in &input.assignments {
+    for (placeholder_index, assignment) in (1..).zip(input.assignments.iter()) {
         cols.push(assignment.column.clone());
         vals.push(format!("${}", placeholder_index));
         bind_exprs.push(&assignment.value);
-        placeholder_index += 1;
     }
 
     let sql = format!(
-        "INSERT INTO {} ({}) VALUES ({}) RETURNING *",
-        table_name,
+        " ({}) VALUES ({}) RETURNING *",
         cols.join(", "),
         vals.join(", ")
     );
+    let sql_const = sql_const(model, "INSERT INTO ", &sql);
 
     let binds = bind_exprs.iter().map(|expr| {
         quote! { .bind(#expr) }
@@ -305,7 +320,7 @@ fn generate_insert_query(model: &Ident, table_name: &str, input: &StaticQueryInp
 
     quote! {
         {
-            const __PREMIX_SQL: &str = #sql;
+            #sql_const
             ::premix_orm::sqlx::query_as::<_, #model>(__PREMIX_SQL)
                 .persistent(true)
                 #(#binds)*
@@ -313,8 +328,8 @@ fn generate_insert_query(model: &Ident, table_name: &str, input: &StaticQueryInp
     }
 }
 
-fn generate_update_query(model: &Ident, table_name: &str, input: &StaticQueryInput) -> TokenStream {
-    let mut sql = format!("UPDATE {} SET ", table_name);
+fn generate_update_query(model: &Ident, input: &StaticQueryInput) -> TokenStream {
+    let mut sql = String::from(" SET ");
     let mut bind_exprs: Vec<&Expr> = Vec::new();
     let mut placeholder_index = 1;
 
@@ -353,11 +368,12 @@ fn generate_update_query(model: &Ident, table_name: &str, input: &StaticQueryInp
     let binds = bind_exprs.iter().map(|expr| {
         quote! { .bind(#expr) }
     });
+    let sql_const = sql_const(model, "UPDATE ", &sql);
 
     if input.returning_all {
         quote! {
             {
-                const __PREMIX_SQL: &str = #sql;
+                #sql_const
                 ::premix_orm::sqlx::query_as::<_, #model>(__PREMIX_SQL)
                     .persistent(true)
                     #(#binds)*
@@ -366,7 +382,7 @@ fn generate_update_query(model: &Ident, table_name: &str, input: &StaticQueryInp
     } else {
         quote! {
             {
-                const __PREMIX_SQL: &str = #sql;
+                #sql_const
                 ::premix_orm::sqlx::query(__PREMIX_SQL)
                     .persistent(true)
                     #(#binds)*
@@ -375,8 +391,8 @@ fn generate_update_query(model: &Ident, table_name: &str, input: &StaticQueryInp
     }
 }
 
-fn generate_delete_query(model: &Ident, table_name: &str, input: &StaticQueryInput) -> TokenStream {
-    let mut sql = format!("DELETE FROM {}", table_name);
+fn generate_delete_query(model: &Ident, input: &StaticQueryInput) -> TokenStream {
+    let mut sql = String::new();
     let mut bind_exprs: Vec<&Expr> = Vec::new();
     let mut placeholder_index = 1;
 
@@ -403,11 +419,12 @@ fn generate_delete_query(model: &Ident, table_name: &str, input: &StaticQueryInp
     let binds = bind_exprs.iter().map(|expr| {
         quote! { .bind(#expr) }
     });
+    let sql_const = sql_const(model, "DELETE FROM ", &sql);
 
     if input.returning_all {
         quote! {
             {
-                const __PREMIX_SQL: &str = #sql;
+                #sql_const
                 ::premix_orm::sqlx::query_as::<_, #model>(__PREMIX_SQL)
                     .persistent(true)
                     #(#binds)*
@@ -416,7 +433,7 @@ fn generate_delete_query(model: &Ident, table_name: &str, input: &StaticQueryInp
     } else {
         quote! {
             {
-                const __PREMIX_SQL: &str = #sql;
+                #sql_const
                 ::premix_orm::sqlx::query(__PREMIX_SQL)
                     .persistent(true)
                     #(#binds)*
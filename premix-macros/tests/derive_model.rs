@@ -35,7 +35,7 @@ fn derive_model_generates_metadata() {
     assert_eq!(<User as PremixModel<Sqlite>>::table_name(), "users");
     assert!(
         <User as PremixModel<Sqlite>>::create_table_sql()
-            .contains("CREATE TABLE IF NOT EXISTS users")
+            .contains("CREATE TABLE IF NOT EXISTS `users`")
     );
     assert!(<User as PremixModel<Sqlite>>::list_columns().contains(&"name".to_string()));
     assert!(<User as PremixModel<Sqlite>>::list_columns().contains(&"deleted_at".to_string()));
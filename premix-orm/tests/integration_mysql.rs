@@ -202,10 +202,48 @@ async fn mysql_filter_like_in() {
     assert_eq!(in_filter.len(), 1);
 }
 
+#[tokio::test]
+async fn mysql_update_and_delete_returning() {
+    let pool = match get_mysql_pool().await {
+        Some(pool) => pool,
+        None => return,
+    };
+    let table = <MyUser as Model<sqlx::MySql>>::table_name();
+    let drop_sql = format!("DROP TABLE IF EXISTS `{}`", table);
+    sqlx::query(&drop_sql).execute(&pool).await.ok();
+    Premix::sync::<sqlx::MySql, MyUser>(&pool)
+        .await
+        .expect("sync");
+
+    for name in ["Ann", "Bob", "Cara"] {
+        let mut user = MyUser {
+            id: 0,
+            name: name.to_string(),
+        };
+        user.save(&pool).await.expect("save");
+    }
+
+    let updated = MyUser::find_in_pool(&pool)
+        .filter_ne("name", "Cara")
+        .update_returning(json!({ "name": "Renamed" }))
+        .await
+        .expect("update_returning");
+    assert_eq!(updated.len(), 2);
+    assert!(updated.iter().all(|u| u.name == "Renamed"));
+
+    let deleted = MyUser::find_in_pool(&pool)
+        .filter_eq("name", "Cara")
+        .delete_returning()
+        .await
+        .expect("delete_returning");
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].name, "Cara");
+}
+
 async fn get_mysql_pool() -> Option<MySqlPool> {
     let db_url = std::env::var("DATABASE_URL").ok()?;
     if !db_url.starts_with("mysql://") {
         return None;
     }
-    Some(sqlx::MySqlPool::connect(&db_url).await.ok()?)
+    sqlx::MySqlPool::connect(&db_url).await.ok()
 }
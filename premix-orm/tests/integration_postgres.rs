@@ -49,6 +49,51 @@ async fn postgres_crud_smoke() {
     assert_eq!(deleted, 1);
 }
 
+#[derive(Model, Debug, Clone)]
+#[premix(table = "PgLedger")]
+struct PgLedger {
+    id: i32,
+    #[premix(column = "userName")]
+    user_name: String,
+    #[premix(column = "order")]
+    rank: i32,
+}
+
+#[tokio::test]
+async fn postgres_sync_keeps_mixed_case_and_reserved_names() {
+    let pool = match get_pg_pool().await {
+        Some(pool) => pool,
+        None => return,
+    };
+    sqlx::query(r#"DROP TABLE IF EXISTS "PgLedger""#)
+        .execute(&pool)
+        .await
+        .ok();
+    Premix::sync::<sqlx::Postgres, PgLedger>(&pool)
+        .await
+        .expect("sync");
+
+    let mut ledger = PgLedger {
+        id: 0,
+        user_name: "ada".to_string(),
+        rank: 2,
+    };
+    ledger.save(&pool).await.expect("save");
+    let (user_name, rank): (String, i32) =
+        sqlx::query_as(r#"SELECT "userName", "order" FROM "PgLedger" WHERE id = $1"#)
+            .bind(ledger.id)
+            .fetch_one(&pool)
+            .await
+            .expect("raw");
+    assert_eq!((user_name.as_str(), rank), ("ada", 2));
+    let found = PgLedger::find_in_pool(&pool)
+        .filter_eq("userName", "ada")
+        .all()
+        .await
+        .expect("all");
+    assert_eq!(found.len(), 1);
+}
+
 #[tokio::test]
 async fn postgres_filters_limit_offset_prepared() {
     let pool = match get_pg_pool().await {
@@ -240,5 +285,5 @@ async fn get_pg_pool() -> Option<PgPool> {
     if !db_url.starts_with("postgres://") && !db_url.starts_with("postgresql://") {
         return None;
     }
-    Some(sqlx::PgPool::connect(&db_url).await.ok()?)
+    sqlx::PgPool::connect(&db_url).await.ok()
 }
@@ -1,4 +1,5 @@
 use futures_util::StreamExt;
+use premix_orm::chrono::{DateTime, Utc};
 use premix_orm::prelude::*;
 use serde_json::json;
 use sqlx::Sqlite;
@@ -35,6 +36,30 @@ struct NullableUser {
     name: Option<String>,
 }
 
+#[derive(Model, Debug, Clone)]
+struct Profile {
+    id: i32,
+    settings: String,
+    tags: String,
+}
+
+#[derive(Model, Debug, Clone)]
+struct Article {
+    id: i32,
+    #[premix(fulltext)]
+    title: String,
+    #[premix(fulltext)]
+    body: String,
+}
+
+#[derive(Model, Debug, Clone)]
+#[premix(scope(open = "status = 'open'", urgent = "priority >= 3"))]
+struct Task {
+    id: i32,
+    status: String,
+    priority: i32,
+}
+
 #[derive(Model, Debug, Clone)]
 #[premix(custom_hooks)]
 struct HookUser {
@@ -49,10 +74,84 @@ struct SensitiveUser {
     password: String,
 }
 
+#[derive(Model, Debug, Clone)]
+#[premix(table = "people")]
+struct Person {
+    id: i32,
+    name: String,
+    #[has_many(Pet)]
+    #[premix(ignore)]
+    pets: Option<Vec<Pet>>,
+}
+
+#[derive(Model, Debug, Clone)]
+#[belongs_to(Person)]
+struct Pet {
+    id: i32,
+    person_id: i32,
+    name: String,
+}
+
+#[derive(Model, Debug, Clone)]
+struct LegacyAccount {
+    id: i32,
+    #[premix(column = "userName")]
+    user_name: String,
+    #[premix(column = "type", index)]
+    kind: String,
+    #[premix(column = "order")]
+    rank: i32,
+}
+
+#[derive(Model, Debug, Clone)]
+struct Tenant {
+    #[premix(primary_key)]
+    slug: String,
+    name: String,
+    #[has_many(Member)]
+    #[premix(ignore)]
+    members: Option<Vec<Member>>,
+}
+
+#[derive(Model, Debug, Clone)]
+#[belongs_to(Tenant)]
+struct Member {
+    id: i64,
+    tenant_id: String,
+    email: String,
+}
+
+#[derive(Model, Debug, Clone)]
+struct Invite {
+    id: i32,
+    tenant_id: String,
+    #[belongs_to(Tenant)]
+    #[premix(ignore)]
+    tenant: Option<Tenant>,
+}
+
+#[derive(Model, Debug, Clone)]
+struct Membership {
+    #[premix(primary_key)]
+    user_id: i32,
+    #[premix(primary_key)]
+    group_id: i32,
+    role: String,
+}
+
+#[derive(Model, Debug, Clone)]
+struct Note {
+    id: i32,
+    body: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
 static BEFORE_SAVE_COUNT: AtomicUsize = AtomicUsize::new(0);
 static AFTER_SAVE_COUNT: AtomicUsize = AtomicUsize::new(0);
 
 impl ModelHooks for HookUser {
+    #[allow(clippy::manual_async_fn)]
     fn before_save(&mut self) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send {
         async move {
             BEFORE_SAVE_COUNT.fetch_add(1, Ordering::SeqCst);
@@ -60,6 +159,7 @@ impl ModelHooks for HookUser {
         }
     }
 
+    #[allow(clippy::manual_async_fn)]
     fn after_save(&mut self) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send {
         async move {
             AFTER_SAVE_COUNT.fetch_add(1, Ordering::SeqCst);
@@ -221,6 +321,358 @@ async fn sqlite_premix_query_select() {
     assert!(fetched.is_some());
 }
 
+#[tokio::test]
+async fn sqlite_table_attribute_renames_table() {
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, Person>(&pool).await.expect("sync");
+    Premix::sync::<Sqlite, Pet>(&pool).await.expect("sync");
+    assert_eq!(<Person as ModelSchema>::TABLE_NAME, "people");
+
+    let mut person = Person {
+        id: 0,
+        name: "Ada".to_string(),
+        pets: None,
+    };
+    person.save(&pool).await.expect("save");
+    let mut pet = Pet {
+        id: 0,
+        person_id: person.id,
+        name: "Rex".to_string(),
+    };
+    pet.save(&pool).await.expect("save");
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM people")
+        .fetch_one(&pool)
+        .await
+        .expect("count");
+    assert_eq!(count, 1);
+
+    let people = Person::find_in_pool(&pool)
+        .include("pets")
+        .all()
+        .await
+        .expect("all");
+    assert_eq!(people[0].pets.as_ref().expect("pets").len(), 1);
+
+    let fetched: Option<Person> = premix_query!(Person, FIND, filter_eq("id", person.id))
+        .fetch_optional(&pool)
+        .await
+        .expect("fetch");
+    assert_eq!(fetched.expect("person").name, "Ada");
+}
+
+#[tokio::test]
+async fn sqlite_custom_primary_keys() {
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, Tenant>(&pool).await.expect("sync");
+    Premix::sync::<Sqlite, Member>(&pool).await.expect("sync");
+    Premix::sync::<Sqlite, Invite>(&pool).await.expect("sync");
+    assert_eq!(<Tenant as ModelSchema>::PRIMARY_KEY, "slug");
+    assert!(<Tenant as Model<Sqlite>>::create_table_sql().contains("`slug` TEXT PRIMARY KEY"));
+
+    let mut tenant = Tenant {
+        slug: "acme".to_string(),
+        name: "Acme".to_string(),
+        members: None,
+    };
+    tenant.save(&pool).await.expect("insert");
+    tenant.name = "Acme Corp".to_string();
+    tenant.save(&pool).await.expect("update");
+
+    let mut member = Member {
+        id: 0,
+        tenant_id: tenant.slug.clone(),
+        email: "ada@acme.test".to_string(),
+    };
+    member.save(&pool).await.expect("insert");
+    assert!(member.id > 0);
+
+    let found = Tenant::find_by_id(&pool, "acme".to_string())
+        .await
+        .expect("find")
+        .expect("row");
+    assert_eq!(found.name, "Acme Corp");
+    Tenant::update_by_id(&pool, "acme".to_string(), json!({ "name": "Acme Inc" }))
+        .await
+        .expect("update_by_id");
+    let by_member = Member::find_by_id(&pool, member.id)
+        .await
+        .expect("find")
+        .expect("row");
+    assert_eq!(
+        by_member
+            .tenant(&pool)
+            .await
+            .expect("lazy")
+            .expect("tenant")
+            .name,
+        "Acme Inc"
+    );
+
+    let tenants = Tenant::find_in_pool(&pool)
+        .include("members")
+        .all()
+        .await
+        .expect("all");
+    assert_eq!(
+        tenants[0].members.as_ref().expect("members")[0].id,
+        member.id
+    );
+    let mut invite = Invite {
+        id: 0,
+        tenant_id: tenant.slug.clone(),
+        tenant: None,
+    };
+    invite.save(&pool).await.expect("insert");
+    let invites = Invite::find_in_pool(&pool)
+        .include("tenant")
+        .all()
+        .await
+        .expect("all");
+    assert_eq!(invites[0].tenant.as_ref().expect("tenant").slug, "acme");
+}
+
+#[tokio::test]
+async fn sqlite_composite_primary_keys() {
+    use premix_orm::schema::{ModelSchema, diff_schema, introspect_sqlite_schema};
+
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, Membership>(&pool)
+        .await
+        .expect("sync");
+    let actual = introspect_sqlite_schema(&pool).await.expect("schema");
+    assert!(diff_schema(&[Membership::schema()], &actual).is_empty());
+
+    for (user_id, group_id, role) in [(1, 1, "owner"), (1, 2, "member"), (2, 1, "member")] {
+        let mut membership = Membership {
+            user_id,
+            group_id,
+            role: role.to_string(),
+        };
+        membership.save(&pool).await.expect("insert");
+    }
+    let mut membership = Membership::find_by_key(&pool, (1, 2))
+        .await
+        .expect("find")
+        .expect("row");
+    assert_eq!(membership.role, "member");
+    membership.role = "admin".to_string();
+    membership.save(&pool).await.expect("update");
+    assert_eq!((membership.user_id, membership.group_id), (1, 2));
+
+    Membership::update_by_id(&pool, (2, 1), json!({ "role": "guest" }))
+        .await
+        .expect("update_by_id");
+    let roles: Vec<String> = Membership::find_in_pool(&pool)
+        .order_by("user_id")
+        .order_by("group_id")
+        .all()
+        .await
+        .expect("all")
+        .into_iter()
+        .map(|m| m.role)
+        .collect();
+    assert_eq!(roles, ["owner", "admin", "guest"]);
+
+    membership.delete(&pool).await.expect("delete");
+    assert!(
+        Membership::find_by_key(&pool, (1, 2))
+            .await
+            .expect("find")
+            .is_none()
+    );
+    assert_eq!(
+        Membership::find_in_pool(&pool)
+            .count()
+            .await
+            .expect("count"),
+        2
+    );
+}
+
+#[tokio::test]
+async fn sqlite_manages_timestamps() {
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, Note>(&pool).await.expect("sync");
+    let started = Utc::now();
+    let mut note = Note {
+        id: 0,
+        body: "draft".to_string(),
+        created_at: DateTime::UNIX_EPOCH,
+        updated_at: DateTime::UNIX_EPOCH,
+    };
+    note.save(&pool).await.expect("insert");
+    assert!(note.created_at >= started);
+    assert_eq!(note.updated_at, note.created_at);
+    let stored = Note::find_by_id(&pool, note.id)
+        .await
+        .expect("find")
+        .expect("row");
+    assert_eq!(stored.created_at, note.created_at);
+
+    let created = note.created_at;
+    note.body = "final".to_string();
+    note.updated_at = DateTime::UNIX_EPOCH;
+    note.save(&pool).await.expect("update");
+    assert_eq!(note.created_at, created);
+    assert!(note.updated_at >= created);
+
+    let updated_at = |pool| async move {
+        Note::find_by_id(pool, 1)
+            .await
+            .expect("find")
+            .expect("row")
+            .updated_at
+    };
+    Note::find_in_pool(&pool)
+        .filter_eq("id", 1)
+        .update_with(|u| u.set("updated_at", DateTime::<Utc>::UNIX_EPOCH))
+        .await
+        .expect("explicit");
+    assert_eq!(updated_at(&pool).await, DateTime::UNIX_EPOCH);
+    Note::find_in_pool(&pool)
+        .filter_eq("id", 1)
+        .without_timestamps()
+        .update(json!({ "body": "quiet" }))
+        .await
+        .expect("opt out");
+    assert_eq!(updated_at(&pool).await, DateTime::UNIX_EPOCH);
+    Note::find_in_pool(&pool)
+        .filter_eq("id", 1)
+        .update(json!({ "body": "bulk" }))
+        .await
+        .expect("bulk");
+    assert!(updated_at(&pool).await >= created);
+}
+
+#[tokio::test]
+async fn sqlite_column_attribute_renames_columns() {
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    // `order` is reserved, so every generated statement has to quote it.
+    sqlx::query(
+        r#"CREATE TABLE legacyaccounts (id INTEGER PRIMARY KEY, userName TEXT, type TEXT, "order" INTEGER)"#,
+    )
+    .execute(&pool)
+    .await
+    .expect("create");
+    assert_eq!(
+        <LegacyAccount as Model<Sqlite>>::list_columns(),
+        vec!["id", "userName", "type", "order"]
+    );
+    assert_eq!(LegacyAccount::KIND.name(), "type");
+    let schema = LegacyAccount::schema();
+    assert_eq!(schema.indexes[0].columns, vec!["type"]);
+
+    let mut account = LegacyAccount {
+        id: 0,
+        user_name: "ada".to_string(),
+        kind: "admin".to_string(),
+        rank: 1,
+    };
+    account.save(&pool).await.expect("insert");
+    account.kind = "owner".to_string();
+    account.rank = 2;
+    account.save(&pool).await.expect("update");
+
+    let (user_name, kind, rank): (String, String, i32) =
+        sqlx::query_as(r#"SELECT userName, type, "order" FROM legacyaccounts WHERE id = ?"#)
+            .bind(account.id)
+            .fetch_one(&pool)
+            .await
+            .expect("raw");
+    assert_eq!(
+        (user_name.as_str(), kind.as_str(), rank),
+        ("ada", "owner", 2)
+    );
+
+    let found = LegacyAccount::find_in_pool(&pool)
+        .filter_by(LegacyAccount::KIND.eq("owner"))
+        .all()
+        .await
+        .expect("all");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].user_name, "ada");
+
+    let mut by_id = LegacyAccount::find_by_id(&pool, account.id)
+        .await
+        .expect("find")
+        .expect("row");
+    assert_eq!(by_id.kind, "owner");
+
+    let err = LegacyAccount::find_in_pool(&pool)
+        .filter_eq("kind", "owner")
+        .all()
+        .await
+        .expect_err("field names are not columns");
+    assert!(err.to_string().contains("Unknown column 'kind'"));
+
+    by_id.delete(&pool).await.expect("delete");
+    assert!(
+        LegacyAccount::find_by_id(&pool, account.id)
+            .await
+            .expect("find")
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn sqlite_sync_and_migrations_quote_column_overrides() {
+    use premix_orm::schema::{ModelSchema, diff_schema, sqlite_migration_sql};
+
+    // Synced tables must accept the derived SQL for reserved-word columns.
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, LegacyAccount>(&pool)
+        .await
+        .expect("sync");
+    let mut account = LegacyAccount {
+        id: 0,
+        user_name: "ada".to_string(),
+        kind: "admin".to_string(),
+        rank: 3,
+    };
+    account.save(&pool).await.expect("insert");
+    let found = LegacyAccount::find_by_id(&pool, account.id)
+        .await
+        .expect("find")
+        .expect("row");
+    assert_eq!((found.user_name.as_str(), found.rank), ("ada", 3));
+
+    // So must tables created by the migration generator, index included.
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    let expected = vec![LegacyAccount::schema()];
+    let diff = diff_schema(&expected, &[]);
+    for stmt in sqlite_migration_sql(&expected, &diff) {
+        sqlx::raw_sql(&stmt).execute(&pool).await.expect("migrate");
+    }
+    let mut account = LegacyAccount {
+        id: 0,
+        user_name: "bob".to_string(),
+        kind: "owner".to_string(),
+        rank: 1,
+    };
+    account.save(&pool).await.expect("insert");
+    let owners = LegacyAccount::find_in_pool(&pool)
+        .filter_by(LegacyAccount::KIND.eq("owner"))
+        .count()
+        .await
+        .expect("count");
+    assert_eq!(owners, 1);
+}
+
 #[tokio::test]
 async fn sqlite_update_all_requires_filters_or_allow_unsafe() {
     let pool = setup_user_post_pool().await;
@@ -239,6 +691,212 @@ async fn sqlite_update_all_requires_filters_or_allow_unsafe() {
     assert_eq!(updated, 2);
 }
 
+#[tokio::test]
+async fn sqlite_update_with_column_expressions() {
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, Task>(&pool).await.expect("sync");
+    for priority in [1, 5] {
+        let mut task = Task {
+            id: 0,
+            status: "open".to_string(),
+            priority,
+        };
+        task.save(&pool).await.expect("save");
+    }
+
+    let err = Task::find_in_pool(&pool)
+        .update_with(|u| u.increment("priority", 1))
+        .await
+        .expect_err("guard");
+    assert!(err.to_string().contains("without filters"));
+
+    let updated = Task::find_in_pool(&pool)
+        .filter_eq("id", 1)
+        .update_with(|u| u.increment("priority", 2).set("status", "bumped"))
+        .await
+        .expect("increment");
+    assert_eq!(updated, 1);
+    Task::find_in_pool(&pool)
+        .filter_gte("priority", 3)
+        .update_with(|u| u.decrement(Task::PRIORITY, 1))
+        .await
+        .expect("decrement");
+    Task::find_in_pool(&pool)
+        .filter_eq("id", 2)
+        .update_with(|u| u.set_now("status"))
+        .await
+        .expect("set_now");
+
+    let tasks = Task::find_in_pool(&pool)
+        .order_by("id")
+        .all()
+        .await
+        .expect("all");
+    assert_eq!((tasks[0].priority, tasks[0].status.as_str()), (2, "bumped"));
+    assert_eq!(tasks[1].priority, 4);
+    assert!(tasks[1].status.parse::<DateTime<Utc>>().is_ok());
+
+    let pool = setup_nullable_user_pool().await;
+    let mut user = NullableUser {
+        id: 0,
+        name: Some("Ann".to_string()),
+    };
+    user.save(&pool).await.expect("save");
+    NullableUser::find_in_pool(&pool)
+        .filter_eq("id", user.id)
+        .update_with(|u| u.set_null("name"))
+        .await
+        .expect("set_null");
+    let cleared = NullableUser::find_in_pool(&pool)
+        .filter_is_null("name")
+        .count()
+        .await
+        .expect("count");
+    assert_eq!(cleared, 1);
+}
+
+#[tokio::test]
+async fn sqlite_update_and_delete_returning() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Ann", "Bob", "Cara"]).await;
+
+    let err = User::find_in_pool(&pool)
+        .delete_returning()
+        .await
+        .expect_err("guard");
+    assert!(err.to_string().contains("without filters"));
+
+    let mut updated = User::find_in_pool(&pool)
+        .filter_ne("name", "Cara")
+        .update_returning(json!({ "name": "Renamed" }))
+        .await
+        .expect("update_returning");
+    updated.sort_by_key(|u| u.id);
+    let rows: Vec<_> = updated.iter().map(|u| (u.id, u.name.as_str())).collect();
+    assert_eq!(rows, [(1, "Renamed"), (2, "Renamed")]);
+
+    let deleted = User::find_in_pool(&pool)
+        .filter_eq("name", "Cara")
+        .delete_returning()
+        .await
+        .expect("delete_returning");
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].id, 3);
+    assert_eq!(User::find_in_pool(&pool).count().await.expect("count"), 2);
+
+    let pool = setup_soft_user_pool().await;
+    let mut user = SoftUser {
+        id: 0,
+        name: "Soft".to_string(),
+        deleted_at: None,
+    };
+    user.save(&pool).await.expect("save");
+    let soft = SoftUser::find_in_pool(&pool)
+        .filter_eq("id", user.id)
+        .delete_returning()
+        .await
+        .expect("soft delete_returning");
+    assert_eq!(soft.len(), 1);
+    assert!(soft[0].deleted_at.is_some());
+}
+
+#[tokio::test]
+async fn sqlite_unknown_columns_suggest_closest() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Ann", "Bob"]).await;
+
+    let err = User::find_in_pool(&pool)
+        .filter_eq("nmae", "Ann")
+        .all()
+        .await
+        .expect_err("typo in filter");
+    match map_sqlx_error(err) {
+        PremixError::UnknownColumn {
+            table,
+            column,
+            suggestion,
+        } => {
+            assert_eq!((table.as_str(), column.as_str()), ("users", "nmae"));
+            assert_eq!(suggestion.as_deref(), Some("name"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    let err = User::find_in_pool(&pool)
+        .filter_eq("id", 1)
+        .update(json!({ "nam": "Renamed" }))
+        .await
+        .expect_err("typo in update");
+    assert!(err.to_string().contains("did you mean 'name'?"));
+
+    let posts = Post::find_in_pool(&pool)
+        .filter_in_query(
+            "user_id",
+            User::find_in_pool(&pool)
+                .select(&["id"])
+                .filter_eq("nme", "Ann"),
+        )
+        .count()
+        .await;
+    assert!(
+        posts
+            .unwrap_err()
+            .to_string()
+            .contains("did you mean 'name'?")
+    );
+
+    sqlx::query("ALTER TABLE users ADD COLUMN legacy_flag INTEGER DEFAULT 0")
+        .execute(&pool)
+        .await
+        .expect("alter");
+    let unmapped = User::find_in_pool(&pool)
+        .filter_eq("legacy_flag", 0)
+        .allow_unknown_columns()
+        .count()
+        .await
+        .expect("unmapped column");
+    assert_eq!(unmapped, 2);
+}
+
+#[tokio::test]
+async fn sqlite_explain_flags_full_scans() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Ann", "Bob"]).await;
+    sqlx::query("CREATE INDEX idx_users_name ON users (name)")
+        .execute(&pool)
+        .await
+        .expect("index");
+
+    let plan = User::find_in_pool(&pool)
+        .filter_eq("name", "Ann")
+        .explain()
+        .await
+        .expect("explain");
+    assert!(!plan.has_full_scan(), "{}", plan.raw);
+    assert!(plan.uses_index("idx_users_name"), "{}", plan.raw);
+    assert!(plan.sql.starts_with("SELECT"));
+
+    let plan = Post::find_in_pool(&pool)
+        .filter_eq("title", "Hello")
+        .explain()
+        .await
+        .expect("explain");
+    let scans: Vec<_> = plan
+        .full_scans()
+        .map(|node| node.table.as_deref())
+        .collect();
+    assert_eq!(scans, [Some("posts")], "{}", plan.raw);
+
+    let err = User::find_in_pool(&pool)
+        .filter_eq("id", 1)
+        .explain_analyze()
+        .await
+        .expect_err("no EXPLAIN ANALYZE on SQLite");
+    assert!(err.to_string().contains("not supported"));
+}
+
 #[tokio::test]
 async fn sqlite_include_empty_relation() {
     let pool = setup_user_post_pool().await;
@@ -297,19 +955,669 @@ async fn sqlite_limit_offset() {
 }
 
 #[tokio::test]
-async fn sqlite_update_with_filter() {
+async fn sqlite_order_by_multiple_columns() {
     let pool = setup_user_post_pool().await;
-    seed_users(&pool, &["Alice", "Bob"]).await;
-
-    let updated = User::find_in_pool(&pool)
-        .filter_eq("name", "Alice")
-        .update(json!({ "name": "Alicia" }))
-        .await
-        .expect("update");
-    assert_eq!(updated, 1);
+    seed_users(&pool, &["Bob", "Alice", "Bob", "Cara"]).await;
 
-    let results = User::find_in_pool(&pool)
-        .filter_eq("name", "Alicia")
+    let users = User::find_in_pool(&pool)
+        .order_by("name")
+        .order_by_desc("id")
+        .all()
+        .await
+        .expect("all");
+    let names: Vec<_> = users.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Alice", "Bob", "Bob", "Cara"]);
+    assert!(users[1].id > users[2].id);
+
+    let page = User::find_in_pool(&pool)
+        .order_by_desc("name")
+        .limit(2)
+        .offset(1)
+        .all()
+        .await
+        .expect("all");
+    let names: Vec<_> = page.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Bob", "Bob"]);
+}
+
+#[tokio::test]
+async fn sqlite_order_by_nulls_first_and_last() {
+    let pool = setup_nullable_user_pool().await;
+    for name in [Some("B"), None, Some("A")] {
+        let mut user = NullableUser {
+            id: 0,
+            name: name.map(str::to_string),
+        };
+        user.save(&pool).await.expect("save");
+    }
+
+    let first = NullableUser::find_in_pool(&pool)
+        .order_by("name")
+        .nulls_first()
+        .all()
+        .await
+        .expect("all");
+    let names: Vec<_> = first.iter().map(|u| u.name.as_deref()).collect();
+    assert_eq!(names, [None, Some("A"), Some("B")]);
+
+    let last = NullableUser::find_in_pool(&pool)
+        .order_by_desc("name")
+        .nulls_last()
+        .all()
+        .await
+        .expect("all");
+    let names: Vec<_> = last.iter().map(|u| u.name.as_deref()).collect();
+    assert_eq!(names, [Some("B"), Some("A"), None]);
+}
+
+#[tokio::test]
+async fn sqlite_count_exists_first_one() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Alice", "Bob", "Bob"]).await;
+
+    let total = User::find_in_pool(&pool).count().await.expect("count");
+    assert_eq!(total, 3);
+    let bobs = User::find_in_pool(&pool)
+        .filter_eq("name", "Bob")
+        .count()
+        .await
+        .expect("count");
+    assert_eq!(bobs, 2);
+
+    assert!(
+        User::find_in_pool(&pool)
+            .filter_eq("name", "Alice")
+            .exists()
+            .await
+            .expect("exists")
+    );
+    assert!(
+        !User::find_in_pool(&pool)
+            .filter_eq("name", "Zed")
+            .exists()
+            .await
+            .expect("exists")
+    );
+
+    let first = User::find_in_pool(&pool)
+        .order_by_desc("id")
+        .first()
+        .await
+        .expect("first")
+        .expect("row");
+    assert_eq!(first.name, "Bob");
+    let none = User::find_in_pool(&pool)
+        .filter_eq("name", "Zed")
+        .first()
+        .await
+        .expect("first");
+    assert!(none.is_none());
+
+    let alice = User::find_in_pool(&pool)
+        .filter_eq("name", "Alice")
+        .one()
+        .await
+        .expect("one");
+    assert_eq!(alice.name, "Alice");
+    let missing = User::find_in_pool(&pool)
+        .filter_eq("name", "Zed")
+        .one()
+        .await;
+    assert!(matches!(missing, Err(sqlx::Error::RowNotFound)));
+    let many = User::find_in_pool(&pool)
+        .filter_eq("name", "Bob")
+        .one()
+        .await;
+    assert!(many.is_err());
+}
+
+#[tokio::test]
+async fn sqlite_count_respects_soft_delete_and_raw_guard() {
+    let pool = setup_soft_user_pool().await;
+    for name in ["A", "B"] {
+        let mut user = SoftUser {
+            id: 0,
+            name: name.to_string(),
+            deleted_at: None,
+        };
+        user.save(&pool).await.expect("save");
+        if name == "B" {
+            user.delete(&pool).await.expect("delete");
+        }
+    }
+
+    assert_eq!(
+        SoftUser::find_in_pool(&pool).count().await.expect("count"),
+        1
+    );
+    assert_eq!(
+        SoftUser::find_in_pool(&pool)
+            .with_deleted()
+            .count()
+            .await
+            .expect("count"),
+        2
+    );
+    let err = SoftUser::find_in_pool(&pool)
+        .filter_raw("name = 'A'")
+        .count()
+        .await;
+    assert!(err.is_err());
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct UserName {
+    name: String,
+}
+
+#[tokio::test]
+async fn sqlite_select_projection_fetch_as() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Cara", "Alice", "Bob"]).await;
+
+    let rows = User::find_in_pool(&pool)
+        .select(&["name"])
+        .filter_ne("name", "Bob")
+        .order_by("name")
+        .fetch_as::<UserName>()
+        .await
+        .expect("fetch_as");
+    let names: Vec<_> = rows.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["Alice", "Cara"]);
+
+    let tuples: Vec<(i32, String)> = User::find_in_pool(&pool)
+        .select(&["id", "name"])
+        .order_by("id")
+        .limit(1)
+        .fetch_as()
+        .await
+        .expect("fetch_as");
+    assert_eq!(tuples[0].1, "Cara");
+
+    let err = User::find_in_pool(&pool)
+        .select(&["nmae"])
+        .fetch_as::<UserName>()
+        .await;
+    assert!(err.is_err());
+}
+
+#[tokio::test]
+async fn sqlite_any_of_and_not_groups() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Alice", "Bob", "Cara", "Dan"]).await;
+
+    let users = User::find_in_pool(&pool)
+        .any_of(|f| {
+            f.eq("name", "Alice")
+                .all_of(|f| f.gte("id", 3).ne("name", "Dan"))
+        })
+        .order_by("id")
+        .all()
+        .await
+        .expect("any_of");
+    let names: Vec<_> = users.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Alice", "Cara"]);
+
+    let remaining = User::find_in_pool(&pool)
+        .not(|f| f.in_list("name", ["Bob", "Dan"]))
+        .filter_gt("id", 1)
+        .count()
+        .await
+        .expect("not");
+    assert_eq!(remaining, 1);
+}
+
+#[tokio::test]
+async fn sqlite_cursor_pagination_walks_forward_and_back() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Ann", "Ben", "Cat", "Dan", "Eve"]).await;
+
+    let page1 = User::find_in_pool(&pool)
+        .paginate_by(&["id"])
+        .cursor_page(2)
+        .await
+        .expect("page 1");
+    assert_eq!(page1.items.iter().map(|u| u.id).collect::<Vec<_>>(), [1, 2]);
+    assert!(page1.prev_cursor.is_none());
+
+    let page2 = User::find_in_pool(&pool)
+        .paginate_by(&["id"])
+        .after(page1.next_cursor.clone().expect("next"))
+        .cursor_page(2)
+        .await
+        .expect("page 2");
+    assert_eq!(page2.items.iter().map(|u| u.id).collect::<Vec<_>>(), [3, 4]);
+
+    let page3 = User::find_in_pool(&pool)
+        .paginate_by(&["id"])
+        .after(page2.next_cursor.clone().expect("next"))
+        .cursor_page(2)
+        .await
+        .expect("page 3");
+    assert_eq!(page3.items.iter().map(|u| u.id).collect::<Vec<_>>(), [5]);
+    assert!(page3.next_cursor.is_none());
+
+    let back = User::find_in_pool(&pool)
+        .paginate_by(&["id"])
+        .before(page3.prev_cursor.expect("prev"))
+        .cursor_page(2)
+        .await
+        .expect("back");
+    assert_eq!(back.items.iter().map(|u| u.id).collect::<Vec<_>>(), [3, 4]);
+    assert!(back.prev_cursor.is_some());
+
+    let bad = User::find_in_pool(&pool)
+        .paginate_by(&["id"])
+        .after("garbage")
+        .cursor_page(2)
+        .await;
+    assert!(bad.is_err());
+}
+
+#[tokio::test]
+async fn sqlite_cursor_pagination_multi_column_with_filters() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["b", "a", "b", "a", "c", "b"]).await;
+
+    let mut seen = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut query = User::find_in_pool(&pool)
+            .filter_ne("name", "c")
+            .paginate_by_desc(&["name", "id"]);
+        if let Some(token) = cursor.take() {
+            query = query.after(token);
+        }
+        let page = query.cursor_page(2).await.expect("page");
+        seen.extend(page.items.iter().map(|u| (u.name.clone(), u.id)));
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    let expected: Vec<(String, i32)> = [("b", 6), ("b", 3), ("b", 1), ("a", 4), ("a", 2)]
+        .into_iter()
+        .map(|(name, id)| (name.to_string(), id))
+        .collect();
+    assert_eq!(seen, expected);
+}
+
+#[tokio::test]
+async fn sqlite_cursor_pagination_respects_soft_delete() {
+    let pool = setup_soft_user_pool().await;
+    for name in ["one", "two", "three"] {
+        let mut user = SoftUser {
+            id: 0,
+            name: name.to_string(),
+            deleted_at: None,
+        };
+        user.save(&pool).await.expect("save");
+        if name == "two" {
+            user.delete(&pool).await.expect("delete");
+        }
+    }
+
+    let page = SoftUser::find_in_pool(&pool)
+        .paginate_by(&["id"])
+        .cursor_page(10)
+        .await
+        .expect("page");
+    let names: Vec<_> = page.items.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["one", "three"]);
+    assert!(page.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn sqlite_paginate_returns_page_with_total() {
+    let pool = setup_user_post_pool().await;
+    let users = seed_users(&pool, &["Ann", "Ben", "Cat", "Dan", "Eve"]).await;
+    let mut post = Post {
+        id: 0,
+        user_id: users[2].id,
+        title: "hello".to_string(),
+    };
+    post.save(&pool).await.expect("save");
+
+    let page = User::find_in_pool(&pool)
+        .filter_ne("name", "Eve")
+        .include("posts")
+        .order_by("id")
+        .paginate(2, 2)
+        .await
+        .expect("paginate");
+    assert_eq!(page.total, 4);
+    assert_eq!(page.total_pages, 2);
+    assert_eq!((page.page, page.per_page), (2, 2));
+    assert!(!page.has_next());
+    assert!(page.has_prev());
+    let names: Vec<_> = page.items.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Cat", "Dan"]);
+    assert_eq!(page.items[0].posts.as_ref().map(Vec::len), Some(1));
+
+    let past_end = User::find_in_pool(&pool)
+        .paginate(9, 2)
+        .await
+        .expect("paginate");
+    assert!(past_end.items.is_empty());
+    assert_eq!(past_end.total, 5);
+    assert_eq!(past_end.total_pages, 3);
+}
+
+#[tokio::test]
+async fn sqlite_paginate_respects_soft_delete_in_tx() {
+    let pool = setup_soft_user_pool().await;
+    for name in ["one", "two", "three"] {
+        let mut user = SoftUser {
+            id: 0,
+            name: name.to_string(),
+            deleted_at: None,
+        };
+        user.save(&pool).await.expect("save");
+        if name == "one" {
+            user.delete(&pool).await.expect("delete");
+        }
+    }
+
+    let mut tx = pool.begin().await.expect("tx");
+    let page = <SoftUser as Model<Sqlite>>::find_in_tx(&mut *tx)
+        .order_by("id")
+        .paginate(1, 10)
+        .await
+        .expect("paginate");
+    tx.commit().await.expect("commit");
+    assert_eq!(page.total, 2);
+    assert_eq!(page.total_pages, 1);
+    let names: Vec<_> = page.items.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["two", "three"]);
+}
+
+#[tokio::test]
+async fn sqlite_paginate_in_tx_uses_one_snapshot() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Ann", "Ben"]).await;
+
+    // Rows written inside the transaction show up in both the total and the page,
+    // so the count and the page query ran on the same connection.
+    let mut tx = pool.begin().await.expect("tx");
+    let mut user = User {
+        id: 0,
+        name: "Cat".to_string(),
+        posts: None,
+    };
+    user.save(&mut *tx).await.expect("save");
+    let page = <User as Model<Sqlite>>::find_in_tx(&mut *tx)
+        .order_by("id")
+        .paginate(1, 10)
+        .await
+        .expect("paginate");
+    assert_eq!(page.total, 3);
+    let names: Vec<_> = page.items.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Ann", "Ben", "Cat"]);
+    tx.rollback().await.expect("rollback");
+
+    let page = User::find_in_pool(&pool)
+        .paginate(1, 10)
+        .await
+        .expect("paginate");
+    assert_eq!((page.total, page.items.len()), (2, 2));
+}
+
+#[tokio::test]
+async fn sqlite_subquery_filters() {
+    let pool = setup_user_post_pool().await;
+    let users = seed_users(&pool, &["Alice", "Bob", "Cara"]).await;
+    for (user_id, title) in [
+        (users[0].id, "hello"),
+        (users[0].id, "again"),
+        (users[2].id, "hello"),
+    ] {
+        let mut post = Post {
+            id: 0,
+            user_id,
+            title: title.to_string(),
+        };
+        post.save(&pool).await.expect("save");
+    }
+
+    let names = |users: Vec<User>| -> Vec<String> { users.into_iter().map(|u| u.name).collect() };
+
+    let said_hello = User::find_in_pool(&pool)
+        .filter_ne("name", "Cara")
+        .filter_in_query(
+            "id",
+            Post::find_in_pool(&pool)
+                .select(&["user_id"])
+                .filter_eq("title", "hello"),
+        )
+        .all()
+        .await
+        .expect("in query");
+    assert_eq!(names(said_hello), ["Alice"]);
+
+    let prolific = User::find_in_pool(&pool)
+        .filter_in_query(
+            "id",
+            Post::find_in_pool(&pool)
+                .group_by("user_id")
+                .having_gte(Aggregate::Count, "*", 2),
+        )
+        .all()
+        .await
+        .expect("grouped in query");
+    assert_eq!(names(prolific), ["Alice"]);
+
+    let with_posts = User::find_in_pool(&pool)
+        .filter_exists(Post::find_in_pool(&pool).correlate("user_id", "id"))
+        .order_by("id")
+        .all()
+        .await
+        .expect("exists");
+    assert_eq!(names(with_posts), ["Alice", "Cara"]);
+
+    let without_posts = User::find_in_pool(&pool)
+        .filter_not_exists(Post::find_in_pool(&pool).correlate("user_id", "id"))
+        .count()
+        .await
+        .expect("not exists");
+    assert_eq!(without_posts, 1);
+
+    // Same-model subqueries correlate against the outer row, not themselves.
+    let named_bob = User::find_in_pool(&pool)
+        .filter_exists(
+            User::find_in_pool(&pool)
+                .correlate("name", "name")
+                .filter_eq("name", "Bob"),
+        )
+        .all()
+        .await
+        .expect("self exists");
+    assert_eq!(names(named_bob), ["Bob"]);
+}
+
+#[tokio::test]
+async fn sqlite_relation_joins() {
+    let pool = setup_user_post_pool().await;
+    let users = seed_users(&pool, &["Alice", "Bob", "Cara"]).await;
+    for (user_id, title) in [
+        (users[0].id, "hello"),
+        (users[0].id, "hello"),
+        (users[2].id, "other"),
+        (users[2].id, "other"),
+    ] {
+        let mut post = Post {
+            id: 0,
+            user_id,
+            title: title.to_string(),
+        };
+        post.save(&pool).await.expect("save");
+    }
+
+    let joined = User::find_in_pool(&pool)
+        .join(User::posts)
+        .filter_eq(("posts", "title"), "hello")
+        .distinct()
+        .all()
+        .await
+        .expect("join");
+    assert_eq!(joined.len(), 1);
+    assert_eq!(joined[0].name, "Alice");
+
+    // Counts users, not joined rows.
+    let with_posts = User::find_in_pool(&pool)
+        .join(User::posts)
+        .count()
+        .await
+        .expect("join count");
+    assert_eq!(with_posts, 2);
+
+    // The total matches the DISTINCT page, not the four joined rows.
+    let page = User::find_in_pool(&pool)
+        .join(User::posts)
+        .distinct()
+        .order_by("id")
+        .paginate(1, 1)
+        .await
+        .expect("join paginate");
+    assert_eq!((page.total, page.total_pages), (2, 2));
+    let names: Vec<_> = page.items.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Alice"]);
+
+    let groups = Post::find_in_pool(&pool)
+        .group_by("user_id")
+        .count()
+        .await
+        .expect("group count");
+    assert_eq!(groups, 2);
+
+    let without_posts = User::find_in_pool(&pool)
+        .left_join(User::posts)
+        .filter_is_null(("posts", "id"))
+        .all()
+        .await
+        .expect("left join");
+    assert_eq!(without_posts.len(), 1);
+    assert_eq!(without_posts[0].name, "Bob");
+
+    let err = User::find_in_pool(&pool)
+        .join(User::posts)
+        .filter_eq(("posts", "title"), "hello")
+        .delete()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not supported for delete"));
+
+    let err = User::find_in_pool(&pool)
+        .join(User::posts)
+        .filter_eq(("posts", "titel"), "hello")
+        .all()
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Unknown column 'titel' on table posts")
+    );
+
+    let err = User::find_in_pool(&pool)
+        .filter_eq(("posts", "title"), "hello")
+        .all()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("must be qualified with users"));
+}
+
+#[tokio::test]
+async fn sqlite_group_by_having_aggregates() {
+    let pool = setup_user_post_pool().await;
+    let users = seed_users(&pool, &["Alice", "Bob"]).await;
+    for (user_id, title) in [
+        (users[0].id, "a1"),
+        (users[0].id, "a2"),
+        (users[0].id, "a3"),
+        (users[1].id, "b1"),
+    ] {
+        let mut post = Post {
+            id: 0,
+            user_id,
+            title: title.to_string(),
+        };
+        post.save(&pool).await.expect("save");
+    }
+
+    let counts: Vec<(i32, i64)> = Post::find_in_pool(&pool)
+        .group_by("user_id")
+        .count_as("total")
+        .order_by("user_id")
+        .fetch_as()
+        .await
+        .expect("group by");
+    assert_eq!(counts, [(users[0].id, 3), (users[1].id, 1)]);
+
+    let busy: Vec<(i32, i64)> = Post::find_in_pool(&pool)
+        .group_by("user_id")
+        .count_as("total")
+        .having_gt(Aggregate::Count, "*", 1)
+        .fetch_as()
+        .await
+        .expect("having");
+    assert_eq!(busy, [(users[0].id, 3)]);
+
+    let (avg_id, max_id): (f64, i32) = Post::find_in_pool(&pool)
+        .filter_eq("user_id", users[0].id)
+        .avg_as("id", "avg_id")
+        .max_as("id", "max_id")
+        .fetch_as()
+        .await
+        .expect("avg")
+        .remove(0);
+    assert_eq!(avg_id, 2.0);
+    assert_eq!(max_id, 3);
+
+    let err = Post::find_in_pool(&pool)
+        .group_by("usr_id")
+        .count_as("total")
+        .fetch_as::<(i32, i64)>()
+        .await;
+    assert!(err.is_err());
+}
+
+#[tokio::test]
+async fn sqlite_select_projection_respects_soft_delete() {
+    let pool = setup_soft_user_pool().await;
+    for name in ["Kept", "Gone"] {
+        let mut user = SoftUser {
+            id: 0,
+            name: name.to_string(),
+            deleted_at: None,
+        };
+        user.save(&pool).await.expect("save");
+        if name == "Gone" {
+            user.delete(&pool).await.expect("delete");
+        }
+    }
+
+    let rows = SoftUser::find_in_pool(&pool)
+        .select(&["name"])
+        .fetch_as::<UserName>()
+        .await
+        .expect("fetch_as");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].name, "Kept");
+}
+
+#[tokio::test]
+async fn sqlite_update_with_filter() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Alice", "Bob"]).await;
+
+    let updated = User::find_in_pool(&pool)
+        .filter_eq("name", "Alice")
+        .update(json!({ "name": "Alicia" }))
+        .await
+        .expect("update");
+    assert_eq!(updated, 1);
+
+    let results = User::find_in_pool(&pool)
+        .filter_eq("name", "Alicia")
         .all()
         .await
         .expect("all");
@@ -461,7 +1769,7 @@ async fn sqlite_filter_variants() {
         .all()
         .await
         .expect("all");
-    assert!(lt.len() >= 1);
+    assert!(!lt.is_empty());
 
     let lte = User::find_in_pool(&pool)
         .filter_lte("id", users[2].id)
@@ -471,6 +1779,304 @@ async fn sqlite_filter_variants() {
     assert!(lte.len() >= lt.len());
 }
 
+#[tokio::test]
+async fn sqlite_range_and_pattern_filters() {
+    let pool = setup_user_post_pool().await;
+    let users = seed_users(&pool, &["Ann", "Bob", "Cara", "100%_done", "100 done"]).await;
+    let names = |users: Vec<User>| -> Vec<String> { users.into_iter().map(|u| u.name).collect() };
+
+    let between = User::find_in_pool(&pool)
+        .filter_between("id", users[1].id, users[2].id)
+        .order_by("id")
+        .all()
+        .await
+        .expect("between");
+    assert_eq!(names(between), ["Bob", "Cara"]);
+
+    let not_in = User::find_in_pool(&pool)
+        .filter_not_in("name", ["Ann", "Bob", "100 done"])
+        .order_by("id")
+        .all()
+        .await
+        .expect("not in");
+    assert_eq!(names(not_in), ["Cara", "100%_done"]);
+    let everyone = User::find_in_pool(&pool)
+        .filter_not_in("id", Vec::<i32>::new())
+        .count()
+        .await
+        .expect("empty not in");
+    assert_eq!(everyone, 5);
+
+    let ilike = User::find_in_pool(&pool)
+        .filter_ilike("name", "CA%")
+        .all()
+        .await
+        .expect("ilike");
+    assert_eq!(names(ilike), ["Cara"]);
+
+    // `%` and `_` from user input must not act as wildcards.
+    let literal = User::find_in_pool(&pool)
+        .filter_contains("name", "0%_")
+        .all()
+        .await
+        .expect("contains");
+    assert_eq!(names(literal), ["100%_done"]);
+    let prefix = User::find_in_pool(&pool)
+        .filter_starts_with("name", "100")
+        .count()
+        .await
+        .expect("starts with");
+    assert_eq!(prefix, 2);
+    let suffix = User::find_in_pool(&pool)
+        .filter_ends_with("name", "_done")
+        .all()
+        .await
+        .expect("ends with");
+    assert_eq!(names(suffix), ["100%_done"]);
+    let grouped = User::find_in_pool(&pool)
+        .any_of(|f| f.icontains("name", "ANN").ends_with("name", "b"))
+        .order_by("id")
+        .all()
+        .await
+        .expect("grouped");
+    assert_eq!(names(grouped), ["Ann", "Bob"]);
+}
+
+#[tokio::test]
+async fn sqlite_json_filters() {
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, Profile>(&pool).await.expect("sync");
+    for (settings, tags) in [
+        (
+            json!({ "theme": "dark", "level": 3 }),
+            json!(["rust", "sql"]),
+        ),
+        (json!({ "theme": "light", "level": 1 }), json!(["go"])),
+    ] {
+        let mut profile = Profile {
+            id: 0,
+            settings: settings.to_string(),
+            tags: tags.to_string(),
+        };
+        profile.save(&pool).await.expect("save");
+    }
+
+    let dark = Profile::find_in_pool(&pool)
+        .filter_json_path("settings", "$.theme", "dark")
+        .all()
+        .await
+        .expect("json path");
+    assert_eq!(dark.len(), 1);
+    assert_eq!(dark[0].id, 1);
+
+    let level = Profile::find_in_pool(&pool)
+        .filter_json_path("settings", "$.level", 1)
+        .all()
+        .await
+        .expect("json path number");
+    assert_eq!(level.len(), 1);
+    assert_eq!(level[0].id, 2);
+
+    let tagged = Profile::find_in_pool(&pool)
+        .json_contains("tags", "sql")
+        .count()
+        .await
+        .expect("json contains");
+    assert_eq!(tagged, 1);
+
+    for (column, value, expected) in [
+        ("settings", json!({ "theme": "dark" }), 1),
+        ("settings", json!({ "theme": "dark", "level": 1 }), 0),
+        ("settings", json!(["dark"]), 0),
+        ("tags", json!(["sql", "rust"]), 1),
+        ("tags", json!(["rust", "go"]), 0),
+        ("tags", json!({ "0": "rust" }), 0),
+    ] {
+        let count = Profile::find_in_pool(&pool)
+            .json_contains(column, value.clone())
+            .count()
+            .await
+            .expect("json contains document");
+        assert_eq!(count, expected, "{} @> {}", column, value);
+    }
+}
+
+#[tokio::test]
+async fn sqlite_fulltext_match_and_rank() {
+    use premix_orm::schema::{
+        ModelSchema, diff_schema, introspect_sqlite_schema, sqlite_migration_sql,
+    };
+
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    let expected = vec![Article::schema()];
+    let diff = diff_schema(
+        &expected,
+        &introspect_sqlite_schema(&pool).await.expect("schema"),
+    );
+    for stmt in sqlite_migration_sql(&expected, &diff) {
+        sqlx::raw_sql(&stmt).execute(&pool).await.expect("migrate");
+    }
+
+    for (title, body) in [
+        ("Gardening", "Tomatoes need sun"),
+        ("Rust ORM", "Premix is a Rust ORM; Rust all the way"),
+        ("Weekly notes", "A short mention of rust"),
+    ] {
+        let mut article = Article {
+            id: 0,
+            title: title.to_string(),
+            body: body.to_string(),
+        };
+        article.save(&pool).await.expect("save");
+    }
+
+    let hits = Article::find_in_pool(&pool)
+        .filter_match(&["title", "body"], "rust")
+        .order_by_rank()
+        .all()
+        .await
+        .expect("match");
+    let titles: Vec<_> = hits.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, ["Rust ORM", "Weekly notes"]);
+
+    // FTS operators in user input are matched as plain words.
+    let none = Article::find_in_pool(&pool)
+        .filter_match(&["title"], "rust OR \"sun")
+        .count()
+        .await
+        .expect("quoted");
+    assert_eq!(none, 0);
+    let title_only = Article::find_in_pool(&pool)
+        .filter_match(&["title"], "tomatoes")
+        .count()
+        .await
+        .expect("column filter");
+    assert_eq!(title_only, 0);
+}
+
+#[tokio::test]
+async fn sqlite_filter_match_rejects_text_keys() {
+    let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+    let err = Tenant::find_in_pool(&pool)
+        .filter_match(&["name"], "acme")
+        .all()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("integer primary key"));
+}
+
+#[tokio::test]
+async fn sqlite_filter_sql_with_binds() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Ann", "Bob", "Cara"]).await;
+
+    let users = User::find_in_pool(&pool)
+        .filter_ne("name", "Cara")
+        .filter_sql(
+            "id > ? OR lower(name) = lower(?)",
+            [BindValue::from(1), "ANN".into()],
+        )
+        .order_by("id")
+        .all()
+        .await
+        .expect("filter_sql");
+    let names: Vec<_> = users.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Ann", "Bob"]);
+
+    let err = User::find_in_pool(&pool)
+        .filter_sql("id = ?", Vec::<i64>::new())
+        .count()
+        .await
+        .expect_err("placeholder mismatch");
+    assert!(err.to_string().contains("placeholders"));
+}
+
+#[tokio::test]
+async fn sqlite_row_locks_in_transaction() {
+    let pool = setup_user_post_pool().await;
+    let users = seed_users(&pool, &["Ann", "Bob"]).await;
+
+    let err = User::find_in_pool(&pool)
+        .for_update()
+        .all()
+        .await
+        .expect_err("pool lock");
+    assert!(err.to_string().contains("transaction"));
+
+    let mut tx = pool.begin().await.expect("tx");
+    let locked = <User as Model<Sqlite>>::lock_by_id(&mut tx, users[1].id)
+        .await
+        .expect("lock_by_id")
+        .expect("row");
+    assert_eq!(locked.name, "Bob");
+    let queued = <User as Model<Sqlite>>::find_in_tx(&mut *tx)
+        .for_share()
+        .skip_locked()
+        .count()
+        .await
+        .expect("skip locked");
+    assert_eq!(queued, 2);
+    tx.commit().await.expect("commit");
+}
+
+#[tokio::test]
+async fn sqlite_typed_column_filters() {
+    let pool = setup_user_post_pool().await;
+    seed_users(&pool, &["Ann", "Bob", "Cara"]).await;
+
+    let users = User::find_in_pool(&pool)
+        .filter_by(User::ID.gte(2))
+        .any_of(|f| {
+            f.filter_by(User::NAME.eq("Cara"))
+                .filter_by(User::NAME.like("B%"))
+        })
+        .order_by_desc(User::ID)
+        .all()
+        .await
+        .expect("typed filters");
+    let names: Vec<_> = users.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["Cara", "Bob"]);
+    assert_eq!(Post::USER_ID.name(), "user_id");
+}
+
+#[tokio::test]
+async fn sqlite_named_scopes_compose() {
+    let pool = Premix::smart_sqlite_pool("sqlite::memory:")
+        .await
+        .expect("pool");
+    Premix::sync::<Sqlite, Task>(&pool).await.expect("sync");
+    for (status, priority) in [("open", 5), ("open", 1), ("done", 4), ("open", 3)] {
+        let mut task = Task {
+            id: 0,
+            status: status.to_string(),
+            priority,
+        };
+        task.save(&pool).await.expect("save");
+    }
+
+    let urgent_open = Task::find_in_pool(&pool)
+        .open()
+        .urgent()
+        .filter_ne("id", 4)
+        .all()
+        .await
+        .expect("scopes");
+    let ids: Vec<_> = urgent_open.iter().map(|t| t.id).collect();
+    assert_eq!(ids, [1]);
+
+    let either = Task::find_in_pool(&pool)
+        .any_of(|f| f.eq("priority", 1).eq("priority", 4))
+        .open()
+        .count()
+        .await
+        .expect("scope with group");
+    assert_eq!(either, 1);
+}
+
 #[tokio::test]
 async fn sqlite_null_filters() {
     let pool = setup_nullable_user_pool().await;